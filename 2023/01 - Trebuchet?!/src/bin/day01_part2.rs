@@ -0,0 +1,22 @@
+use std::process::ExitCode;
+
+use common::report::Part;
+use common::Error;
+use trebuchet::calibration::Document;
+use trebuchet::lexicon::DigitLexicon;
+use trebuchet::part2;
+
+// -- Unlike the library, the binary reads the lexicons named in AOC_LEXICON.
+fn parse(input: &str) -> Result<Document<'_>, Error> {
+    part2::parse_with(input, DigitLexicon::from_env()?)
+}
+
+fn main() -> ExitCode {
+    let part = Part {
+        year: 2023,
+        day: 1,
+        part: 2,
+        timed: |input| common::time(input, parse, part2::answer),
+    };
+    common::cli::main(env!("CARGO_MANIFEST_DIR"), &part)
+}
@@ -0,0 +1,68 @@
+use std::env;
+use std::process::ExitCode;
+
+use common::cli::{self, Source};
+use common::Error;
+use trebuchet::lexicon::DigitLexicon;
+use trebuchet::scanner::Scanner;
+
+const USAGE: &str = "[--part 1|2] [--input PATH]";
+
+// -- Sums calibration logs too large to load as a whole, a batch at a time.
+struct Options {
+    part: u8,
+    source: Source,
+}
+
+fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut part = 2;
+
+    let source = cli::parse_input_args(args, |arg, value| {
+        match arg {
+            "--part" => match value()?.as_str() {
+                "1" => part = 1,
+                "2" => part = 2,
+                part => return Err(format!("no such part: {}", part)),
+            },
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    })?;
+
+    Ok(Options {
+        part,
+        source: source.unwrap_or_else(|| Source::bundled(env!("CARGO_MANIFEST_DIR"))),
+    })
+}
+
+fn sum(options: &Options) -> Result<u64, Error> {
+    let lexicon = match options.part {
+        1 => DigitLexicon::digits(),
+        _ => DigitLexicon::from_env()?,
+    };
+    let reader = options.source.open().map_err(Error::new)?;
+
+    Scanner::new(&lexicon).sum_reader(reader)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => return cli::usage_error(&err, USAGE),
+    };
+
+    match sum(&options) {
+        Ok(sum) => {
+            println!("{}", sum);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
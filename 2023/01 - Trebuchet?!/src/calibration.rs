@@ -0,0 +1,49 @@
+use common::{Error, Line};
+
+use crate::lexicon::{DigitLexicon, Match};
+use crate::scanner::Scanner;
+
+const RADIX: u32 = 10;
+
+// -----------------------------------------------------------------------------
+// -- How a line was read: its first and last digits and the value they make.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    pub line: usize,
+    pub first: Match,
+    pub last: Match,
+    pub value: u32,
+}
+
+pub fn calibrate(line: &Line, lexicon: &DigitLexicon) -> Result<Calibration, Error> {
+    let text = line.as_str();
+
+    match lexicon.matches(text) {
+        Some((first, last)) => Ok(Calibration {
+            line: line.number(),
+            value: first.digit * RADIX + last.digit,
+            first,
+            last,
+        }),
+        None => Err(line.error("no digit on the line")),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- The input as a single block of bytes, borrowed rather than copied, with the lexicon
+// -- to read it with.
+pub struct Document<'a> {
+    pub lexicon: DigitLexicon,
+    pub text: &'a [u8],
+}
+
+impl<'a> Document<'a> {
+    pub fn new(input: &'a str, lexicon: DigitLexicon) -> Self {
+        let text = input.as_bytes();
+        Self { lexicon, text }
+    }
+
+    pub fn sum(&self) -> Result<u64, Error> {
+        Scanner::new(&self.lexicon).sum(self.text)
+    }
+}
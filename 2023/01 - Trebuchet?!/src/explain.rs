@@ -0,0 +1,106 @@
+use common::Error;
+
+use crate::calibration::calibrate;
+use crate::lexicon::{DigitLexicon, Match};
+
+// -- Part 1 only reads digits, part 2 also English words, as the solvers do.
+pub fn explain(input: &str, part: u8) -> Result<String, Error> {
+    let lexicon = match part {
+        1 => DigitLexicon::digits(),
+        _ => DigitLexicon::english(),
+    };
+
+    Ok(report(input, &lexicon))
+}
+
+// -----------------------------------------------------------------------------
+fn token(line: &str, found: &Match) -> String {
+    let kind = if found.word { "word" } else { "digit" };
+
+    format!(
+        "'{}' {} {}..{}",
+        &line[found.span.clone()],
+        kind,
+        found.span.start,
+        found.span.end
+    )
+}
+
+// -- One row per line with the tokens picked for it and their byte ranges; lines without
+// -- a digit are listed as errors and left out of the total.
+pub fn report(input: &str, lexicon: &DigitLexicon) -> String {
+    let mut rows = vec![[
+        String::from("line"),
+        String::from("first"),
+        String::from("last"),
+        String::from("value"),
+    ]];
+    let mut errors = vec![];
+    let mut total = 0;
+
+    for line in common::lines(input) {
+        match calibrate(&line, lexicon) {
+            Ok(calibration) => {
+                total += calibration.value;
+                rows.push([
+                    line.number().to_string(),
+                    token(&line, &calibration.first),
+                    token(&line, &calibration.last),
+                    calibration.value.to_string(),
+                ]);
+            }
+            Err(err) => {
+                errors.push(line.number());
+                rows.push([
+                    line.number().to_string(),
+                    format!("error: {}", err.message()),
+                    String::new(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+
+    let widths: Vec<usize> = (0..4)
+        .map(|column| {
+            rows.iter()
+                .filter(|row| !row[2].is_empty() || column == 0)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut report = String::new();
+
+    for row in &rows {
+        let line = if row[2].is_empty() {
+            format!("{:>w$}  {}", row[0], row[1], w = widths[0])
+        } else {
+            format!(
+                "{:>w0$}  {:w1$}  {:w2$}  {:>w3$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3]
+            )
+        };
+        report.push_str(line.trim_end());
+        report.push('\n');
+    }
+
+    let read = rows.len() - 1 - errors.len();
+    report.push_str(&format!("total {} from {} line(s)", total, read));
+
+    if !errors.is_empty() {
+        let numbers: Vec<String> = errors.iter().map(usize::to_string).collect();
+        report.push_str(&format!(", no digit on line(s) {}", numbers.join(", ")));
+    }
+
+    report.push('\n');
+    report
+}
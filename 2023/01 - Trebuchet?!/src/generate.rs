@@ -0,0 +1,33 @@
+use common::random::Rng;
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// -----------------------------------------------------------------------------
+// -- `size` lines mixing letters, spelled-out digits and digits. Every line holds at
+// -- least one real digit, so both parts have an answer.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut pieces: Vec<String> = (0..rng.range_usize(1..=6))
+            .map(|_| match rng.below(3) {
+                0 => {
+                    let len = rng.range_usize(1..=4);
+                    rng.word(len)
+                }
+                1 => String::from(*rng.choose(&WORDS)),
+                _ => rng.range(0..=9).to_string(),
+            })
+            .collect();
+
+        let position = rng.index(pieces.len() + 1);
+        pieces.insert(position, rng.range(0..=9).to_string());
+
+        input.push_str(&pieces.concat());
+        input.push('\n');
+    }
+
+    input
+}
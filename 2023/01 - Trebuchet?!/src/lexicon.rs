@@ -0,0 +1,366 @@
+use std::collections::VecDeque;
+use std::env;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use common::Error;
+
+const RADIX: u32 = 10;
+
+const ENGLISH: [&str; RADIX as usize] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const GERMAN: [&str; RADIX as usize] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+const FRENCH: [&str; RADIX as usize] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+
+const SPANISH: [&str; RADIX as usize] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+pub const BUILTINS: [(&str, [&str; RADIX as usize]); 4] = [
+    ("english", ENGLISH),
+    ("german", GERMAN),
+    ("french", FRENCH),
+    ("spanish", SPANISH),
+];
+
+// -- Lexicons to use instead of English, e.g. 'english,german' or 'english,dutch.txt'.
+pub const LEXICON_VAR: &str = "AOC_LEXICON";
+
+// -----------------------------------------------------------------------------
+// -- Aho-Corasick over the bytes of the words. Failure links are folded into a dense
+// -- transition table, so scanning is a single lookup per byte.
+#[derive(Clone, Debug)]
+struct Automaton {
+    next: Vec<[u32; 256]>,
+    // -- The longest and shortest words ending in each state, as (digit, length).
+    found: Vec<Option<Ends>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Ends {
+    longest: (u32, usize),
+    shortest: (u32, usize),
+}
+
+// -- The digit, start and length of a word found on a line.
+type Found = (u32, usize, usize);
+
+const MISSING: u32 = u32::MAX;
+
+impl Automaton {
+    fn new<'a, I>(words: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a [u8], u32)>,
+    {
+        let mut next = vec![[MISSING; 256]];
+        let mut found: Vec<Option<Ends>> = vec![None];
+
+        // -- The trie.
+        for (word, digit) in words {
+            let mut state = 0;
+
+            for &byte in word {
+                let child = next[state][usize::from(byte)];
+
+                state = if child == MISSING {
+                    next.push([MISSING; 256]);
+                    found.push(None);
+                    next[state][usize::from(byte)] = (next.len() - 1) as u32;
+                    next.len() - 1
+                } else {
+                    child as usize
+                };
+            }
+
+            let own = (digit, word.len());
+            found[state] = Some(Ends {
+                longest: own,
+                shortest: own,
+            });
+        }
+
+        // -- Breadth first, so a state's failure is complete before its children need it.
+        let mut fail = vec![0; next.len()];
+        let mut queue = VecDeque::new();
+
+        for child in next[0].iter_mut() {
+            match *child {
+                MISSING => *child = 0,
+                state => queue.push_back(state as usize),
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let fallbacks = next[fail[state]];
+
+            for (child, fallback) in next[state].iter_mut().zip(fallbacks) {
+                if *child == MISSING {
+                    *child = fallback;
+                    continue;
+                }
+
+                let child = *child as usize;
+                fail[child] = fallback as usize;
+
+                // -- Words ending at the failure state are suffixes, so shorter than any
+                // -- word of this state's own.
+                found[child] = match (found[child], found[fail[child]]) {
+                    (Some(own), Some(suffix)) => Some(Ends {
+                        longest: own.longest,
+                        shortest: suffix.shortest,
+                    }),
+                    (own, suffix) => own.or(suffix),
+                };
+                queue.push_back(child);
+            }
+        }
+
+        Self { next, found }
+    }
+
+    // -- The words starting first and starting last on the line, in a single pass: the
+    // -- longest word ending at a byte starts earliest and the shortest one latest. Ties
+    // -- go to the shorter word.
+    fn scan(&self, line: &[u8]) -> Option<(Found, Found)> {
+        let mut state = 0;
+        let mut first: Option<Found> = None;
+        let mut last: Option<Found> = None;
+
+        for (index, &byte) in line.iter().enumerate() {
+            state = self.next[state][usize::from(byte)] as usize;
+
+            let Some(ends) = self.found[state] else {
+                continue;
+            };
+
+            let (digit, len) = ends.longest;
+            let start = index + 1 - len;
+            if first.is_none_or(|(_, best, _)| start < best) {
+                first = Some((digit, start, len));
+            }
+
+            let (digit, len) = ends.shortest;
+            let start = index + 1 - len;
+            if last.is_none_or(|(_, best, _)| start > best) {
+                last = Some((digit, start, len));
+            }
+        }
+
+        first.zip(last)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- A digit found on a line, with the byte range of the digit or word it was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub digit: u32,
+    pub span: Range<usize>,
+    // -- Spelled out rather than written as a digit.
+    pub word: bool,
+}
+
+impl Match {
+    fn new(line: &str, digit: u32, span: Range<usize>) -> Self {
+        let word = !line[span.clone()].bytes().all(|byte| byte.is_ascii_digit());
+        Self { digit, span, word }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- Spelled-out digits alongside the digits themselves. A line's first digit is read
+// -- from the word starting first and its last digit from the word starting last, so
+// -- overlapping words like "eightwo" read 8 from the left and 2 from the right.
+#[derive(Clone, Debug)]
+pub struct DigitLexicon {
+    words: Vec<(String, u32)>,
+    automaton: Automaton,
+}
+
+impl DigitLexicon {
+    pub fn new<I, S>(words: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        let mut lexicon: Vec<(String, u32)> = vec![];
+
+        for (word, digit) in words {
+            let word = word.into();
+
+            if word.is_empty() {
+                return Err(Error::new("empty word in lexicon"));
+            }
+            // -- The digits are always read as themselves, a word can't redefine them.
+            if word.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(Error::new(format!("'{}' is a number, not a word", word)));
+            }
+            if digit >= RADIX {
+                return Err(Error::new(format!("'{}' is not a digit", digit)));
+            }
+
+            match lexicon.iter().find(|(known, _)| *known == word) {
+                Some((_, known)) if *known != digit => {
+                    return Err(Error::new(format!(
+                        "'{}' stands for both {} and {}",
+                        word, known, digit
+                    )))
+                }
+                Some(_) => {}
+                None => lexicon.push((word, digit)),
+            }
+        }
+
+        let mut all: Vec<(String, u32)> =
+            (0..RADIX).map(|digit| (digit.to_string(), digit)).collect();
+        all.extend(lexicon.iter().cloned());
+
+        let automaton = Automaton::new(all.iter().map(|(word, digit)| (word.as_bytes(), *digit)));
+
+        Ok(Self {
+            words: lexicon,
+            automaton,
+        })
+    }
+
+    // -- Only the digits themselves, as part 1 reads them.
+    pub fn digits() -> Self {
+        Self::new(Vec::<(String, u32)>::new()).unwrap()
+    }
+
+    // -- "english", "german", "french" or "spanish".
+    pub fn builtin(name: &str) -> Option<Self> {
+        let (_, table) = BUILTINS.iter().find(|(builtin, _)| *builtin == name)?;
+        Some(Self::table(table))
+    }
+
+    fn table(table: &[&str; RADIX as usize]) -> Self {
+        Self::new(table.iter().zip(0..).map(|(word, digit)| (*word, digit))).unwrap()
+    }
+
+    pub fn english() -> Self {
+        Self::table(&ENGLISH)
+    }
+
+    pub fn german() -> Self {
+        Self::table(&GERMAN)
+    }
+
+    pub fn french() -> Self {
+        Self::table(&FRENCH)
+    }
+
+    pub fn spanish() -> Self {
+        Self::table(&SPANISH)
+    }
+
+    // -- One word and its digit per line, e.g. "drie 3". Blank lines and lines starting
+    // -- with '#' are skipped, and a digit may have several words.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut words = vec![];
+
+        for line in common::lines(text) {
+            let trimmed = line.as_str().trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = trimmed.split_whitespace();
+            let word = line.expect(fields.next(), "a word")?;
+            let digit = line.expect(fields.next(), "a digit")?;
+
+            if let Some(extra) = fields.next() {
+                return Err(line.error_in(extra, "expected a word and a digit"));
+            }
+
+            let digit = match digit.parse() {
+                Ok(digit) if digit < RADIX => digit,
+                _ => return Err(line.error_in(digit, "expected a digit")),
+            };
+
+            words.push((word, digit));
+        }
+
+        Self::new(words)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| Error::new(format!("unable to read '{}': {}", path.display(), err)))?;
+
+        Self::parse(&text)
+            .map_err(|err| Error::new(format!("in lexicon '{}': {}", path.display(), err)))
+    }
+
+    // -- Comma-separated built-in names and word list files, merged into one lexicon.
+    pub fn from_spec(spec: &str) -> Result<Self, Error> {
+        let mut words = vec![];
+
+        for part in spec.split(',').map(str::trim) {
+            let lexicon = match Self::builtin(part) {
+                Some(lexicon) => lexicon,
+                None if Path::new(part).is_file() => Self::load(Path::new(part))?,
+                None => {
+                    let names: Vec<&str> = BUILTINS.iter().map(|(name, _)| *name).collect();
+                    return Err(Error::new(format!(
+                        "unknown lexicon '{}', expected one of {} or a word list file",
+                        part,
+                        names.join(", ")
+                    )));
+                }
+            };
+            words.extend(lexicon.words);
+        }
+
+        Self::new(words)
+    }
+
+    // -- English unless AOC_LEXICON says otherwise; for the binaries, the library itself
+    // -- never reads the environment.
+    pub fn from_env() -> Result<Self, Error> {
+        match env::var(LEXICON_VAR) {
+            Ok(spec) if !spec.trim().is_empty() => Self::from_spec(&spec),
+            _ => Ok(Self::english()),
+        }
+    }
+
+    // -- The spelled-out words; the digits themselves are always known.
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    pub fn first(&self, line: &str) -> Option<u32> {
+        self.value(line.as_bytes()).map(|value| value / RADIX)
+    }
+
+    pub fn last(&self, line: &str) -> Option<u32> {
+        self.value(line.as_bytes()).map(|value| value % RADIX)
+    }
+
+    // -- The calibration value of a line: its first digit followed by its last.
+    pub fn value(&self, line: &[u8]) -> Option<u32> {
+        let ((first, _, _), (last, _, _)) = self.automaton.scan(line)?;
+        Some(first * RADIX + last)
+    }
+
+    // -- The first and last digits with where they were found.
+    pub fn matches(&self, line: &str) -> Option<(Match, Match)> {
+        let (first, last) = self.automaton.scan(line.as_bytes())?;
+        let found = |(digit, start, len): Found| Match::new(line, digit, start..start + len);
+        Some((found(first), found(last)))
+    }
+}
+
+impl Default for DigitLexicon {
+    fn default() -> Self {
+        Self::english()
+    }
+}
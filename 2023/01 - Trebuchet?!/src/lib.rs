@@ -0,0 +1,8 @@
+pub mod calibration;
+pub mod explain;
+pub mod generate;
+pub mod lexicon;
+pub mod part1;
+pub mod part2;
+pub mod reference;
+pub mod scanner;
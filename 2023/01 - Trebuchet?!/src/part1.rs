@@ -0,0 +1,17 @@
+use common::{Answer, Error};
+
+use crate::calibration::Document;
+use crate::lexicon::DigitLexicon;
+
+// -- Only digits count, spelled-out ones are left to part 2.
+pub fn parse(input: &str) -> Result<Document<'_>, Error> {
+    Ok(Document::new(input, DigitLexicon::digits()))
+}
+
+pub fn answer(document: &Document) -> Result<Answer, Error> {
+    Ok(document.sum()?.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
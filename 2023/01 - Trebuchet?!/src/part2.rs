@@ -0,0 +1,22 @@
+use common::{Answer, Error};
+
+use crate::calibration::Document;
+use crate::lexicon::DigitLexicon;
+
+// -- Spelled-out digits are read in English, so the answer never depends on the
+// -- environment; `parse_with` takes any other lexicon.
+pub fn parse(input: &str) -> Result<Document<'_>, Error> {
+    parse_with(input, DigitLexicon::english())
+}
+
+pub fn parse_with(input: &str, lexicon: DigitLexicon) -> Result<Document<'_>, Error> {
+    Ok(Document::new(input, lexicon))
+}
+
+pub fn answer(document: &Document) -> Result<Answer, Error> {
+    Ok(document.sum()?.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
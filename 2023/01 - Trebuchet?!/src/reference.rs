@@ -0,0 +1,62 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// -- Every digit on the line, in order, optionally counting spelled out ones.
+fn digits(line: &str, spelled: bool) -> Vec<u32> {
+    line.char_indices()
+        .filter_map(|(index, ch)| {
+            let rest = &line[index..];
+
+            ch.to_digit(10).or_else(|| {
+                WORDS
+                    .iter()
+                    .position(|word| spelled && rest.starts_with(word))
+                    .map(|position| position as u32)
+            })
+        })
+        .collect()
+}
+
+fn calibrate(input: &str, spelled: bool) -> Result<Answer, Error> {
+    let mut sum = 0;
+
+    for line in input.lines() {
+        let digits = digits(line, spelled);
+        let (first, last) = digits
+            .first()
+            .zip(digits.last())
+            .ok_or_else(|| Error::new(format!("no digit in '{}'", line)))?;
+        sum += first * 10 + last;
+    }
+
+    Ok(sum.into())
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    calibrate(input, false)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    calibrate(input, true)
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
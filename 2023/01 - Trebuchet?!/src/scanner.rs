@@ -0,0 +1,153 @@
+use std::io::Read;
+use std::ops::Range;
+
+use common::pool::Pool;
+use common::{Error, Line};
+
+use crate::lexicon::DigitLexicon;
+
+// -- Large enough that handing a chunk to a worker costs next to nothing.
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+// -----------------------------------------------------------------------------
+// -- What a chunk of whole lines adds up to, with the first line that had no digit.
+#[derive(Clone, Debug, Default)]
+struct Tally {
+    sum: u64,
+    lines: usize,
+    missing: Option<(usize, Range<usize>)>,
+}
+
+fn tally(lexicon: &DigitLexicon, chunk: &[u8]) -> Tally {
+    let mut tally = Tally::default();
+    let mut offset = 0;
+
+    if chunk.is_empty() {
+        return tally;
+    }
+
+    // -- A final newline ends the last line rather than starting an empty one.
+    let chunk = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+
+    for line in chunk.split(|&byte| byte == b'\n') {
+        match lexicon.value(line) {
+            Some(value) => tally.sum += u64::from(value),
+            None if tally.missing.is_none() => {
+                tally.missing = Some((tally.lines, offset..offset + line.len()));
+            }
+            None => {}
+        }
+
+        tally.lines += 1;
+        offset += line.len() + 1;
+    }
+
+    tally
+}
+
+// -- Cuts the input into chunks of about `size` bytes, each ending with a whole line.
+fn chunks(input: &[u8], size: usize) -> Vec<&[u8]> {
+    let mut chunks = vec![];
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let cut = match rest.get(size..) {
+            Some(tail) => tail
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(rest.len(), |newline| size + newline + 1),
+            None => rest.len(),
+        };
+
+        let (chunk, tail) = rest.split_at(cut);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+// -----------------------------------------------------------------------------
+// -- Sums calibration values straight off the bytes, without splitting the input into
+// -- strings. Chunks of whole lines are scanned in parallel, then added up in order so
+// -- a line without a digit is reported with its number.
+#[derive(Clone, Copy, Debug)]
+pub struct Scanner<'a> {
+    pub lexicon: &'a DigitLexicon,
+    pub pool: Pool,
+    pub chunk_size: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(lexicon: &'a DigitLexicon) -> Self {
+        Self {
+            lexicon,
+            pool: Pool::available(),
+            chunk_size: CHUNK_SIZE,
+        }
+    }
+
+    // -- Adds the lines of `batch` to `total`, `lines` being the number of lines before it.
+    fn add(&self, batch: &[u8], total: &mut u64, lines: &mut usize) -> Result<(), Error> {
+        let chunks = chunks(batch, self.chunk_size.max(1));
+        let tallies = self.pool.map(&chunks, |chunk| tally(self.lexicon, chunk));
+
+        for (chunk, tally) in chunks.iter().zip(tallies) {
+            if let Some((index, span)) = tally.missing {
+                let text = &chunk[span];
+                let text = String::from_utf8_lossy(text.strip_suffix(b"\r").unwrap_or(text));
+                let line = Line::new(*lines + index + 1, &text);
+                return Err(line.error("no digit on the line"));
+            }
+
+            *total += tally.sum;
+            *lines += tally.lines;
+        }
+
+        Ok(())
+    }
+
+    pub fn sum(&self, input: &[u8]) -> Result<u64, Error> {
+        let (mut total, mut lines) = (0, 0);
+        self.add(input, &mut total, &mut lines)?;
+        Ok(total)
+    }
+
+    // -- Reads a chunk per worker at a time, carrying any partial last line over to the
+    // -- next read, so inputs far larger than memory can be summed.
+    pub fn sum_reader<R: Read>(&self, mut reader: R) -> Result<u64, Error> {
+        let batch_size = self.chunk_size.max(1) * self.pool.workers();
+        let mut buffer = Vec::with_capacity(batch_size);
+        let (mut total, mut lines) = (0, 0);
+
+        loop {
+            let wanted = match batch_size.checked_sub(buffer.len()) {
+                Some(0) | None => batch_size,
+                Some(wanted) => wanted,
+            };
+            let read = reader
+                .by_ref()
+                .take(wanted as u64)
+                .read_to_end(&mut buffer)
+                .map_err(|err| Error::new(format!("unable to read input: {}", err)))?;
+            let done = read < wanted;
+
+            let cut = if done {
+                buffer.len()
+            } else {
+                match buffer.iter().rposition(|&byte| byte == b'\n') {
+                    Some(newline) => newline + 1,
+                    // -- A line longer than a whole batch; keep reading until it ends.
+                    None => continue,
+                }
+            };
+
+            self.add(&buffer[..cut], &mut total, &mut lines)?;
+            buffer.drain(..cut);
+
+            if done {
+                return Ok(total);
+            }
+        }
+    }
+}
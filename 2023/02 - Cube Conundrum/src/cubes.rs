@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use common::{Error, Line};
+
+// -----------------------------------------------------------------------------
+// -- A multiset of cubes keyed by color. Colors are any text, "dark red" included, and
+// -- a color can be present with no cubes at all.
+#[derive(Clone, Debug, Default)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, color: &str, count: u32) {
+        *self.counts.entry(String::from(color)).or_default() += count;
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    fn present(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.colors().filter(|(_, count)| *count > 0)
+    }
+
+    // -- The most cubes of every color in either, i.e. the multiset union.
+    pub fn union(&self, other: &Cubes) -> Cubes {
+        let mut union = self.clone();
+
+        for (color, count) in other.colors() {
+            let most = union.counts.entry(String::from(color)).or_default();
+            *most = (*most).max(count);
+        }
+
+        union
+    }
+
+    // -- Whether the bag could never have held these cubes.
+    pub fn invalid(&self, bag: &Bag) -> bool {
+        !bag.allows(self)
+    }
+
+    // -- The product of the counts of red, green, blue and every other color present, so
+    // -- cubes missing one of the puzzle's colors have a power of 0.
+    pub fn power(&self) -> u64 {
+        let standard = Bag::standard();
+        let others = self
+            .present()
+            .filter(|(color, _)| standard.limits().count(color) == 0);
+
+        standard
+            .limits()
+            .colors()
+            .map(|(color, _)| self.count(color))
+            .chain(others.map(|(_, count)| count))
+            .map(u64::from)
+            .product()
+    }
+
+    // -- "3 blue, 4 dark red"; a color given twice adds up.
+    pub fn parse(line: &Line, text: &str) -> Result<Self, Error> {
+        let mut cubes = Cubes::new();
+
+        for entry in text.split(',') {
+            let entry = entry.trim();
+            let (count, color) = entry
+                .split_once(' ')
+                .ok_or_else(|| line.error_in(entry, "expected a cube count and color"))?;
+
+            let count: u32 = line.parse(count)?;
+            let color = color.trim();
+            if color.is_empty() {
+                return Err(line.error_in(entry, "expected a cube color"));
+            }
+
+            cubes.add(color, count);
+        }
+
+        Ok(cubes)
+    }
+}
+
+// -- No cubes of a color is the same as not mentioning it.
+impl PartialEq for Cubes {
+    fn eq(&self, other: &Self) -> bool {
+        self.present().eq(other.present())
+    }
+}
+
+impl Eq for Cubes {}
+
+// -- In the same form as a round, colors in alphabetical order.
+impl Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self
+            .colors()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect();
+
+        write!(f, "{}", entries.join(", "))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- What the bag holds. A color it does not mention is one it has no cubes of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bag {
+    limits: Cubes,
+}
+
+impl Bag {
+    pub fn new(limits: Cubes) -> Self {
+        Self { limits }
+    }
+
+    // -- The puzzle's bag: 12 red, 13 green and 14 blue cubes.
+    pub fn standard() -> Self {
+        let mut limits = Cubes::new();
+        limits.add("red", 12);
+        limits.add("green", 13);
+        limits.add("blue", 14);
+        Self { limits }
+    }
+
+    pub fn limits(&self) -> &Cubes {
+        &self.limits
+    }
+
+    pub fn allows(&self, cubes: &Cubes) -> bool {
+        cubes
+            .colors()
+            .all(|(color, count)| count <= self.limits.count(color))
+    }
+
+    // -- In the same form as a round, e.g. "12 red, 13 green, 14 blue".
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let line = Line::new(1, text);
+        Ok(Self::new(Cubes::parse(&line, &line)?))
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Cubes>,
+}
+
+impl Game {
+    // -- "Game 7: 3 blue, 4 red; 1 red, 2 green".
+    pub fn parse(line: &Line) -> Result<Self, Error> {
+        let (game, record) = line
+            .split_once(':')
+            .ok_or_else(|| line.error("expected 'Game N: ' prefix"))?;
+
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| line.error_in(game, "expected 'Game N: ' prefix"))?;
+        let id = line.parse(id.trim())?;
+
+        let rounds = record
+            .split(';')
+            .map(|round| Cubes::parse(line, round))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { id, rounds })
+    }
+
+    pub fn possible(&self, bag: &Bag) -> bool {
+        !self.rounds.iter().any(|round| round.invalid(bag))
+    }
+
+    // -- The fewest cubes of each color that make every round possible.
+    pub fn fewest(&self) -> Cubes {
+        self.rounds
+            .iter()
+            .fold(Cubes::new(), |fewest, round| fewest.union(round))
+    }
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, Error> {
+    common::lines(input)
+        .map(|line| Game::parse(&line))
+        .collect()
+}
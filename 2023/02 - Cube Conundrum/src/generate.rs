@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+// -----------------------------------------------------------------------------
+// -- `size` games of one to six rounds, each showing up to 20 cubes of some colors.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for game in 1..=size.max(1) {
+        let rounds: Vec<String> = (0..rng.range_usize(1..=6))
+            .map(|_| {
+                let mut colors = COLORS;
+                rng.shuffle(&mut colors);
+
+                colors[..rng.range_usize(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        input.push_str(&format!("Game {}: {}\n", game, rounds.join("; ")));
+    }
+
+    input
+}
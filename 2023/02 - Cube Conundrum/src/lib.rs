@@ -0,0 +1,6 @@
+pub mod cubes;
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod query;
+pub mod reference;
@@ -0,0 +1,33 @@
+use common::{Answer, Error};
+
+use crate::cubes::{parse_games, Bag, Game};
+
+// -----------------------------------------------------------------------------
+pub struct Record {
+    pub bag: Bag,
+    pub games: Vec<Game>,
+}
+
+pub fn parse(input: &str) -> Result<Record, Error> {
+    parse_with(input, Bag::standard())
+}
+
+pub fn parse_with(input: &str, bag: Bag) -> Result<Record, Error> {
+    let games = parse_games(input)?;
+    Ok(Record { bag, games })
+}
+
+pub fn answer(record: &Record) -> Result<Answer, Error> {
+    let sum: u32 = record
+        .games
+        .iter()
+        .filter(|game| game.possible(&record.bag))
+        .map(|game| game.id)
+        .sum();
+
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
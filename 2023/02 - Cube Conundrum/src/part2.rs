@@ -0,0 +1,19 @@
+use common::{Answer, Error};
+
+use crate::cubes::{parse_games, Game};
+
+pub fn parse(input: &str) -> Result<Vec<Game>, Error> {
+    parse_games(input)
+}
+
+// -- Each game's power is taken over its own colors, so a color only another game shows
+// -- changes nothing.
+pub fn answer(games: &[Game]) -> Result<Answer, Error> {
+    let sum: u64 = games.iter().map(|game| game.fewest().power()).sum();
+
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
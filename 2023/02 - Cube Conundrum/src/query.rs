@@ -0,0 +1,584 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+
+use common::{Error, Line};
+
+use crate::cubes::{parse_games, Bag, Cubes, Game};
+
+// -----------------------------------------------------------------------------
+// -- Questions about a game log, e.g.
+// --
+// --   games where any round blue > 10
+// --   games where id in 5..20 | fewest
+// --   rounds where id = 42 and exceeds {12 red, 13 green}
+// --
+// -- A query picks `games` or `rounds`, keeps those matching its `where` condition and
+// -- hands them to an aggregate: `list` (the default), `count`, `sum id`, `sum power`
+// -- or `fewest`, the smallest bag that allows all of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Query {
+    pub subject: Subject,
+    pub filter: Option<Expr>,
+    pub aggregate: Aggregate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subject {
+    Games,
+    Rounds,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    List,
+    Count,
+    SumId,
+    SumPower,
+    Fewest,
+}
+
+// -- Games are measured by their fewest cubes, rounds by their own. A round's id is the
+// -- id of its game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Id,
+    Round,
+    Power,
+    Color(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+use Cmp::*;
+
+impl Cmp {
+    fn holds(self, left: u64, right: u64) -> bool {
+        match self {
+            Lt => left < right,
+            Le => left <= right,
+            Gt => left > right,
+            Ge => left >= right,
+            Eq => left == right,
+            Ne => left != right,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Compare(Value, Cmp, u64),
+    // -- Both ends included.
+    Between(Value, u64, u64),
+    Within(Bag),
+    Exceeds(Bag),
+    AnyRound(Box<Expr>),
+    AllRounds(Box<Expr>),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Word(String),
+    Number(u64),
+    // -- A color in double quotes, for the ones with spaces or named like a keyword.
+    Quoted(String),
+    // -- A bag in braces, kept as text for `Bag::parse`.
+    Braced(String),
+    Symbol(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: Kind,
+    offset: usize,
+    len: usize,
+}
+
+const SYMBOLS: [&str; 10] = ["..", "<=", ">=", "!=", "<", ">", "=", "(", ")", "|"];
+
+const COMPARISONS: [(&str, Cmp); 6] = [
+    ("<", Lt),
+    ("<=", Le),
+    (">", Gt),
+    (">=", Ge),
+    ("=", Eq),
+    ("!=", Ne),
+];
+
+fn tokenize(line: &Line) -> Result<Vec<Token>, Error> {
+    let text = line.as_str();
+    let mut tokens = vec![];
+    let mut offset = 0;
+
+    while let Some(ch) = text[offset..].chars().next() {
+        let rest = &text[offset..];
+
+        if ch.is_whitespace() {
+            offset += ch.len_utf8();
+            continue;
+        }
+
+        let (kind, len) = if ch.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            (Kind::Number(line.parse(&rest[..len])?), len)
+        } else if ch.is_alphabetic() {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+                .unwrap_or(rest.len());
+            (Kind::Word(String::from(&rest[..len])), len)
+        } else if ch == '"' || ch == '{' {
+            let close = if ch == '"' { '"' } else { '}' };
+            let Some(end) = rest[1..].find(close) else {
+                return Err(line.error_at(offset, 1, format!("missing closing '{}'", close)));
+            };
+
+            let inner = String::from(&rest[1..end + 1]);
+            match ch {
+                '"' => (Kind::Quoted(inner), end + 2),
+                _ => (Kind::Braced(inner), end + 2),
+            }
+        } else {
+            let symbol = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol));
+            match symbol {
+                Some(symbol) => (Kind::Symbol(symbol), symbol.len()),
+                None => {
+                    let len = ch.len_utf8();
+                    return Err(line.error_at(offset, len, format!("unexpected '{}'", ch)));
+                }
+            }
+        };
+
+        tokens.push(Token { kind, offset, len });
+        offset += len;
+    }
+
+    Ok(tokens)
+}
+
+// -----------------------------------------------------------------------------
+// -- Recursive descent, loosest binding first:
+// --
+// --   query     := ("games" | "rounds") ["where" or] ["|" aggregate]
+// --   or        := and {"or" and}
+// --   and       := factor {"and" factor}
+// --   factor    := "not" factor | "(" or ")" | "any" "round" factor
+// --              | "all" "rounds" factor | ("within" | "exceeds") bag
+// --              | value cmp number | value "in" number ".." number
+// --   value     := "id" | "round" | "power" | color
+// --   aggregate := "list" | "count" | "sum" ("id" | "power") | "fewest"
+struct Parser<'a> {
+    line: Line<'a>,
+    tokens: Vec<Token>,
+    position: usize,
+    // -- The colors a condition may name, so a misspelled keyword is not taken for one.
+    colors: &'a BTreeSet<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Kind> {
+        self.tokens.get(self.position).map(|token| &token.kind)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn error(&self, token: Option<&Token>, message: impl Into<String>) -> Error {
+        match token {
+            Some(token) => self.line.error_at(token.offset, token.len, message),
+            None => self.line.error_at_end(message),
+        }
+    }
+
+    // -- The next token if it is the given keyword.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let found = matches!(self.peek(), Some(Kind::Word(word)) if word == keyword);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    // -- An error at the token about to be read.
+    fn unexpected(&self, message: impl Into<String>) -> Error {
+        self.error(self.tokens.get(self.position), message)
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        match self.keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.unexpected(format!("expected '{}'", keyword))),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), Error> {
+        match self.next() {
+            Some(Token {
+                kind: Kind::Symbol(found),
+                ..
+            }) if found == symbol => Ok(()),
+            token => Err(self.error(token.as_ref(), format!("expected '{}'", symbol))),
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, Error> {
+        match self.next() {
+            Some(Token {
+                kind: Kind::Number(number),
+                ..
+            }) => Ok(number),
+            token => Err(self.error(token.as_ref(), "expected a number")),
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, Error> {
+        let subject = if self.keyword("games") {
+            Subject::Games
+        } else if self.keyword("rounds") {
+            Subject::Rounds
+        } else {
+            return Err(self.unexpected("expected 'games' or 'rounds'"));
+        };
+
+        let filter = match self.keyword("where") {
+            true => Some(self.or(subject)?),
+            false => None,
+        };
+
+        let aggregate = match self.peek() {
+            Some(Kind::Symbol("|")) => {
+                self.position += 1;
+                self.aggregate()?
+            }
+            _ => Aggregate::List,
+        };
+
+        if self.peek().is_some() {
+            return Err(self.unexpected("unexpected text after the query"));
+        }
+
+        Ok(Query {
+            subject,
+            filter,
+            aggregate,
+        })
+    }
+
+    fn or(&mut self, subject: Subject) -> Result<Expr, Error> {
+        let mut expr = self.and(subject)?;
+        while self.keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and(subject)?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self, subject: Subject) -> Result<Expr, Error> {
+        let mut expr = self.factor(subject)?;
+        while self.keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.factor(subject)?));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self, subject: Subject) -> Result<Expr, Error> {
+        let Some(token) = self.next() else {
+            return Err(self.error(None, "expected a condition"));
+        };
+
+        let word = match &token.kind {
+            Kind::Symbol("(") => {
+                let expr = self.or(subject)?;
+                self.expect_symbol(")")?;
+                return Ok(expr);
+            }
+            Kind::Word(word) => word.as_str(),
+            Kind::Quoted(color) => {
+                let color = self.color(&token, color)?;
+                return self.compare(color);
+            }
+            _ => return Err(self.error(Some(&token), "expected a condition")),
+        };
+
+        match word {
+            "not" => Ok(Expr::Not(Box::new(self.factor(subject)?))),
+            "any" | "all" if subject == Subject::Rounds => {
+                Err(self.error(Some(&token), "rounds have no rounds of their own"))
+            }
+            "any" => {
+                self.expect_keyword("round")?;
+                Ok(Expr::AnyRound(Box::new(self.factor(Subject::Rounds)?)))
+            }
+            "all" => {
+                self.expect_keyword("rounds")?;
+                Ok(Expr::AllRounds(Box::new(self.factor(Subject::Rounds)?)))
+            }
+            "within" | "exceeds" => {
+                let bag = match self.next() {
+                    Some(Token {
+                        kind: Kind::Braced(text),
+                        offset,
+                        ..
+                    }) => Bag::parse(&text).map_err(|err| {
+                        let message = format!("bad bag: {}", err.message());
+                        self.line.error_at(offset, text.len() + 2, message)
+                    })?,
+                    token => return Err(self.error(token.as_ref(), "expected a bag like {12 red}")),
+                };
+
+                match word {
+                    "within" => Ok(Expr::Within(bag)),
+                    _ => Ok(Expr::Exceeds(bag)),
+                }
+            }
+            "round" if subject == Subject::Games => Err(self.error(
+                Some(&token),
+                "'round' numbers rounds, use 'any round' or 'all rounds' on games",
+            )),
+            "id" => self.compare(Value::Id),
+            "round" => self.compare(Value::Round),
+            "power" => self.compare(Value::Power),
+            color => {
+                let color = self.color(&token, color)?;
+                self.compare(color)
+            }
+        }
+    }
+
+    fn color(&self, token: &Token, color: &str) -> Result<Value, Error> {
+        if self.colors.contains(color) {
+            return Ok(Value::Color(String::from(color)));
+        }
+
+        let known: Vec<&str> = self.colors.iter().map(String::as_str).collect();
+        Err(self.error(
+            Some(token),
+            format!(
+                "'{}' is neither a keyword nor a color of the input ({})",
+                color,
+                known.join(", ")
+            ),
+        ))
+    }
+
+    fn compare(&mut self, value: Value) -> Result<Expr, Error> {
+        if self.keyword("in") {
+            let from = self.number()?;
+            self.expect_symbol("..")?;
+            let to = self.number()?;
+            return Ok(Expr::Between(value, from, to));
+        }
+
+        let cmp = COMPARISONS
+            .iter()
+            .find(|(symbol, _)| self.peek() == Some(&Kind::Symbol(symbol)))
+            .map(|(_, cmp)| *cmp)
+            .ok_or_else(|| {
+                self.unexpected("expected a comparison (<, <=, >, >=, =, !=) or 'in'")
+            })?;
+        self.position += 1;
+
+        Ok(Expr::Compare(value, cmp, self.number()?))
+    }
+
+    fn aggregate(&mut self) -> Result<Aggregate, Error> {
+        if self.keyword("list") {
+            Ok(Aggregate::List)
+        } else if self.keyword("count") {
+            Ok(Aggregate::Count)
+        } else if self.keyword("fewest") {
+            Ok(Aggregate::Fewest)
+        } else if !self.keyword("sum") {
+            Err(self.unexpected("expected 'list', 'count', 'sum id', 'sum power' or 'fewest'"))
+        } else if self.keyword("id") {
+            Ok(Aggregate::SumId)
+        } else if self.keyword("power") {
+            Ok(Aggregate::SumPower)
+        } else {
+            Err(self.unexpected("expected 'id' or 'power'"))
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- What a condition is checked against: a game with its fewest cubes, or one of its
+// -- rounds, numbered from 1.
+struct Item<'a> {
+    game: &'a Game,
+    round: Option<usize>,
+    cubes: Cow<'a, Cubes>,
+}
+
+impl<'a> Item<'a> {
+    fn game(game: &'a Game) -> Self {
+        Self {
+            game,
+            round: None,
+            cubes: Cow::Owned(game.fewest()),
+        }
+    }
+
+    fn rounds(game: &'a Game) -> impl Iterator<Item = Item<'a>> {
+        game.rounds
+            .iter()
+            .enumerate()
+            .map(move |(index, cubes)| Item {
+                game,
+                round: Some(index + 1),
+                cubes: Cow::Borrowed(cubes),
+            })
+    }
+
+    fn value(&self, value: &Value) -> u64 {
+        match value {
+            Value::Id => u64::from(self.game.id),
+            Value::Round => self.round.unwrap_or(0) as u64,
+            Value::Power => self.cubes.power(),
+            Value::Color(color) => u64::from(self.cubes.count(color)),
+        }
+    }
+}
+
+impl Expr {
+    fn matches(&self, item: &Item) -> bool {
+        match self {
+            Expr::Compare(value, cmp, number) => cmp.holds(item.value(value), *number),
+            Expr::Between(value, from, to) => (*from..=*to).contains(&item.value(value)),
+            Expr::Within(bag) => bag.allows(&item.cubes),
+            Expr::Exceeds(bag) => !bag.allows(&item.cubes),
+            Expr::AnyRound(expr) => Item::rounds(item.game).any(|round| expr.matches(&round)),
+            Expr::AllRounds(expr) => Item::rounds(item.game).all(|round| expr.matches(&round)),
+            Expr::Not(expr) => !expr.matches(item),
+            Expr::And(left, right) => left.matches(item) && right.matches(item),
+            Expr::Or(left, right) => left.matches(item) || right.matches(item),
+        }
+    }
+}
+
+// -- The puzzle's red, green and blue and every other color seen in any game.
+fn colors(games: &[Game]) -> BTreeSet<String> {
+    let standard = Bag::standard();
+    let rounds = games.iter().flat_map(|game| &game.rounds);
+
+    std::iter::once(standard.limits())
+        .chain(rounds)
+        .flat_map(|cubes| cubes.colors().map(|(color, _)| String::from(color)))
+        .collect()
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    Games(Vec<Game>),
+    // -- The game id, the round number and its cubes.
+    Rounds(Vec<(u32, usize, Cubes)>),
+    Number(u64),
+    Bag(Cubes),
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Games(games) => {
+                for game in games {
+                    let rounds: Vec<String> = game.rounds.iter().map(Cubes::to_string).collect();
+                    writeln!(f, "Game {}: {}", game.id, rounds.join("; "))?;
+                }
+                Ok(())
+            }
+            Output::Rounds(rounds) => {
+                for (id, round, cubes) in rounds {
+                    writeln!(f, "Game {} round {}: {}", id, round, cubes)?;
+                }
+                Ok(())
+            }
+            Output::Number(number) => writeln!(f, "{}", number),
+            Output::Bag(cubes) if cubes.colors().next().is_none() => writeln!(f, "no cubes"),
+            Output::Bag(cubes) => writeln!(f, "{}", cubes),
+        }
+    }
+}
+
+impl Query {
+    // -- Conditions may only name red, green and blue; `parse_for` knows the input's colors.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        Self::parse_for(text, &[])
+    }
+
+    // -- Conditions may name any color the games show, as well as red, green and blue.
+    pub fn parse_for(text: &str, games: &[Game]) -> Result<Self, Error> {
+        let colors = colors(games);
+        let line = Line::new(1, text);
+        let tokens = tokenize(&line)?;
+        let mut parser = Parser {
+            line,
+            tokens,
+            position: 0,
+            colors: &colors,
+        };
+
+        parser.query()
+    }
+
+    pub fn run(&self, games: &[Game]) -> Output {
+        let keep = |item: &Item| {
+            self.filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(item))
+        };
+
+        let items: Vec<Item> = match self.subject {
+            Subject::Games => games.iter().map(Item::game).filter(keep).collect(),
+            Subject::Rounds => games.iter().flat_map(Item::rounds).filter(keep).collect(),
+        };
+
+        match self.aggregate {
+            Aggregate::List if self.subject == Subject::Games => {
+                Output::Games(items.iter().map(|item| item.game.clone()).collect())
+            }
+            Aggregate::List => Output::Rounds(
+                items
+                    .into_iter()
+                    .map(|item| {
+                        let round = item.round.unwrap_or(0);
+                        (item.game.id, round, item.cubes.into_owned())
+                    })
+                    .collect(),
+            ),
+            Aggregate::Count => Output::Number(items.len() as u64),
+            Aggregate::SumId => {
+                Output::Number(items.iter().map(|item| u64::from(item.game.id)).sum())
+            }
+            Aggregate::SumPower => {
+                Output::Number(items.iter().map(|item| item.value(&Value::Power)).sum())
+            }
+            Aggregate::Fewest => Output::Bag(
+                items
+                    .iter()
+                    .fold(Cubes::new(), |fewest, item| fewest.union(&item.cubes)),
+            ),
+        }
+    }
+}
+
+// -- The entry point behind 'aoc query'.
+pub fn query(input: &str, text: &str) -> Result<String, Error> {
+    let games = parse_games(input)?;
+    let query = Query::parse_for(text, &games)?;
+    Ok(query.run(&games).to_string())
+}
@@ -0,0 +1,64 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+const BAG: [u32; 3] = [12, 13, 14];
+
+// -- The id of every game and the most cubes of each color shown in any of its draws.
+fn games(input: &str) -> Result<Vec<(u32, [u32; 3])>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let bad = || Error::new(format!("malformed game '{}'", line));
+            let (game, draws) = line.split_once(": ").ok_or_else(bad)?;
+            let id = game
+                .strip_prefix("Game ")
+                .and_then(|id| id.parse().ok())
+                .ok_or_else(bad)?;
+
+            let mut most = [0; 3];
+            for cubes in draws.split([',', ';']) {
+                let (count, color) = cubes.trim().split_once(' ').ok_or_else(bad)?;
+                let count: u32 = count.parse().map_err(|_| bad())?;
+                let index = COLORS.iter().position(|c| *c == color).ok_or_else(bad)?;
+                most[index] = most[index].max(count);
+            }
+
+            Ok((id, most))
+        })
+        .collect()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let sum: u32 = games(input)?
+        .iter()
+        .filter(|(_, most)| most.iter().zip(BAG).all(|(count, limit)| *count <= limit))
+        .map(|(id, _)| id)
+        .sum();
+    Ok(sum.into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let sum: u32 = games(input)?
+        .iter()
+        .map(|(_, most)| most.iter().product::<u32>())
+        .sum();
+    Ok(sum.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
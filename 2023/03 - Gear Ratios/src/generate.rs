@@ -0,0 +1,36 @@
+use common::random::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` schematic of numbers up to three digits, scattered symbols and
+// -- plenty of gears. Numbers never touch each other on the same row.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut row = String::new();
+
+        while row.len() < size {
+            let remaining = size - row.len();
+            let after_digit = row.ends_with(|ch: char| ch.is_ascii_digit());
+
+            match rng.below(10) {
+                0..=2 if !after_digit => {
+                    let len = rng.range_usize(1..=3.min(remaining));
+                    let low = 10_i64.pow(len as u32 - 1);
+                    row.push_str(&rng.range(low..=low * 10 - 1).to_string());
+                }
+                3 if rng.chance(0.5) => row.push('*'),
+                3 => row.push(*rng.choose(&SYMBOLS)),
+                _ => row.push('.'),
+            }
+        }
+
+        input.push_str(&row);
+        input.push('\n');
+    }
+
+    input
+}
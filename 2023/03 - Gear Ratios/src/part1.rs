@@ -0,0 +1,114 @@
+use common::{Answer, Error, Line};
+use grid::{Coord2D, Grid};
+
+const RADIX: u32 = 10;
+
+struct DigitContainer {
+    coord: Coord2D,
+}
+
+struct NumberContainer {
+    digits: Vec<DigitContainer>,
+    value: u32,
+}
+
+impl NumberContainer {
+    fn new(line: &Line, digits: Vec<DigitContainer>) -> Result<Self, Error> {
+        let col_index = digits[0].coord.col as usize;
+        let offset = line
+            .char_indices()
+            .nth(col_index)
+            .map_or(0, |(offset, _)| offset);
+        let value = line.parse(&line[offset..offset + digits.len()])?;
+        Ok(NumberContainer { digits, value })
+    }
+
+    fn is_part_number(&self, schematic: &Schematic) -> bool {
+        for digit in &self.digits {
+            if schematic.is_valid_digit(digit) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<NumberContainer>,
+}
+
+impl TryFrom<&str> for Schematic {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // -- Construct the grid.
+        let grid = Grid::parse(input, |_, ch| Ok(ch))?;
+        let lines: Vec<_> = common::lines(input).collect();
+
+        // -- Find all numbers.
+        let mut numbers = Vec::new();
+
+        for (row_index, row) in grid.rows().enumerate() {
+            let line = &lines[row_index];
+            let mut digits = Box::<Vec<DigitContainer>>::default();
+
+            for (col_index, curr) in row.iter().enumerate() {
+                // -- Found digit.
+                if curr.is_digit(RADIX) {
+                    // -- Collect the digit.
+                    digits.push(DigitContainer {
+                        coord: Coord2D::from_indices(col_index, row_index),
+                    });
+                    continue;
+                }
+
+                if !digits.is_empty() {
+                    numbers.push(NumberContainer::new(line, *digits)?);
+                    *digits = Default::default();
+                }
+            }
+
+            // -- Handle remaining digits.
+            if !digits.is_empty() {
+                numbers.push(NumberContainer::new(line, *digits)?);
+            }
+        }
+
+        Ok(Schematic { grid, numbers })
+    }
+}
+
+impl Schematic {
+    fn is_valid_digit(&self, digit: &DigitContainer) -> bool {
+        for coord in self.grid.neighbors8(&digit.coord) {
+            let v = self.grid[coord];
+            if !v.is_ascii_digit() && v != '.' {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn sum_of_part_numbers(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|n| n.is_part_number(self))
+            .map(|n| n.value)
+            .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Schematic, Error> {
+    Schematic::try_from(input)
+}
+
+pub fn answer(schematic: &Schematic) -> Result<Answer, Error> {
+    Ok(schematic.sum_of_part_numbers().into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
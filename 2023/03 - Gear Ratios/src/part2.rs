@@ -0,0 +1,176 @@
+use common::{Answer, Error, Line};
+use grid::{Coord2D, Grid};
+
+const RADIX: u32 = 10;
+
+struct DigitContainer {
+    coord: Coord2D,
+}
+
+impl DigitContainer {
+    fn adjacent_to(&self, gear: &Gear) -> bool {
+        self.coord.neighbors8().any(|coord| coord == gear.coord)
+    }
+}
+
+struct InclusiveRange {
+    begin: isize,
+    end: isize,
+}
+
+impl InclusiveRange {
+    fn new(begin: isize, end: isize) -> Self {
+        Self { begin, end }
+    }
+
+    fn contains(&self, value: isize) -> bool {
+        value >= self.begin && value <= self.end
+    }
+}
+
+struct NumberContainer {
+    row_index: isize,
+    col_range: InclusiveRange,
+    digits: Vec<DigitContainer>,
+    value: u32,
+}
+
+impl NumberContainer {
+    fn new(line: &Line, digits: Vec<DigitContainer>) -> Result<Self, Error> {
+        let first = digits[0].coord;
+        let last = digits[digits.len() - 1].coord;
+        let offset = line
+            .char_indices()
+            .nth(first.col as usize)
+            .map_or(0, |(offset, _)| offset);
+        let value = line.parse(&line[offset..offset + digits.len()])?;
+        let col_range = InclusiveRange::new(first.col, last.col);
+        Ok(NumberContainer {
+            row_index: first.row,
+            col_range,
+            digits,
+            value,
+        })
+    }
+}
+
+struct Gear {
+    coord: Coord2D,
+}
+
+impl Gear {
+    fn get_ratio(&self, schematic: &Schematic) -> Option<u32> {
+        let mut first = None;
+        let mut second = None;
+
+        for number in &schematic.numbers {
+            // -- Skip numbers too vertically far away.
+            let gear_row_range = self.coord.row - 1..=self.coord.row + 1;
+
+            if !gear_row_range.contains(&number.row_index) {
+                continue;
+            }
+
+            // -- Skip numbers too horizontally far away.
+            let num_col_range =
+                InclusiveRange::new(number.col_range.begin - 1, number.col_range.end + 1);
+
+            if !num_col_range.contains(self.coord.col) {
+                continue;
+            }
+
+            // -- Look for adjacent digits.
+            for digit in &number.digits {
+                if digit.adjacent_to(self) {
+                    match (&first, &second) {
+                        (None, _) => {
+                            first = Some(number.value);
+                            break;
+                        }
+                        (_, None) => {
+                            second = Some(number.value);
+                            break;
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+        }
+
+        if let (Some(first), Some(second)) = (first, second) {
+            return Some(first * second);
+        }
+
+        None
+    }
+}
+
+pub struct Schematic {
+    numbers: Vec<NumberContainer>,
+    gears: Vec<Gear>,
+}
+
+impl TryFrom<&str> for Schematic {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // -- Construct the grid.
+        let grid = Grid::parse(input, |_, ch| Ok(ch))?;
+        let lines: Vec<_> = common::lines(input).collect();
+
+        // -- Find all numbers.
+        let mut numbers = Vec::new();
+        let mut gears = Vec::new();
+
+        for (row_index, row) in grid.rows().enumerate() {
+            let line = &lines[row_index];
+            let mut digits = Box::<Vec<DigitContainer>>::default();
+
+            for (col_index, curr) in row.iter().enumerate() {
+                let coord = Coord2D::from_indices(col_index, row_index);
+
+                // -- Found digit.
+                if curr.is_digit(RADIX) {
+                    // -- Collect the digit.
+                    digits.push(DigitContainer { coord });
+                    continue;
+                }
+
+                // -- Found gear.
+                if *curr == '*' {
+                    gears.push(Gear { coord });
+                }
+
+                if !digits.is_empty() {
+                    numbers.push(NumberContainer::new(line, *digits)?);
+                    *digits = Default::default();
+                }
+            }
+
+            // -- Handle remaining digits.
+            if !digits.is_empty() {
+                numbers.push(NumberContainer::new(line, *digits)?);
+            }
+        }
+
+        Ok(Schematic { numbers, gears })
+    }
+}
+
+impl Schematic {
+    fn sum_of_gear_ratios(&self) -> u32 {
+        self.gears.iter().filter_map(|g| g.get_ratio(self)).sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Schematic, Error> {
+    Schematic::try_from(input)
+}
+
+pub fn answer(schematic: &Schematic) -> Result<Answer, Error> {
+    Ok(schematic.sum_of_gear_ratios().into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
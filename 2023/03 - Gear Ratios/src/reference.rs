@@ -0,0 +1,121 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- A number and the cells it covers: its row and its first and last column.
+struct Number {
+    value: u32,
+    row: usize,
+    first: usize,
+    last: usize,
+}
+
+impl Number {
+    fn touches(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.first && col <= self.last + 1
+    }
+}
+
+fn schematic(input: &str) -> Result<(Vec<Vec<char>>, Vec<Number>), Error> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(Error::new("ragged schematic"));
+    }
+
+    let mut numbers = Vec::new();
+
+    for (row, cells) in rows.iter().enumerate() {
+        let mut col = 0;
+
+        while col < cells.len() {
+            if !cells[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            let first = col;
+            let mut value = 0;
+            while col < cells.len() && cells[col].is_ascii_digit() {
+                value = value * 10 + cells[col].to_digit(10).unwrap();
+                col += 1;
+            }
+
+            numbers.push(Number {
+                value,
+                row,
+                first,
+                last: col - 1,
+            });
+        }
+    }
+
+    Ok((rows, numbers))
+}
+
+// -- Every symbol with its position, checking every number against every symbol.
+fn symbols(rows: &[Vec<char>]) -> Vec<(char, usize, usize)> {
+    rows.iter()
+        .enumerate()
+        .flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| !cell.is_ascii_digit() && **cell != '.')
+                .map(move |(col, cell)| (*cell, row, col))
+        })
+        .collect()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let (rows, numbers) = schematic(input)?;
+    let symbols = symbols(&rows);
+
+    let sum: u32 = numbers
+        .iter()
+        .filter(|number| {
+            symbols
+                .iter()
+                .any(|(_, row, col)| number.touches(*row, *col))
+        })
+        .map(|number| number.value)
+        .sum();
+    Ok(sum.into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let (rows, numbers) = schematic(input)?;
+
+    let sum: u64 = symbols(&rows)
+        .iter()
+        .filter(|(symbol, _, _)| *symbol == '*')
+        .filter_map(|(_, row, col)| {
+            let adjacent: Vec<_> = numbers
+                .iter()
+                .filter(|number| number.touches(*row, *col))
+                .collect();
+
+            match adjacent[..] {
+                [a, b] => Some(u64::from(a.value) * u64::from(b.value)),
+                _ => None,
+            }
+        })
+        .sum();
+    Ok(sum.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
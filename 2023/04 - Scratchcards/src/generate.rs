@@ -0,0 +1,67 @@
+use common::random::Rng;
+
+const NUM_WINNING: usize = 10;
+const NUM_YOURS: usize = 25;
+
+// -- Every card is processed once per copy, so keep the total number of copies in check.
+const COPIES_PER_CARD: usize = 2_000;
+
+fn num_copies(matches: &[usize]) -> usize {
+    let mut copies = vec![1_usize; matches.len()];
+
+    for (index, num_matched) in matches.iter().enumerate() {
+        for next in index + 1..=index + num_matched {
+            copies[next] = copies[next].saturating_add(copies[index]);
+        }
+    }
+
+    copies
+        .iter()
+        .fold(0, |total, count| total.saturating_add(*count))
+}
+
+// -----------------------------------------------------------------------------
+// -- `size` cards. A card never wins copies of cards past the end of the table.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    let mut matches: Vec<usize> = (0..size)
+        .map(|index| match rng.chance(0.4) {
+            true => 0,
+            false => rng.range_usize(0..=NUM_WINNING.min(size - index - 1)),
+        })
+        .collect();
+
+    while num_copies(&matches) > COPIES_PER_CARD * size {
+        let index = rng.index(size);
+        matches[index] = matches[index].saturating_sub(1);
+    }
+
+    let mut input = String::new();
+
+    for (index, num_matched) in matches.iter().enumerate() {
+        let numbers = rng.distinct(NUM_WINNING + NUM_YOURS - num_matched, 1..=99);
+        let (winning, others) = numbers.split_at(NUM_WINNING);
+
+        let mut yours: Vec<u64> = winning[..*num_matched].to_vec();
+        yours.extend(others);
+        rng.shuffle(&mut yours);
+
+        let format = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|number| format!("{:>2}", number))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            index + 1,
+            format(winning),
+            format(&yours)
+        ));
+    }
+
+    input
+}
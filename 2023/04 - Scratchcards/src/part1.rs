@@ -0,0 +1,68 @@
+use common::{Answer, Error, Line};
+
+pub struct GameCard {
+    score: usize,
+}
+
+fn parse_nums(line: &Line, nums_str: &str) -> Result<Vec<u8>, Error> {
+    nums_str.split_whitespace().map(|s| line.parse(s)).collect()
+}
+
+impl TryFrom<Line<'_>> for GameCard {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let (_, nums_str) = line
+            .split_once(": ")
+            .ok_or_else(|| line.error("expected 'Card N: ' prefix"))?;
+        let (winning_str, your_str) = nums_str
+            .split_once(" | ")
+            .ok_or_else(|| line.error_in(nums_str, "expected ' | ' separator"))?;
+
+        let winning_nums = parse_nums(&line, winning_str)?;
+        let your_nums = parse_nums(&line, your_str)?;
+
+        let num_matched = Self::num_matched(winning_nums, your_nums);
+        let score = Self::score(num_matched);
+
+        Ok(Self { score })
+    }
+}
+
+impl GameCard {
+    fn num_matched(winning_nums: Vec<u8>, your_nums: Vec<u8>) -> usize {
+        let mut num_matched = 0;
+
+        for winning_num in &winning_nums {
+            for your_num in &your_nums {
+                if winning_num == your_num {
+                    num_matched += 1;
+                    break;
+                }
+            }
+        }
+
+        num_matched
+    }
+
+    fn score(num_matched: usize) -> usize {
+        if num_matched == 0 {
+            return 0;
+        }
+
+        1 << (num_matched - 1)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<GameCard>, Error> {
+    common::lines(input).map(GameCard::try_from).collect()
+}
+
+pub fn answer(cards: &[GameCard]) -> Result<Answer, Error> {
+    let sum: usize = cards.iter().map(|card| card.score).sum();
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+
+use common::{Answer, Error, Line};
+
+pub struct GameCard {
+    index: usize,
+    num_matched: usize,
+}
+
+fn parse_nums(line: &Line, nums_str: &str) -> Result<Vec<u8>, Error> {
+    nums_str.split_whitespace().map(|s| line.parse(s)).collect()
+}
+
+impl TryFrom<Line<'_>> for GameCard {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let (card_str, nums_str) = line
+            .split_once(": ")
+            .ok_or_else(|| line.error("expected 'Card N: ' prefix"))?;
+        let (winning_str, your_str) = nums_str
+            .split_once(" | ")
+            .ok_or_else(|| line.error_in(nums_str, "expected ' | ' separator"))?;
+
+        let mut card_components = card_str.split_whitespace().skip(1);
+        let index = line.parse_next(&mut card_components, "card number")?;
+
+        let winning_nums = parse_nums(&line, winning_str)?;
+        let your_nums = parse_nums(&line, your_str)?;
+
+        let num_matched = Self::num_matched(winning_nums, your_nums);
+
+        Ok(Self { index, num_matched })
+    }
+}
+
+impl GameCard {
+    fn num_matched(winning_nums: Vec<u8>, your_nums: Vec<u8>) -> usize {
+        let mut num_matched = 0;
+
+        for winning_num in &winning_nums {
+            for your_num in &your_nums {
+                if winning_num == your_num {
+                    num_matched += 1;
+                    break;
+                }
+            }
+        }
+
+        num_matched
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<GameCard>, Error> {
+    common::lines(input).map(GameCard::try_from).collect()
+}
+
+pub fn answer(cards: &[GameCard]) -> Result<Answer, Error> {
+    let mut queue: VecDeque<_> = cards.iter().map(|card| card.index).collect();
+
+    let mut total = 0_usize;
+
+    while let Some(curr_index) = queue.pop_front() {
+        total += 1;
+
+        let num_matched = curr_index
+            .checked_sub(1)
+            .and_then(|i| cards.get(i))
+            .ok_or_else(|| Error::new(format!("card {} does not exist", curr_index)))?
+            .num_matched;
+
+        for new_index in curr_index + 1..=curr_index + num_matched {
+            queue.push_back(new_index);
+        }
+    }
+
+    Ok(total.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- How many of your numbers are winning numbers, card by card.
+fn matches(input: &str) -> Result<Vec<usize>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let bad = || Error::new(format!("malformed card '{}'", line));
+            let (_, numbers) = line.split_once(':').ok_or_else(bad)?;
+            let (winning, yours) = numbers.split_once('|').ok_or_else(bad)?;
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+
+            Ok(yours
+                .split_whitespace()
+                .filter(|number| winning.contains(number))
+                .count())
+        })
+        .collect()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let sum: u64 = matches(input)?
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| 1 << (count - 1))
+        .sum();
+    Ok(sum.into())
+}
+
+// -- Scratches every copy one at a time, queueing up the copies it wins.
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let matches = matches(input)?;
+    let mut queue: VecDeque<usize> = (0..matches.len()).collect();
+    let mut scratched = 0_u64;
+
+    while let Some(card) = queue.pop_front() {
+        scratched += 1;
+        queue.extend((card + 1..=card + matches[card]).filter(|next| *next < matches.len()));
+    }
+
+    Ok(scratched.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,54 @@
+use common::random::Rng;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// -----------------------------------------------------------------------------
+// -- `size` seed ranges and `size` ranges in every map. The source ranges of a map never
+// -- overlap, and every value stays below 2^32 like in the real almanac.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    almanac(rng, size, 1 << 32)
+}
+
+// -- Every value stays below `limit`, and seed ranges cover at most a sixteenth of it.
+pub fn almanac(rng: &mut Rng, size: usize, limit: u64) -> String {
+    let size = size.max(1);
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.below(limit - 1);
+            let len = rng.range_u64(1..=(limit - start).min((limit >> 4).max(1)));
+            format!("{} {}", start, len)
+        })
+        .collect();
+
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in MAP_NAMES {
+        input.push_str(&format!("\n{} map:\n", name));
+
+        // -- Consecutive pairs of sorted cut points bound disjoint source ranges.
+        let mut cuts = rng.distinct(2 * size, 0..=limit - 1);
+        cuts.sort();
+
+        let mut ranges: Vec<(u64, u64)> = cuts
+            .chunks(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .collect();
+        rng.shuffle(&mut ranges);
+
+        for (src, len) in ranges {
+            let dst = rng.below(limit - len + 1);
+            input.push_str(&format!("{} {} {}\n", dst, src, len));
+        }
+    }
+
+    input
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
-use std::io::{stdin, BufRead, Stdin};
+
+use common::{Answer, Error};
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 enum AlmanacMapType {
@@ -14,9 +15,11 @@ enum AlmanacMapType {
 
 use AlmanacMapType::*;
 
-impl From<&str> for AlmanacMapType {
-    fn from(map_name: &str) -> Self {
-        match map_name {
+impl TryFrom<&str> for AlmanacMapType {
+    type Error = Error;
+
+    fn try_from(map_name: &str) -> Result<Self, Self::Error> {
+        let map_type = match map_name {
             "seed-to-soil" => SeedToSoil,
             "soil-to-fertilizer" => SoilToFertilizer,
             "fertilizer-to-water" => FertilizerToWater,
@@ -24,8 +27,10 @@ impl From<&str> for AlmanacMapType {
             "light-to-temperature" => LightToTemperature,
             "temperature-to-humidity" => TemperatureToHumidity,
             "humidity-to-location" => HumidityToLocation,
-            _ => panic!("unknown map name '{}'", map_name),
-        }
+            _ => return Err(Error::new(format!("unknown map name '{}'", map_name))),
+        };
+
+        Ok(map_type)
     }
 }
 
@@ -84,23 +89,25 @@ impl AlmanacMap {
     }
 }
 
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<usize>,
     maps: HashMap<AlmanacMapType, Vec<AlmanacMap>>,
 }
 
-impl From<&Stdin> for Almanac {
-    fn from(stdin: &Stdin) -> Self {
-        let mut lines = stdin.lock().lines();
+impl TryFrom<&str> for Almanac {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
 
-        let seeds: Vec<_> = lines
-            .next()
-            .unwrap()
-            .unwrap()
+        let seeds_line = lines.next().ok_or_else(|| Error::new("input is empty"))?;
+        let seeds_str = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| seeds_line.error("expected 'seeds:' line"))?;
+        let seeds = seeds_str
             .split_whitespace()
-            .skip(1)
-            .map(|l| l.parse().unwrap())
-            .collect();
+            .map(|s| seeds_line.parse(s))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut maps: HashMap<_, _> = ALMANAC_MAP_TYPES
             .iter()
@@ -109,33 +116,34 @@ impl From<&Stdin> for Almanac {
 
         let mut curr_type = None;
 
-        while let Some(Ok(line)) = lines.next() {
+        for line in lines {
             // -- Skip blank lines.
             if line.is_empty() {
                 continue;
             }
 
             // -- Switch building map.
-            if line.ends_with(" map:") {
-                let map_name = line.split_whitespace().next().unwrap();
-                curr_type = Some(AlmanacMapType::from(map_name));
+            if let Some(map_name) = line.strip_suffix(" map:") {
+                let map_type =
+                    AlmanacMapType::try_from(map_name).map_err(|err| line.locate(map_name, err))?;
+                curr_type = Some(map_type);
                 continue;
             }
 
             // -- Create new almanac map for input.
-            let mut nums = line.split_whitespace().map(|w| w.parse().unwrap());
-            let dst_start = nums.next().unwrap();
-            let src_start = nums.next().unwrap();
-            let range_length = nums.next().unwrap();
+            let mut nums = line.split_whitespace();
+            let dst_start = line.parse_next(&mut nums, "destination range start")?;
+            let src_start = line.parse_next(&mut nums, "source range start")?;
+            let range_length = line.parse_next(&mut nums, "range length")?;
             let almanac_map = AlmanacMap::new(dst_start, src_start, range_length);
 
             match curr_type {
-                Some(curr_type) => maps.get_mut(&curr_type).unwrap().push(almanac_map),
-                None => panic!("attempt to push to curr_type when None"),
+                Some(curr_type) => maps.entry(curr_type).or_default().push(almanac_map),
+                None => return Err(line.error("range appears before any map header")),
             }
         }
 
-        Self { seeds, maps }
+        Ok(Self { seeds, maps })
     }
 }
 
@@ -152,7 +160,7 @@ impl Almanac {
         value
     }
 
-    fn lowest_location(&self) -> usize {
+    fn lowest_location(&self) -> Option<usize> {
         let mut values = self.seeds.clone();
 
         for map_type in ALMANAC_MAP_TYPES.iter() {
@@ -165,12 +173,21 @@ impl Almanac {
             values = new_values;
         }
 
-        values.into_iter().min().unwrap()
+        values.into_iter().min()
     }
 }
 
-fn main() {
-    let almanac = Almanac::from(&stdin());
-    let lowest = almanac.lowest_location();
-    println!("{lowest}");
+pub fn parse(input: &str) -> Result<Almanac, Error> {
+    Almanac::try_from(input)
+}
+
+pub fn answer(almanac: &Almanac) -> Result<Answer, Error> {
+    let lowest = almanac
+        .lowest_location()
+        .ok_or_else(|| Error::new("no seeds given"))?;
+    Ok(lowest.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
@@ -0,0 +1,326 @@
+use std::cmp::min;
+use std::collections::HashMap;
+
+use common::pool::Pool;
+use common::{Answer, Error};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+enum AlmanacMapType {
+    SeedToSoil,
+    SoilToFertilizer,
+    FertilizerToWater,
+    WaterToLight,
+    LightToTemperature,
+    TemperatureToHumidity,
+    HumidityToLocation,
+}
+
+use AlmanacMapType::*;
+
+impl TryFrom<&str> for AlmanacMapType {
+    type Error = Error;
+
+    fn try_from(map_name: &str) -> Result<Self, Self::Error> {
+        let map_type = match map_name {
+            "seed-to-soil" => SeedToSoil,
+            "soil-to-fertilizer" => SoilToFertilizer,
+            "fertilizer-to-water" => FertilizerToWater,
+            "water-to-light" => WaterToLight,
+            "light-to-temperature" => LightToTemperature,
+            "temperature-to-humidity" => TemperatureToHumidity,
+            "humidity-to-location" => HumidityToLocation,
+            _ => return Err(Error::new(format!("unknown map name '{}'", map_name))),
+        };
+
+        Ok(map_type)
+    }
+}
+
+const ALMANAC_MAP_TYPES: [AlmanacMapType; 7] = [
+    SeedToSoil,
+    SoilToFertilizer,
+    FertilizerToWater,
+    WaterToLight,
+    LightToTemperature,
+    TemperatureToHumidity,
+    HumidityToLocation,
+];
+
+#[derive(Clone, Copy)]
+struct ExclusiveRange {
+    begin: usize,
+    end: usize,
+    len: usize,
+}
+
+impl ExclusiveRange {
+    fn new(begin: usize, end: usize) -> Self {
+        Self {
+            begin,
+            end,
+            len: end - begin,
+        }
+    }
+
+    fn new_by_len(begin: usize, len: usize) -> Self {
+        Self {
+            begin,
+            end: begin + len,
+            len,
+        }
+    }
+
+    fn contains(&self, value: usize) -> bool {
+        value >= self.begin && value < self.end
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> {
+        self.begin..self.end
+    }
+}
+
+struct RangeConversionResult {
+    unconverted: Vec<ExclusiveRange>,
+    converted: Option<ExclusiveRange>,
+}
+
+#[derive(Clone)]
+struct AlmanacMap {
+    dst_start: usize,
+    src_start: usize,
+    src_range: ExclusiveRange,
+}
+
+impl AlmanacMap {
+    fn new(dst_start: usize, src_start: usize, range_length: usize) -> Self {
+        AlmanacMap {
+            dst_start,
+            src_start,
+            src_range: ExclusiveRange::new_by_len(src_start, range_length),
+        }
+    }
+
+    fn convert(&self, input: usize) -> Option<usize> {
+        if !self.src_range.contains(input) {
+            return None;
+        }
+
+        let offset = input.checked_sub(self.src_start).unwrap();
+        let conversion = self.dst_start.checked_add(offset).unwrap();
+
+        Some(conversion)
+    }
+
+    fn convert_range(&self, input: ExclusiveRange) -> RangeConversionResult {
+        let first = input.begin.max(self.src_range.begin);
+        let end = input.end.min(self.src_range.end);
+
+        // -- No intersection.
+        if first >= end {
+            return RangeConversionResult {
+                unconverted: vec![input],
+                converted: None,
+            };
+        }
+
+        // -- Whatever lies before or after the source range is left as it is.
+        let unconverted = [
+            ExclusiveRange::new(input.begin, first),
+            ExclusiveRange::new(end, input.end),
+        ]
+        .into_iter()
+        .filter(|range| range.len > 0)
+        .collect();
+
+        let start_converted = self.convert(first).unwrap();
+        let end_converted = self.convert(end - 1).unwrap().checked_add(1).unwrap();
+
+        RangeConversionResult {
+            unconverted,
+            converted: Some(ExclusiveRange::new(start_converted, end_converted)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: HashMap<AlmanacMapType, Vec<AlmanacMap>>,
+}
+
+impl TryFrom<&str> for Almanac {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
+
+        let seeds_line = lines.next().ok_or_else(|| Error::new("input is empty"))?;
+        let seeds_str = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| seeds_line.error("expected 'seeds:' line"))?;
+        let seeds = seeds_str
+            .split_whitespace()
+            .map(|s| seeds_line.parse(s))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut maps: HashMap<_, _> = ALMANAC_MAP_TYPES
+            .iter()
+            .map(|map_type| (*map_type, Vec::new()))
+            .collect();
+
+        let mut curr_type = None;
+
+        for line in lines {
+            // -- Skip blank lines.
+            if line.is_empty() {
+                continue;
+            }
+
+            // -- Switch building map.
+            if let Some(map_name) = line.strip_suffix(" map:") {
+                let map_type =
+                    AlmanacMapType::try_from(map_name).map_err(|err| line.locate(map_name, err))?;
+                curr_type = Some(map_type);
+                continue;
+            }
+
+            // -- Create new almanac map for input.
+            let mut nums = line.split_whitespace();
+            let dst_start = line.parse_next(&mut nums, "destination range start")?;
+            let src_start = line.parse_next(&mut nums, "source range start")?;
+            let range_length = line.parse_next(&mut nums, "range length")?;
+            let almanac_map = AlmanacMap::new(dst_start, src_start, range_length);
+
+            match curr_type {
+                Some(curr_type) => maps.entry(curr_type).or_default().push(almanac_map),
+                None => return Err(line.error("range appears before any map header")),
+            }
+        }
+
+        Ok(Self { seeds, maps })
+    }
+}
+
+impl Almanac {
+    fn transform_with_map(&self, map_type: &AlmanacMapType, value: usize) -> usize {
+        let maps = self.maps.get(map_type).unwrap();
+
+        for map in maps {
+            if let Some(converted) = map.convert(value) {
+                return converted;
+            }
+        }
+
+        value
+    }
+
+    fn seed_to_location(&self, seed: usize) -> usize {
+        let mut location = seed;
+
+        for map_type in ALMANAC_MAP_TYPES.iter() {
+            location = self.transform_with_map(map_type, location);
+        }
+
+        location
+    }
+
+    fn range_to_location(&self, range: ExclusiveRange) -> usize {
+        let mut lowest = usize::MAX;
+
+        for seed in range.iter() {
+            let location = self.seed_to_location(seed);
+            lowest = min(lowest, location);
+        }
+
+        lowest
+    }
+
+    fn get_seed_ranges(&self) -> Vec<ExclusiveRange> {
+        self.seeds
+            .windows(2)
+            .step_by(2)
+            .map(|w| ExclusiveRange::new_by_len(w[0], w[1]))
+            .filter(|range| range.len > 0)
+            .collect()
+    }
+
+    // -- Splits the seed ranges into chunks, so that one huge range still keeps every
+    // -- worker busy.
+    fn lowest_location_brute_parallel(&self, pool: &Pool) -> Option<usize> {
+        const CHUNK_LEN: usize = 1 << 20;
+
+        let chunks: Vec<ExclusiveRange> = self
+            .get_seed_ranges()
+            .iter()
+            .flat_map(|range| {
+                (range.begin..range.end)
+                    .step_by(CHUNK_LEN)
+                    .map(|begin| ExclusiveRange::new(begin, min(begin + CHUNK_LEN, range.end)))
+            })
+            .collect();
+
+        pool.map(&chunks, |chunk| self.range_to_location(*chunk))
+            .into_iter()
+            .min()
+    }
+
+    fn lowest_location(&self) -> Option<usize> {
+        let mut ranges = self.get_seed_ranges();
+
+        for map_type in ALMANAC_MAP_TYPES.iter() {
+            let mut new_ranges = Vec::new();
+
+            while let Some(range) = ranges.pop() {
+                let mut was_converted = false;
+
+                for map in self.maps.get(map_type).unwrap() {
+                    let RangeConversionResult {
+                        unconverted,
+                        converted,
+                    } = map.convert_range(range);
+
+                    // -- Converted parts are done with this map; the rest may still match
+                    // -- one of the others.
+                    if let Some(converted) = converted {
+                        was_converted = true;
+                        ranges.extend(unconverted);
+                        new_ranges.push(converted);
+                        break;
+                    }
+                }
+
+                // -- Keep range if no conversion occured.
+                if !was_converted {
+                    new_ranges.push(range);
+                }
+            }
+
+            ranges = new_ranges;
+        }
+
+        ranges.iter().map(|r| r.begin).min()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Almanac, Error> {
+    Almanac::try_from(input)
+}
+
+pub fn answer(almanac: &Almanac) -> Result<Answer, Error> {
+    let lowest = almanac
+        .lowest_location()
+        .ok_or_else(|| Error::new("no seed ranges given"))?;
+    Ok(lowest.into())
+}
+
+// -- Maps every single seed, spread over every core; only feasible for small almanacs.
+pub fn answer_brute(almanac: &Almanac) -> Result<Answer, Error> {
+    let lowest = almanac
+        .lowest_location_brute_parallel(&Pool::available())
+        .ok_or_else(|| Error::new("no seed ranges given"))?;
+    Ok(lowest.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
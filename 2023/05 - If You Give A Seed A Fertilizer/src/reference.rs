@@ -0,0 +1,21 @@
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part2};
+
+// -- Values below 256 keep the seed ranges short enough to try every seed.
+fn small(rng: &mut Rng, size: usize) -> String {
+    generate::almanac(rng, size, 256)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    part2::answer_brute(&part2::parse(input)?)
+}
+
+pub const REFERENCES: &[Reference] = &[Reference {
+    part: 2,
+    fast: part2::solve,
+    slow: slow_part2,
+    generate: small,
+}];
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use common::report::Part;
+
+fn main() -> ExitCode {
+    let part = Part {
+        year: 2023,
+        day: 6,
+        part: 2,
+        timed: common::timed!(wait_for_it::part2),
+    };
+    common::cli::main(env!("CARGO_MANIFEST_DIR"), &part)
+}
@@ -0,0 +1,49 @@
+use common::random::Rng;
+
+// -- Part 2 reads all the times as one number, and squares it, so the concatenated time
+// -- stays within nine digits.
+const MAX_TIME_DIGITS: usize = 9;
+
+fn concat(values: &[u64]) -> u128 {
+    let digits: String = values.iter().map(u64::to_string).collect();
+    digits.parse().unwrap()
+}
+
+// -----------------------------------------------------------------------------
+// -- `size` races (at most nine), every one of which can be won, and so can the single
+// -- long race part 2 makes of them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_races = size.clamp(1, MAX_TIME_DIGITS);
+    let digits = (MAX_TIME_DIGITS / num_races).min(3) as u32;
+    races(rng, num_races, digits)
+}
+
+// -- Race times have exactly `digits` digits.
+pub fn races(rng: &mut Rng, num_races: usize, digits: u32) -> String {
+    let (low, high) = (10_u64.pow(digits - 1).max(3), 10_u64.pow(digits) - 1);
+
+    loop {
+        let times: Vec<u64> = (0..num_races).map(|_| rng.range_u64(low..=high)).collect();
+        let distances: Vec<u64> = times
+            .iter()
+            .map(|time| {
+                // -- The best possible distance is (time / 2) * (time - time / 2).
+                let best = (time / 2) * (time - time / 2);
+                rng.range_u64(best / 2..=best - 1)
+            })
+            .collect();
+
+        let (time, distance) = (concat(&times), concat(&distances));
+
+        if distance < (time / 2) * (time - time / 2) {
+            let row = |values: &[u64]| {
+                values
+                    .iter()
+                    .map(|value| format!("{:>6}", value))
+                    .collect::<String>()
+            };
+
+            return format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances));
+        }
+    }
+}
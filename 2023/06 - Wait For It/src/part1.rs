@@ -0,0 +1,69 @@
+use std::iter::zip;
+
+use common::{Answer, Error, Line};
+
+pub struct RaceData {
+    time: usize,
+    distance: usize,
+}
+
+impl RaceData {
+    fn new(time: usize, distance: usize) -> Self {
+        Self { time, distance }
+    }
+
+    fn num_win_scenarios(&self) -> usize {
+        let mut total = 0;
+
+        for time_wait in 1..self.time {
+            let time_go = self.time - time_wait;
+
+            if time_wait * time_go > self.distance {
+                total += 1;
+            }
+        }
+
+        total
+    }
+}
+
+fn parse_row(line: Option<Line>, label: &str) -> Result<Vec<usize>, Error> {
+    let line = line.ok_or_else(|| Error::new(format!("missing '{}' line", label)))?;
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(format!("expected '{}' line", label)))?;
+
+    values.split_whitespace().map(|s| line.parse(s)).collect()
+}
+
+fn parse_racedata(input: &str) -> Result<Vec<RaceData>, Error> {
+    let mut lines = common::lines(input);
+
+    let times = parse_row(lines.next(), "Time:")?;
+    let distances = parse_row(lines.next(), "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(Error::new(format!(
+            "found {} times but {} distances",
+            times.len(),
+            distances.len()
+        )));
+    }
+
+    Ok(zip(times, distances)
+        .map(|(t, d)| RaceData::new(t, d))
+        .collect())
+}
+
+pub fn parse(input: &str) -> Result<Vec<RaceData>, Error> {
+    parse_racedata(input)
+}
+
+pub fn answer(racedata: &[RaceData]) -> Result<Answer, Error> {
+    let solution: usize = racedata.iter().map(|rd| rd.num_win_scenarios()).product();
+    Ok(solution.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,83 @@
+use common::{Answer, Error, Line};
+
+pub struct RaceData {
+    time: usize,
+    distance: usize,
+}
+
+impl RaceData {
+    fn num_win_scenarios_brute(&self) -> usize {
+        let mut count = 0;
+
+        for time_wait in 0..=self.time {
+            if (self.time * time_wait) - time_wait.pow(2) > self.distance {
+                count += 1
+            }
+        }
+
+        count
+    }
+
+    fn num_win_scenarios(&self) -> usize {
+        let time = self.time as f64;
+        let distance = self.distance as f64;
+
+        let lower = (-time + (time.powf(2.0) - (4.0 * distance)).sqrt()) / -2.0;
+        let lower = lower.floor();
+        let upper = (-time - (time.powf(2.0) - (4.0 * distance)).sqrt()) / -2.0;
+        let upper = upper.ceil();
+        let result = upper - lower - 1.0;
+
+        (result as i64) as usize
+    }
+}
+
+fn parse_row(line: Option<Line>, label: &str) -> Result<usize, Error> {
+    let line = line.ok_or_else(|| Error::new(format!("missing '{}' line", label)))?;
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(format!("expected '{}' line", label)))?;
+
+    // -- Check every piece before joining, so a bad digit is reported where it sits.
+    let mut digits = String::new();
+
+    for piece in values.split_whitespace() {
+        line.parse::<usize>(piece)?;
+        digits.push_str(piece);
+    }
+
+    digits.parse().map_err(|err| {
+        line.error_in(
+            values.trim(),
+            format!("invalid {} '{}': {}", label, digits, err),
+        )
+    })
+}
+
+fn parse_racedata(input: &str) -> Result<RaceData, Error> {
+    let mut lines = common::lines(input);
+
+    let time = parse_row(lines.next(), "Time:")?;
+    let distance = parse_row(lines.next(), "Distance:")?;
+
+    Ok(RaceData { time, distance })
+}
+
+pub fn parse(input: &str) -> Result<RaceData, Error> {
+    parse_racedata(input)
+}
+
+pub fn answer(racedata: &RaceData) -> Result<Answer, Error> {
+    // let solution = racedata.num_win_scenarios_brute();
+    let solution = racedata.num_win_scenarios();
+    Ok(solution.into())
+}
+
+// -- Tries every wait time; only feasible for short races.
+pub fn answer_brute(racedata: &RaceData) -> Result<Answer, Error> {
+    Ok(racedata.num_win_scenarios_brute().into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,23 @@
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part2};
+
+// -- One or two races of up to two digits keep the combined race short enough to try
+// -- every wait time.
+fn small(rng: &mut Rng, size: usize) -> String {
+    let digits = rng.range_u64(1..=2) as u32;
+    generate::races(rng, size.clamp(1, 2), digits)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    part2::answer_brute(&part2::parse(input)?)
+}
+
+pub const REFERENCES: &[Reference] = &[Reference {
+    part: 2,
+    fast: part2::solve,
+    slow: slow_part2,
+    generate: small,
+}];
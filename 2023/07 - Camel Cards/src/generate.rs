@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+use common::random::Rng;
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// -----------------------------------------------------------------------------
+// -- `size` distinct hands with bids up to 1000. Hands are biased towards repeated cards
+// -- so every hand type shows up.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 100_000);
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+
+    while seen.len() < size {
+        let num_kinds = rng.range_usize(1..=5);
+        let kinds: Vec<char> = (0..num_kinds).map(|_| *rng.choose(&CARDS)).collect();
+        let hand: String = (0..5).map(|_| *rng.choose(&kinds)).collect();
+
+        if seen.insert(hand.clone()) {
+            input.push_str(&format!("{} {}\n", hand, rng.range(1..=1000)));
+        }
+    }
+
+    input
+}
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::io::{stdin, BufRead};
+
+use common::{Answer, Error, Line};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum CardType {
@@ -21,9 +22,11 @@ enum CardType {
 
 use CardType::*;
 
-impl From<char> for CardType {
-    fn from(ch: char) -> Self {
-        match ch {
+impl TryFrom<char> for CardType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        let card_type = match ch {
             '2' => C2,
             '3' => C3,
             '4' => C4,
@@ -37,8 +40,10 @@ impl From<char> for CardType {
             'Q' => Q,
             'K' => K,
             'A' => A,
-            _ => panic!("not a card face: '{}'", ch),
-        }
+            _ => return Err(Error::new(format!("not a card face: '{}'", ch))),
+        };
+
+        Ok(card_type)
     }
 }
 
@@ -78,17 +83,17 @@ enum HandType {
 use HandType::*;
 
 impl HandType {
-    fn new(card_count_map: &HashMap<CardType, usize>) -> Self {
-        let mut card_type_counts: Vec<_> = card_count_map.iter().map(|(_, n)| n).collect();
+    fn new(card_count_map: &HashMap<CardType, usize>) -> Result<Self, Error> {
+        let mut card_type_counts: Vec<_> = card_count_map.values().collect();
         card_type_counts.sort_by(|a, b| b.cmp(a));
         let mut card_type_counts = card_type_counts.into_iter();
 
         let a = match card_type_counts.next() {
             Some(a) => a,
-            _ => return HighCard,
+            _ => return Ok(HighCard),
         };
 
-        match a {
+        let hand_type = match a {
             5 => FiveOfAKind,
             4 => FourOfAKind,
             3 | 2 => {
@@ -98,38 +103,58 @@ impl HandType {
                     (3, _) => ThreeOfAKind,
                     (2, 2) => TwoPair,
                     (2, _) => OnePair,
-                    _ => panic!("unhandled card count case: ({}, {})", a, b),
+                    _ => {
+                        return Err(Error::new(format!(
+                            "unhandled card count case: ({}, {})",
+                            a, b
+                        )))
+                    }
                 }
             }
             1 => HighCard,
-            _ => panic!("unhandled card count case: {}", a),
-        }
+            _ => return Err(Error::new(format!("unhandled card count case: {}", a))),
+        };
+
+        Ok(hand_type)
     }
 }
 
 #[derive(PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: [CardType; HAND_SIZE],
     bid: usize,
     hand_type: HandType,
 }
 
-impl From<String> for Hand {
-    fn from(line: String) -> Self {
+impl TryFrom<Line<'_>> for Hand {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
         let mut components = line.split_whitespace();
 
         // -- Get cards.
+        let cards_str = line.expect(components.next(), "cards")?;
+
+        if cards_str.chars().count() != HAND_SIZE {
+            return Err(line.error_in(
+                cards_str,
+                format!(
+                    "expected {} cards, found {}",
+                    HAND_SIZE,
+                    cards_str.chars().count()
+                ),
+            ));
+        }
+
         let mut cards = [CardType::A; HAND_SIZE];
-        components
-            .next()
-            .unwrap()
-            .chars()
-            .map(|ch| ch.into())
-            .zip(cards.iter_mut())
-            .for_each(|(card_type, slot)| *slot = card_type);
+
+        for ((offset, ch), slot) in cards_str.char_indices().zip(cards.iter_mut()) {
+            *slot = CardType::try_from(ch)
+                .map_err(|err| line.locate(&cards_str[offset..offset + ch.len_utf8()], err))?;
+        }
 
         // -- Get bid
-        let bid = components.next().unwrap().parse().unwrap();
+        let bid = line.parse_next(&mut components, "bid")?;
 
         // -- Count cards.
         let mut card_count_map = HashMap::new();
@@ -143,13 +168,14 @@ impl From<String> for Hand {
         }
 
         // -- Determine hand type.
-        let hand_type = HandType::new(&card_count_map);
+        let hand_type =
+            HandType::new(&card_count_map).map_err(|err| line.locate(cards_str, err))?;
 
-        Hand {
+        Ok(Hand {
             cards,
             bid,
             hand_type,
-        }
+        })
     }
 }
 
@@ -166,7 +192,7 @@ impl Ord for Hand {
             .zip(other.cards.iter())
             .map(|(c0, c1)| c0.value().cmp(&c1.value()))
             .find(|ord| *ord != Ordering::Equal)
-            .unwrap()
+            .unwrap_or(Ordering::Equal)
     }
 }
 
@@ -176,13 +202,17 @@ impl PartialOrd for Hand {
     }
 }
 
-fn main() {
-    let mut hands: Vec<Hand> = stdin()
-        .lock()
-        .lines()
-        .map(|line| line.unwrap().into())
-        .collect();
+pub fn parse(input: &str) -> Result<Vec<Hand>, Error> {
+    common::lines(input).map(Hand::try_from).collect()
+}
+
+pub fn answer(hands: &[Hand]) -> Result<Answer, Error> {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
     let solution: usize = hands.iter().enumerate().map(|(i, h)| h.bid * (i + 1)).sum();
-    println!("{solution}");
+    Ok(solution.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
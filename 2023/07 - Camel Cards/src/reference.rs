@@ -0,0 +1,99 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+const CARDS: &str = "23456789TJQKA";
+const CARDS_WITH_JOKERS: &str = "J23456789TQKA";
+
+// -- Higher is stronger: the sorted counts of each label compare like the hand types.
+fn strength(hand: &[char]) -> Vec<usize> {
+    let mut counts: Vec<usize> = CARDS
+        .chars()
+        .map(|card| hand.iter().filter(|c| **c == card).count())
+        .filter(|count| *count > 0)
+        .collect();
+    counts.sort_by(|a, b| b.cmp(a));
+    counts
+}
+
+// -- Tries every way of replacing the jokers with the other labels in the hand.
+fn best_strength(hand: &[char]) -> Vec<usize> {
+    let Some(joker) = hand.iter().position(|card| *card == 'J') else {
+        return strength(hand);
+    };
+
+    let mut labels: Vec<char> = hand.iter().copied().filter(|card| *card != 'J').collect();
+    if labels.is_empty() {
+        labels.push('A');
+    }
+
+    labels
+        .iter()
+        .map(|label| {
+            let mut replaced = hand.to_vec();
+            replaced[joker] = *label;
+            best_strength(&replaced)
+        })
+        .max()
+        .unwrap()
+}
+
+fn winnings(input: &str, jokers: bool) -> Result<Answer, Error> {
+    let order = if jokers { CARDS_WITH_JOKERS } else { CARDS };
+    let mut hands = Vec::new();
+
+    for line in input.lines() {
+        let bad = || Error::new(format!("malformed hand '{}'", line));
+        let (hand, bid) = line.split_once(' ').ok_or_else(bad)?;
+        let bid: u64 = bid.trim().parse().map_err(|_| bad())?;
+        let cards: Vec<char> = hand.chars().collect();
+
+        if cards.len() != 5 || cards.iter().any(|card| !CARDS.contains(*card)) {
+            return Err(bad());
+        }
+
+        let strength = if jokers {
+            best_strength(&cards)
+        } else {
+            strength(&cards)
+        };
+        let ranks: Vec<usize> = cards
+            .iter()
+            .map(|card| order.find(*card).unwrap())
+            .collect();
+        hands.push((strength, ranks, bid));
+    }
+
+    hands.sort();
+
+    let total: u64 = hands
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, _, bid))| (rank as u64 + 1) * bid)
+        .sum();
+    Ok(total.into())
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    winnings(input, false)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    winnings(input, true)
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
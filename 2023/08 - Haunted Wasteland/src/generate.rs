@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use common::random::Rng;
+
+const PRIMES: [usize; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+// -----------------------------------------------------------------------------
+struct Names {
+    len: usize,
+    used: HashSet<String>,
+}
+
+impl Names {
+    // -- A fresh name ending in the given letter.
+    fn with_suffix(&mut self, rng: &mut Rng, last: char) -> String {
+        loop {
+            let mut name: String = (1..self.len)
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect();
+            name.push(last);
+
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // -- A fresh name ending in neither 'A' nor 'Z'.
+    fn plain(&mut self, rng: &mut Rng) -> String {
+        let last = char::from(b'B' + rng.below(24) as u8);
+        self.with_suffix(rng, last)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- `size` directions and two to six ghosts. Ghost i walks a loop of `size` * p_i steps
+// -- for a distinct prime p_i, passing its Z node once per lap at every multiple of the
+// -- loop length. Every position on a loop but the last has a left and a right twin, so
+// -- the directions matter for the path but not for the timing. Ghost 0 runs from AAA to
+// -- ZZZ, so part 1 has an answer as well.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_ghosts = rng.range_usize(2..=6);
+    network(rng, size, &PRIMES, num_ghosts)
+}
+
+// -- The loop lengths use `num_ghosts` of the given primes.
+pub fn network(rng: &mut Rng, size: usize, primes: &[usize], num_ghosts: usize) -> String {
+    let num_directions = size.max(1);
+
+    let mut primes = primes.to_vec();
+    rng.shuffle(&mut primes);
+
+    let num_nodes: usize = primes[..num_ghosts]
+        .iter()
+        .map(|prime| 2 * num_directions * prime + 1)
+        .sum();
+    let mut names = Names {
+        len: if num_nodes < 10_000 { 3 } else { 4 },
+        used: HashSet::new(),
+    };
+    names.used.insert(String::from("AAA"));
+    names.used.insert(String::from("ZZZ"));
+
+    let mut nodes = Vec::new();
+
+    for (ghost, prime) in primes[..num_ghosts].iter().enumerate() {
+        let length = num_directions * prime;
+
+        let (start, goal) = match ghost {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            _ => (names.with_suffix(rng, 'A'), names.with_suffix(rng, 'Z')),
+        };
+
+        // -- Position j of the loop is reached after j + 1 steps.
+        let twins: Vec<(String, String)> = (0..length - 1)
+            .map(|_| (names.plain(rng), names.plain(rng)))
+            .collect();
+
+        let next = |position: usize| match twins.get(position) {
+            Some((left, right)) => (left.clone(), right.clone()),
+            None => (goal.clone(), goal.clone()),
+        };
+
+        nodes.push((start.clone(), next(0)));
+        nodes.push((goal.clone(), next(0)));
+
+        for (position, (left, right)) in twins.iter().enumerate() {
+            nodes.push((left.clone(), next(position + 1)));
+            nodes.push((right.clone(), next(position + 1)));
+        }
+    }
+
+    rng.shuffle(&mut nodes);
+
+    let directions: String = (0..num_directions)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut input = format!("{}\n\n", directions);
+
+    for (name, (left, right)) in nodes {
+        input.push_str(&format!("{} = ({}, {})\n", name, left, right));
+    }
+
+    input
+}
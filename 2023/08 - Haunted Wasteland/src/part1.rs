@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use common::{Answer, Error};
+
+enum Direction {
+    Left,
+    Right,
+}
+
+use Direction::*;
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'L' => Ok(Left),
+            'R' => Ok(Right),
+            _ => Err(Error::new(format!("invalid direction char: '{}'", ch))),
+        }
+    }
+}
+
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn get(&self, direction: &Direction) -> &str {
+        match direction {
+            Left => &self.left,
+            Right => &self.right,
+        }
+    }
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    map: HashMap<String, Node>,
+}
+
+impl TryFrom<&str> for Network {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
+
+        // -- Parse directions.
+        let directions_line = lines.next().ok_or_else(|| Error::new("input is empty"))?;
+        let directions = directions_line
+            .char_indices()
+            .map(|(offset, ch)| {
+                Direction::try_from(ch)
+                    .map_err(|err| err.at(&directions_line, offset, ch.len_utf8()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if directions.is_empty() {
+            return Err(directions_line.error("expected at least one direction"));
+        }
+
+        lines.next();
+
+        // -- Parse nodes.
+        let mut map = HashMap::new();
+        let mut references = Vec::new();
+
+        for line in lines {
+            let (name, targets) = line
+                .as_str()
+                .split_once(" = ")
+                .ok_or_else(|| line.error("expected 'NAME = (LEFT, RIGHT)'"))?;
+            let (left, right) = targets
+                .strip_prefix('(')
+                .and_then(|t| t.strip_suffix(')'))
+                .and_then(|t| t.split_once(", "))
+                .ok_or_else(|| line.error_in(targets, "expected '(LEFT, RIGHT)'"))?;
+
+            references.push((line, left));
+            references.push((line, right));
+
+            let name = name.to_string();
+            let left = left.to_string();
+            let right = right.to_string();
+
+            let key = name.clone();
+            let node = Node { name, left, right };
+
+            map.insert(key, node);
+        }
+
+        // -- Make sure every referenced node exists.
+        for (line, target) in references {
+            if !map.contains_key(target) {
+                return Err(line.error_in(target, format!("unknown node '{}'", target)));
+            }
+        }
+
+        Ok(Network { directions, map })
+    }
+}
+
+impl Network {
+    fn walk_distance(&self) -> Result<usize, Error> {
+        const START_NODE_NAME: &str = "AAA";
+        const FINAL_NODE_NAME: &str = "ZZZ";
+
+        let mut distance = 0;
+        let mut directions = self.directions.iter().cycle();
+        let mut curr = self
+            .map
+            .get(START_NODE_NAME)
+            .ok_or_else(|| Error::new(format!("no '{}' node", START_NODE_NAME)))?;
+
+        while curr.name != FINAL_NODE_NAME {
+            distance += 1;
+
+            let direction = directions.next().unwrap();
+            let next_name = curr.get(direction);
+            curr = self.map.get(next_name).unwrap();
+        }
+
+        Ok(distance)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Network, Error> {
+    Network::try_from(input)
+}
+
+pub fn answer(network: &Network) -> Result<Answer, Error> {
+    Ok(network.walk_distance()?.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
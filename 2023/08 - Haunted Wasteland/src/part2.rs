@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use common::math::Congruence;
+use common::{diagnostics, Answer, Error};
+
+// -- Ghost path: the first `offset` steps lead into a loop of `length` steps. Steps that
+// -- land on a Z node are recorded up to the end of the first loop.
+struct Cycle {
+    offset: usize,
+    length: usize,
+    hits: Vec<usize>,
+}
+
+impl Cycle {
+    fn is_hit(&self, step: usize) -> bool {
+        let step = match step.checked_sub(self.offset) {
+            Some(into_loop) => self.offset + into_loop % self.length,
+            None => step,
+        };
+
+        self.hits.contains(&step)
+    }
+
+    fn loop_hits(&self) -> impl Iterator<Item = Congruence> + '_ {
+        self.hits
+            .iter()
+            .filter(|hit| **hit >= self.offset)
+            .map(|hit| Congruence::new(*hit as u64, self.length as u64))
+    }
+}
+
+enum Direction {
+    Left,
+    Right,
+}
+
+use Direction::*;
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'L' => Ok(Left),
+            'R' => Ok(Right),
+            _ => Err(Error::new(format!("invalid direction char: '{}'", ch))),
+        }
+    }
+}
+
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn get(&self, direction: &Direction) -> &str {
+        match direction {
+            Left => &self.left,
+            Right => &self.right,
+        }
+    }
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    map: HashMap<String, Node>,
+}
+
+impl TryFrom<&str> for Network {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
+
+        // -- Parse directions.
+        let directions_line = lines.next().ok_or_else(|| Error::new("input is empty"))?;
+        let directions = directions_line
+            .char_indices()
+            .map(|(offset, ch)| {
+                Direction::try_from(ch)
+                    .map_err(|err| err.at(&directions_line, offset, ch.len_utf8()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if directions.is_empty() {
+            return Err(directions_line.error("expected at least one direction"));
+        }
+
+        lines.next();
+
+        // -- Parse nodes.
+        let mut map = HashMap::new();
+        let mut references = Vec::new();
+
+        for line in lines {
+            let (name, targets) = line
+                .as_str()
+                .split_once(" = ")
+                .ok_or_else(|| line.error("expected 'NAME = (LEFT, RIGHT)'"))?;
+            let (left, right) = targets
+                .strip_prefix('(')
+                .and_then(|t| t.strip_suffix(')'))
+                .and_then(|t| t.split_once(", "))
+                .ok_or_else(|| line.error_in(targets, "expected '(LEFT, RIGHT)'"))?;
+
+            references.push((line, left));
+            references.push((line, right));
+
+            let name = name.to_string();
+            let left = left.to_string();
+            let right = right.to_string();
+
+            let key = name.clone();
+            let node = Node { name, left, right };
+
+            map.insert(key, node);
+        }
+
+        // -- Make sure every referenced node exists.
+        for (line, target) in references {
+            if !map.contains_key(target) {
+                return Err(line.error_in(target, format!("unknown node '{}'", target)));
+            }
+        }
+
+        Ok(Network { directions, map })
+    }
+}
+
+impl Network {
+    fn trace_cycle(&self, start: &Node, node_indices: &HashMap<&str, usize>) -> Cycle {
+        let num_directions = self.directions.len();
+        let mut seen = vec![None; node_indices.len() * num_directions];
+        let mut hits = Vec::new();
+        let mut curr = start;
+
+        for step in 0.. {
+            // -- A ghost's state is its node and its position in the directions.
+            let direction_index = step % num_directions;
+            let state = node_indices[curr.name.as_str()] * num_directions + direction_index;
+
+            if let Some(first) = seen[state] {
+                return Cycle {
+                    offset: first,
+                    length: step - first,
+                    hits,
+                };
+            }
+
+            seen[state] = Some(step);
+
+            if curr.name.ends_with('Z') {
+                hits.push(step);
+            }
+
+            let next_name = curr.get(&self.directions[direction_index]);
+            curr = self.map.get(next_name).unwrap();
+        }
+
+        unreachable!()
+    }
+
+    fn walk_parallel(&self) -> Result<u64, Error> {
+        let node_indices: HashMap<&str, usize> = self
+            .map
+            .keys()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), index))
+            .collect();
+
+        let cycles: Vec<_> = self
+            .map
+            .values()
+            .filter(|node| node.name.ends_with('A'))
+            .map(|node| self.trace_cycle(node, &node_indices))
+            .collect();
+
+        let mut lengths: Vec<_> = cycles.iter().map(|cycle| cycle.length).collect();
+        lengths.sort();
+        diagnostics::record("cycle_lengths", lengths);
+
+        // -- Before every ghost has entered its loop, check each step directly.
+        let settled = cycles
+            .iter()
+            .map(|cycle| cycle.offset)
+            .max()
+            .ok_or_else(|| Error::new("no starting nodes ending in 'A'"))?;
+
+        if let Some(step) = (0..settled).find(|step| cycles.iter().all(|c| c.is_hit(*step))) {
+            return Ok(step as u64);
+        }
+
+        // -- Afterwards a ghost is on a Z node exactly when the step is congruent to one of
+        // -- its loop hits, so combine every choice of hits across ghosts.
+        let mut solutions = vec![Congruence::new(0, 1)];
+
+        for cycle in cycles.iter() {
+            solutions = solutions
+                .iter()
+                .flat_map(|solution| cycle.loop_hits().filter_map(|hit| solution.merge(&hit)))
+                .collect();
+        }
+
+        solutions
+            .iter()
+            .filter_map(|solution| solution.first_at_least(settled as u64))
+            .min()
+            .ok_or_else(|| Error::new("ghosts never stand on Z nodes at the same time"))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Network, Error> {
+    Network::try_from(input)
+}
+
+pub fn answer(network: &Network) -> Result<Answer, Error> {
+    Ok(network.walk_parallel()?.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
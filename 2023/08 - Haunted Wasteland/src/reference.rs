@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- Walking every ghost step by step takes as many steps as the answer.
+const MAX_STEPS: usize = 10_000_000;
+
+// -- Two or three ghosts on loops of 2, 3, 5 or 7 laps keep the answer small.
+fn small(rng: &mut Rng, size: usize) -> String {
+    let num_ghosts = rng.range_usize(2..=3);
+    generate::network(rng, size, &[2, 3, 5, 7], num_ghosts)
+}
+
+type Nodes<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn network(input: &str) -> Result<(Vec<char>, Nodes<'_>), Error> {
+    let mut lines = input.lines();
+    let directions: Vec<char> = lines.next().unwrap_or_default().chars().collect();
+
+    if directions.is_empty() || directions.iter().any(|d| *d != 'L' && *d != 'R') {
+        return Err(Error::new("malformed directions"));
+    }
+
+    let mut nodes = HashMap::new();
+
+    for line in lines.filter(|line| !line.is_empty()) {
+        let bad = || Error::new(format!("malformed node '{}'", line));
+        let (name, targets) = line.split_once(" = (").ok_or_else(bad)?;
+        let (left, right) = targets
+            .strip_suffix(')')
+            .and_then(|targets| targets.split_once(", "))
+            .ok_or_else(bad)?;
+        nodes.insert(name, (left, right));
+    }
+
+    Ok((directions, nodes))
+}
+
+// -- Moves every ghost at once until all of them stand on a node ending in 'Z'.
+fn walk(input: &str, start: fn(&str) -> bool, goal: fn(&str) -> bool) -> Result<Answer, Error> {
+    let (directions, nodes) = network(input)?;
+    let mut ghosts: Vec<&str> = nodes.keys().copied().filter(|name| start(name)).collect();
+
+    if ghosts.is_empty() {
+        return Err(Error::new("no starting node"));
+    }
+
+    for steps in 0..MAX_STEPS {
+        if ghosts.iter().all(|ghost| goal(ghost)) {
+            return Ok(steps.into());
+        }
+
+        for ghost in ghosts.iter_mut() {
+            let (left, right) = nodes
+                .get(ghost)
+                .ok_or_else(|| Error::new(format!("unknown node '{}'", ghost)))?;
+            *ghost = match directions[steps % directions.len()] {
+                'L' => left,
+                _ => right,
+            };
+        }
+    }
+
+    Err(Error::new("gave up walking"))
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    walk(input, |name| name == "AAA", |name| name == "ZZZ")
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    walk(
+        input,
+        |name| name.ends_with('A'),
+        |name| name.ends_with('Z'),
+    )
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: small,
+    },
+];
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use common::report::Part;
+
+fn main() -> ExitCode {
+    let part = Part {
+        year: 2023,
+        day: 9,
+        part: 1,
+        timed: common::timed!(mirage_maintenance::part1),
+    };
+    common::cli::main(env!("CARGO_MANIFEST_DIR"), &part)
+}
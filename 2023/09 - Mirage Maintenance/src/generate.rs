@@ -0,0 +1,31 @@
+use common::random::Rng;
+
+const LEN: i64 = 21;
+
+// -----------------------------------------------------------------------------
+// -- `size` sequences of 21 values, each sampled from a random polynomial of degree up to
+// -- five with small integer coefficients, so the differences bottom out like in the
+// -- real report. Values stay well within i64, also one step either side.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let degree = rng.range_usize(0..=5);
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.range(-9..=9)).collect();
+
+        let values: Vec<String> = (0..LEN)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
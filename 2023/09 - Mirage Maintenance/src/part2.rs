@@ -0,0 +1,57 @@
+use common::{Answer, Error};
+
+fn parse_lines(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    common::lines(input)
+        .map(|line| {
+            let nums: Vec<i64> = line
+                .split_whitespace()
+                .map(|word| line.parse(word))
+                .collect::<Result<_, _>>()?;
+
+            if nums.is_empty() {
+                return Err(line.error_at_end("expected a history of numbers"));
+            }
+
+            Ok(nums)
+        })
+        .collect()
+}
+
+fn find_next(nums: &[i64]) -> i64 {
+    let mut sum = 0;
+    let mut nums = nums.to_owned();
+
+    nums.reverse();
+
+    while !nums.iter().all(|n| *n == 0) {
+        let mut new_nums = Vec::new();
+
+        let mut nums_iter = nums.into_iter();
+        let Some(mut curr) = nums_iter.next() else {
+            break;
+        };
+
+        for next in nums_iter {
+            new_nums.push(next - curr);
+            curr = next;
+        }
+
+        sum += curr;
+        nums = new_nums;
+    }
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    parse_lines(input)
+}
+
+pub fn answer(num_lines: &[Vec<i64>]) -> Result<Answer, Error> {
+    let sum: i64 = num_lines.iter().map(|nums| find_next(nums)).sum();
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,82 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+fn binomial(n: i128, k: i128) -> i128 {
+    (0..k).fold(1, |value, i| value * (n - i) / (i + 1))
+}
+
+fn histories(input: &str) -> Result<Vec<Vec<i128>>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let values: Vec<i128> = line
+                .split_whitespace()
+                .map(|value| value.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| Error::new(format!("malformed history '{}'", line)))?;
+
+            if values.is_empty() {
+                return Err(Error::new("empty history"));
+            }
+
+            Ok(values)
+        })
+        .collect()
+}
+
+// -- Instead of differences, extrapolates the polynomial through all n values directly:
+// -- y(n) = sum (-1)^(n-1-i) C(n, i) y(i), and y(-1) = sum (-1)^i C(n, i+1) y(i).
+fn extrapolate(values: &[i128], backwards: bool) -> i128 {
+    let n = values.len() as i128;
+
+    values
+        .iter()
+        .zip(0..)
+        .map(|(value, i)| {
+            let (sign, k) = if backwards {
+                (i, i + 1)
+            } else {
+                (n - 1 - i, i)
+            };
+            let sign = if sign % 2 == 0 { 1 } else { -1 };
+            sign * binomial(n, k) * value
+        })
+        .sum()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let sum: i128 = histories(input)?
+        .iter()
+        .map(|values| extrapolate(values, false))
+        .sum();
+    Ok(i64::try_from(sum)
+        .map_err(|_| Error::new("sum out of range"))?
+        .into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let sum: i128 = histories(input)?
+        .iter()
+        .map(|values| extrapolate(values, true))
+        .sum();
+    Ok(i64::try_from(sum)
+        .map_err(|_| Error::new("sum out of range"))?
+        .into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,120 @@
+use common::random::Rng;
+use grid::{Coord2D, Direction, Grid};
+
+use Direction::*;
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+fn pipe(a: Direction, b: Direction) -> char {
+    match (a.min(b), a.max(b)) {
+        (North, South) => '|',
+        (East, West) => '-',
+        (North, East) => 'L',
+        (North, West) => 'J',
+        (South, West) => '7',
+        (East, South) => 'F',
+        _ => unreachable!("a pipe joins two different directions"),
+    }
+}
+
+fn direction(from: Coord2D, to: Coord2D) -> Direction {
+    Direction::ALL
+        .into_iter()
+        .find(|direction| from.step(*direction) == to)
+        .unwrap()
+}
+
+// -- The outline of a histogram: columns of random heights standing on a common base,
+// -- which is always a simple closed loop. Returns the corners in order.
+fn histogram(rng: &mut Rng, size: usize) -> Vec<Coord2D> {
+    let (left, right, bottom) = (1, size as isize - 2, size as isize - 2);
+
+    let mut bounds: Vec<isize> = (left + 1..right).filter(|_| rng.chance(0.2)).collect();
+    bounds.insert(0, left);
+    bounds.push(right);
+
+    // -- Neighboring columns differ in height, so every corner is a real turn.
+    let mut tops: Vec<isize> = Vec::new();
+    for _ in 1..bounds.len() {
+        loop {
+            let top = rng.range(1..=bottom as i64 - 1) as isize;
+            if tops.last() != Some(&top) {
+                tops.push(top);
+                break;
+            }
+        }
+    }
+
+    let mut corners = vec![Coord2D::new(left, bottom), Coord2D::new(right, bottom)];
+
+    for (index, top) in tops.iter().enumerate().rev() {
+        corners.push(Coord2D::new(bounds[index + 1], *top));
+        corners.push(Coord2D::new(bounds[index], *top));
+    }
+
+    corners
+}
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` maze holding exactly one loop through S, surrounded by random
+// -- pipe junk. Tiles next to S that are not part of the loop never point at it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let corners = histogram(rng, size);
+
+    // -- Walk the outline one tile at a time.
+    let mut path = Vec::new();
+    for (index, corner) in corners.iter().enumerate() {
+        let next = corners[(index + 1) % corners.len()];
+        let step = direction(
+            *corner,
+            Coord2D::new(
+                corner.col + (next.col - corner.col).signum(),
+                corner.row + (next.row - corner.row).signum(),
+            ),
+        );
+
+        let mut coord = *corner;
+        while coord != next {
+            path.push(coord);
+            coord = coord.step(step);
+        }
+    }
+
+    let mut grid = Grid::from_fn(size, size, |_| *rng.choose(&JUNK));
+
+    for (index, coord) in path.iter().enumerate() {
+        let prev = path[(index + path.len() - 1) % path.len()];
+        let next = path[(index + 1) % path.len()];
+        grid[*coord] = pipe(direction(*coord, prev), direction(*coord, next));
+    }
+
+    let start = path[rng.index(path.len())];
+    grid[start] = 'S';
+
+    // -- Loop tiles only point along the loop, so only junk can point at S by accident.
+    for neighbor in grid.neighbors4(&start).collect::<Vec<_>>() {
+        if !path.contains(&neighbor) {
+            grid[neighbor] = '.';
+        }
+    }
+
+    // -- Mirror top to bottom half of the time, so the base is not always at the bottom.
+    if rng.chance(0.5) {
+        let flipped = |ch: char| match ch {
+            'L' => 'F',
+            'F' => 'L',
+            'J' => '7',
+            '7' => 'J',
+            ch => ch,
+        };
+        let last = size as isize - 1;
+        grid = Grid::from_fn(size, size, |coord| {
+            flipped(grid[Coord2D::new(coord.col, last - coord.row)])
+        });
+    }
+
+    grid.rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
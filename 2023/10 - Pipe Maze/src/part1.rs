@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use common::{diagnostics, image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+use Direction::*;
+
+// -----------------------------------------------------------------------------
+#[derive(PartialEq)]
+enum TileType {
+    Vertical,
+    Horizontal,
+    BendNorthToEast,
+    BendNorthToWest,
+    BendSouthToWest,
+    BendSouthToEast,
+    Ground,
+    Start,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        let tile = match ch {
+            '|' => Vertical,
+            '-' => Horizontal,
+            'L' => BendNorthToEast,
+            'J' => BendNorthToWest,
+            '7' => BendSouthToWest,
+            'F' => BendSouthToEast,
+            '.' => Ground,
+            'S' => Start,
+            _ => return Err(Error::new(format!("unknown tile type character: '{}'", ch))),
+        };
+
+        Ok(tile)
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            Vertical => '│',
+            Horizontal => '─',
+            BendNorthToEast => '└',
+            BendNorthToWest => '┘',
+            BendSouthToWest => '┐',
+            BendSouthToEast => '┌',
+            Ground => '·',
+            Start => 'S',
+        }
+    }
+
+    fn get_next_directions(&self) -> Option<Vec<Direction>> {
+        let directions = match self {
+            Vertical => vec![North, South],
+            Horizontal => vec![East, West],
+            BendNorthToEast => vec![North, East],
+            BendNorthToWest => vec![North, West],
+            BendSouthToWest => vec![South, West],
+            BendSouthToEast => vec![South, East],
+            _ => return None,
+        };
+
+        Some(directions)
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct Board {
+    grid: Grid<TileType>,
+    start: Coord2D,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // -- Parse input into grid and find starting point.
+        let mut start = None;
+        let grid = Grid::parse(input, |coord, ch| {
+            let tile = TileType::try_from(ch)?;
+
+            if tile == Start {
+                if start.is_some() {
+                    return Err(Error::new("found more than one start tile"));
+                }
+                start = Some(coord);
+            }
+
+            Ok(tile)
+        })?;
+
+        let start = start.ok_or_else(|| Error::new("no start tile found"))?;
+        let mut board = Board { grid, start };
+
+        // -- Determine start node neighbors.
+        let start = board.start;
+        let mut neighbor_map = HashMap::new();
+
+        for direction in Direction::ALL {
+            if let Some(dir_coor) = board.grid.step(&start, direction) {
+                if let Some(dir_tile) = board.grid.get(&dir_coor) {
+                    if match direction {
+                        North => matches!(dir_tile, Vertical | BendSouthToEast | BendSouthToWest),
+                        East => matches!(dir_tile, Horizontal | BendNorthToWest | BendSouthToWest),
+                        South => matches!(dir_tile, Vertical | BendNorthToEast | BendNorthToWest),
+                        West => matches!(dir_tile, Horizontal | BendNorthToEast | BendSouthToEast),
+                    } {
+                        neighbor_map.insert(direction, dir_tile);
+                    }
+                }
+            }
+        }
+
+        board.grid[start] = match (
+            neighbor_map.get(&North),
+            neighbor_map.get(&East),
+            neighbor_map.get(&South),
+            neighbor_map.get(&West),
+        ) {
+            (Some(_), None, Some(_), None) => Vertical,
+            (None, Some(_), None, Some(_)) => Horizontal,
+            (Some(_), Some(_), None, None) => BendNorthToEast,
+            (Some(_), None, None, Some(_)) => BendNorthToWest,
+            (None, None, Some(_), Some(_)) => BendSouthToWest,
+            (None, Some(_), Some(_), None) => BendSouthToEast,
+            _ => {
+                return Err(Error::new(format!(
+                    "start tile at line {}, column {} does not join exactly two pipes",
+                    start.row + 1,
+                    start.col + 1
+                )))
+            }
+        };
+
+        // -- Result result.
+        Ok(board)
+    }
+}
+
+impl Board {
+    fn find_loop_coords(&self) -> Result<HashSet<Coord2D>, Error> {
+        let mut loop_coords = HashSet::new();
+        let mut queue = VecDeque::from([self.start]);
+
+        while let Some(curr_coord) = queue.pop_front() {
+            let broken = || {
+                Error::new(format!(
+                    "loop is broken at line {}, column {}",
+                    curr_coord.row + 1,
+                    curr_coord.col + 1
+                ))
+            };
+
+            let directions = self
+                .grid
+                .get(&curr_coord)
+                .and_then(TileType::get_next_directions)
+                .ok_or_else(broken)?;
+
+            for direction in directions {
+                let next_coord = self.grid.step(&curr_coord, direction).ok_or_else(broken)?;
+
+                if !loop_coords.contains(&next_coord) {
+                    loop_coords.insert(next_coord);
+                    queue.push_back(next_coord);
+
+                    // -- One frame per row's worth of pipe keeps the walk watchable.
+                    if loop_coords.len() % self.grid.ncols() == 0 {
+                        visualize::frame(|| self.canvas(&loop_coords).render());
+                    }
+                }
+            }
+        }
+
+        visualize::frame(|| self.canvas(&loop_coords).render());
+        image::record(|| self.canvas(&loop_coords).to_drawing());
+        Ok(loop_coords)
+    }
+
+    fn canvas(&self, loop_coords: &HashSet<Coord2D>) -> Canvas {
+        Canvas::from_grid(&self.grid, TileType::glyph)
+            .layer(Color::Red, loop_coords.iter().copied())
+            .layer(Color::Green, [self.start])
+            .highlight([self.start])
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    let loop_coords = board.find_loop_coords()?;
+    diagnostics::record("loop_length", loop_coords.len());
+
+    let steps = loop_coords.len() / 2;
+    Ok(steps.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
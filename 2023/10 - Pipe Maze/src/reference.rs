@@ -0,0 +1,154 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- North, east, south, west as (row, col) steps.
+const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+fn arms(tile: char) -> &'static [usize] {
+    match tile {
+        '|' => &[0, 2],
+        '-' => &[1, 3],
+        'L' => &[0, 1],
+        'J' => &[0, 3],
+        '7' => &[2, 3],
+        'F' => &[1, 2],
+        _ => &[],
+    }
+}
+
+struct Maze {
+    rows: Vec<Vec<char>>,
+    // -- The loop's tiles in order, starting at S, and the arms of each.
+    tiles: Vec<((usize, usize), Vec<usize>)>,
+}
+
+impl Maze {
+    fn step(&self, (row, col): (usize, usize), arm: usize) -> Option<(usize, usize)> {
+        let (dr, dc) = STEPS[arm];
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+        self.rows.get(row)?.get(col)?;
+        Some((row, col))
+    }
+
+    fn tile(&self, (row, col): (usize, usize)) -> char {
+        self.rows[row][col]
+    }
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+        if rows.iter().any(|row| row.len() != rows[0].len()) {
+            return Err(Error::new("ragged maze"));
+        }
+
+        let start = rows
+            .iter()
+            .enumerate()
+            .find_map(|(row, cells)| Some((row, cells.iter().position(|c| *c == 'S')?)))
+            .ok_or_else(|| Error::new("no start"))?;
+
+        let mut maze = Self {
+            rows,
+            tiles: Vec::new(),
+        };
+
+        // -- S connects to exactly the neighbors that point back at it.
+        let start_arms: Vec<usize> = (0..4)
+            .filter(|arm| {
+                maze.step(start, *arm)
+                    .is_some_and(|next| arms(maze.tile(next)).contains(&((arm + 2) % 4)))
+            })
+            .collect();
+
+        if start_arms.len() != 2 {
+            return Err(Error::new("start does not join exactly two pipes"));
+        }
+
+        let mut tiles = vec![(start, start_arms.clone())];
+        let mut arm = start_arms[0];
+        let mut coord = maze.step(start, arm).unwrap();
+
+        while coord != start {
+            let back = (arm + 2) % 4;
+            let tile_arms = arms(maze.tile(coord));
+
+            if !tile_arms.contains(&back) || tiles.len() > maze.rows.len() * maze.rows[0].len() {
+                return Err(Error::new("broken loop"));
+            }
+
+            arm = *tile_arms.iter().find(|a| **a != back).unwrap();
+            tiles.push((coord, tile_arms.to_vec()));
+            coord = maze
+                .step(coord, arm)
+                .ok_or_else(|| Error::new("loop leaves the map"))?;
+        }
+
+        maze.tiles = tiles;
+        Ok(maze)
+    }
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let maze = Maze::parse(input)?;
+    Ok((maze.tiles.len() / 2).into())
+}
+
+// -- Draws the loop at three times the size, with a border around it, and floods the
+// -- outside from a corner. Tiles whose centers stay dry are enclosed.
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let maze = Maze::parse(input)?;
+    let (nrows, ncols) = (maze.rows.len() * 3 + 2, maze.rows[0].len() * 3 + 2);
+    let mut wall = vec![vec![false; ncols]; nrows];
+
+    for ((row, col), tile_arms) in maze.tiles.iter() {
+        let (row, col) = (row * 3 + 2, col * 3 + 2);
+        wall[row][col] = true;
+
+        for arm in tile_arms {
+            let (dr, dc) = STEPS[*arm];
+            wall[(row as isize + dr) as usize][(col as isize + dc) as usize] = true;
+        }
+    }
+
+    let mut wet = vec![vec![false; ncols]; nrows];
+    let mut stack = vec![(0_usize, 0_usize)];
+    wet[0][0] = true;
+
+    while let Some((row, col)) = stack.pop() {
+        for (dr, dc) in STEPS {
+            let (Some(row), Some(col)) = (row.checked_add_signed(dr), col.checked_add_signed(dc))
+            else {
+                continue;
+            };
+
+            if row < nrows && col < ncols && !wall[row][col] && !wet[row][col] {
+                wet[row][col] = true;
+                stack.push((row, col));
+            }
+        }
+    }
+
+    let enclosed = (0..maze.rows.len())
+        .flat_map(|row| (0..maze.rows[row].len()).map(move |col| (row, col)))
+        .filter(|(row, col)| !wet[row * 3 + 2][col * 3 + 2] && !wall[row * 3 + 2][col * 3 + 2])
+        .count();
+    Ok(enclosed.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use common::report::Part;
+
+fn main() -> ExitCode {
+    let part = Part {
+        year: 2023,
+        day: 11,
+        part: 2,
+        timed: common::timed!(cosmic_expansion::part2),
+    };
+    common::cli::main(env!("CARGO_MANIFEST_DIR"), &part)
+}
@@ -0,0 +1,33 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` image. Roughly one row and column in ten is left empty so there is
+// -- something to expand, and there are always at least two galaxies.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let open = !empty_rows[row] && !empty_cols[col];
+                    if open && rng.chance(0.05) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    // -- Guarantee a pair on the diagonal's ends.
+    rows[0][0] = '#';
+    rows[size - 1][size - 1] = '#';
+
+    rows.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
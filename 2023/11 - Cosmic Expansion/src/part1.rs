@@ -1,5 +1,6 @@
 use std::cmp::{max, min};
-use std::io::{stdin, BufRead, Stdin};
+
+use common::{Answer, Error};
 
 #[derive(Clone, PartialEq)]
 struct Coordinate {
@@ -24,29 +25,33 @@ impl From<(Coordinate, Coordinate)> for Edge {
     }
 }
 
-struct Image {
+pub struct Image {
     empty_cols: Vec<usize>,
     empty_rows: Vec<usize>,
     paths: Vec<Edge>,
 }
 
-impl From<&Stdin> for Image {
-    fn from(stdin: &Stdin) -> Self {
+impl TryFrom<&str> for Image {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         // -- Parse input.
         let mut galaxies = Vec::new();
         let mut col_counter = None;
         let mut empty_rows = Vec::new();
 
-        for (row, line) in stdin.lock().lines().enumerate() {
-            let line = line.unwrap();
-
+        for (row, line) in common::lines(input).enumerate() {
             // -- Initalize column counter.
-            if col_counter.is_none() {
-                col_counter = Some(vec![0; line.len()]);
+            let col_counter = col_counter.get_or_insert_with(|| vec![0; line.len()]);
+
+            if line.len() != col_counter.len() {
+                return Err(line.error(format!(
+                    "expected {} columns, found {}",
+                    col_counter.len(),
+                    line.len()
+                )));
             }
 
-            let col_counter = col_counter.as_mut().unwrap();
-
             // -- Check if row is empty.
             if line.chars().all(|c| c == '.') {
                 empty_rows.push(row);
@@ -54,16 +59,26 @@ impl From<&Stdin> for Image {
             }
 
             for (col, c) in line.char_indices() {
-                if c == '#' {
-                    let coord = Coordinate::from((col, row));
-                    galaxies.push(coord);
-                    col_counter[col] += 1;
+                match c {
+                    '#' => {
+                        let coord = Coordinate::from((col, row));
+                        galaxies.push(coord);
+                        col_counter[col] += 1;
+                    }
+                    '.' => {}
+                    _ => {
+                        return Err(line.error_at(
+                            col,
+                            c.len_utf8(),
+                            format!("invalid character: '{}'", c),
+                        ))
+                    }
                 }
             }
         }
 
         let empty_cols = col_counter
-            .unwrap()
+            .unwrap_or_default()
             .iter()
             .enumerate()
             .filter(|(_, count)| **count == 0)
@@ -84,11 +99,11 @@ impl From<&Stdin> for Image {
         }
 
         // -- Return structure.
-        Self {
+        Ok(Self {
             empty_cols,
             empty_rows,
             paths,
-        }
+        })
     }
 }
 
@@ -130,8 +145,14 @@ impl Image {
     }
 }
 
-fn main() {
-    let image = Image::from(&stdin());
-    let sum = image.sum_of_distances(2);
-    println!("{sum}");
+pub fn parse(input: &str) -> Result<Image, Error> {
+    Image::try_from(input)
+}
+
+pub fn answer(image: &Image) -> Result<Answer, Error> {
+    Ok(image.sum_of_distances(2).into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
@@ -0,0 +1,67 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- Measures every pair of galaxies on the original image, counting each empty row and
+// -- column crossed on the way as `factor` of them.
+fn distances(input: &str, factor: u64) -> Result<Answer, Error> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(Error::new("ragged image"));
+    }
+
+    let galaxies: Vec<(usize, usize)> = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| **cell == '#')
+                .map(move |(col, _)| (row, col))
+        })
+        .collect();
+
+    let empty_row = |row: usize| rows[row].iter().all(|cell| *cell != '#');
+    let empty_col = |col: usize| rows.iter().all(|cells| cells[col] != '#');
+    let crossed = |a: usize, b: usize, empty: &dyn Fn(usize) -> bool| -> u64 {
+        (a.min(b)..a.max(b))
+            .map(|index| if empty(index) { factor } else { 1 })
+            .sum()
+    };
+
+    let mut sum = 0;
+
+    for (index, (row_a, col_a)) in galaxies.iter().enumerate() {
+        for (row_b, col_b) in galaxies[index + 1..].iter() {
+            sum += crossed(*row_a, *row_b, &empty_row) + crossed(*col_a, *col_b, &empty_col);
+        }
+    }
+
+    Ok(sum.into())
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    distances(input, 2)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    distances(input, 1_000_000)
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,38 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- `size` records. Each one starts from an actual arrangement of one to six groups and
+// -- hides some springs behind '?', so it has at least one arrangement.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    records(rng, size, 6, 5)
+}
+
+// -- Records of up to `max_groups` groups of at most `max_group` damaged springs.
+pub fn records(rng: &mut Rng, size: usize, max_groups: usize, max_group: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let groups: Vec<usize> = (0..rng.range_usize(1..=max_groups))
+            .map(|_| rng.range_usize(1..=max_group))
+            .collect();
+
+        let mut springs = ".".repeat(rng.range_usize(0..=2));
+        for (index, group) in groups.iter().enumerate() {
+            if index > 0 {
+                springs.push_str(&".".repeat(rng.range_usize(1..=3)));
+            }
+            springs.push_str(&"#".repeat(*group));
+        }
+        springs.push_str(&".".repeat(rng.range_usize(0..=2)));
+
+        let record: String = springs
+            .chars()
+            .map(|ch| if rng.chance(0.5) { '?' } else { ch })
+            .collect();
+        let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+
+        input.push_str(&format!("{} {}\n", record, groups.join(",")));
+    }
+
+    input
+}
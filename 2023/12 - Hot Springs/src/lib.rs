@@ -0,0 +1,4 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod reference;
@@ -2,7 +2,9 @@ use std::cmp::min;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
-use std::io::{stdin, BufRead};
+
+use common::pool::Pool;
+use common::{Answer, Error, Line};
 
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Hash)]
@@ -14,38 +16,46 @@ enum SpringState {
 
 use SpringState::*;
 
-impl From<char> for SpringState {
-    fn from(ch: char) -> Self {
+impl TryFrom<char> for SpringState {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
         match ch {
-            '.' => Operational,
-            '#' => Damaged,
-            '?' => Unknown,
-            _ => panic!("invalid character for SpringState: '{}'", ch),
+            '.' => Ok(Operational),
+            '#' => Ok(Damaged),
+            '?' => Ok(Unknown),
+            _ => Err(Error::new(format!(
+                "invalid character for SpringState: '{}'",
+                ch
+            ))),
         }
     }
 }
 
-struct SpringRecord {
+pub struct SpringRecord {
     springs: Vec<SpringState>,
     checksum: Vec<u8>,
 }
 
-impl From<String> for SpringRecord {
-    fn from(line: String) -> Self {
+impl TryFrom<Line<'_>> for SpringRecord {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
         let mut components = line.split_whitespace();
-        let springs = components
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| c.into())
-            .collect();
-        let checksum = components
-            .next()
-            .unwrap()
+        let springs_str = line.expect(components.next(), "springs")?;
+        let springs = springs_str
+            .char_indices()
+            .map(|(offset, c)| {
+                SpringState::try_from(c)
+                    .map_err(|err| line.locate(&springs_str[offset..offset + c.len_utf8()], err))
+            })
+            .collect::<Result<_, _>>()?;
+        let checksum = line
+            .expect(components.next(), "checksum")?
             .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect();
-        Self { springs, checksum }
+            .map(|s| line.parse(s))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { springs, checksum })
     }
 }
 
@@ -87,8 +97,10 @@ fn count_arrangments(
     let key = get_hash(springs, checksum);
 
     if let Some(arrangements) = cache.get(&key) {
+        common::count!("cache_hits");
         return *arrangements;
     }
+    common::count!("cache_misses");
 
     // -- Try potential arrangments.
     let mut arrangments = 0;
@@ -119,16 +131,21 @@ fn count_arrangments(
     arrangments
 }
 
-fn main() {
-    let spring_records: Vec<SpringRecord> = stdin()
-        .lock()
-        .lines()
-        .map(|line| line.unwrap().into())
-        .collect();
-    let mut cache = HashMap::new();
-    let sum: usize = spring_records
-        .iter()
-        .map(|sr| count_arrangments(&mut cache, &sr.springs, &sr.checksum))
+pub fn parse(input: &str) -> Result<Vec<SpringRecord>, Error> {
+    common::lines(input).map(SpringRecord::try_from).collect()
+}
+
+pub fn answer(spring_records: &[SpringRecord]) -> Result<Answer, Error> {
+    // -- Records are independent, so each one gets its own cache and worker.
+    let sum: usize = Pool::available()
+        .map(spring_records, |sr| {
+            count_arrangments(&mut HashMap::new(), &sr.springs, &sr.checksum)
+        })
+        .into_iter()
         .sum();
-    println!("{sum}");
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
@@ -0,0 +1,132 @@
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- Short records, so trying every assignment of the unknown springs stays cheap.
+fn small(rng: &mut Rng, size: usize) -> String {
+    generate::records(rng, size, 3, 3)
+}
+
+type Record = (Vec<char>, Vec<usize>);
+
+fn records(input: &str) -> Result<Vec<Record>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let bad = || Error::new(format!("malformed record '{}'", line));
+            let (springs, groups) = line.split_once(' ').ok_or_else(bad)?;
+            let groups = groups
+                .split(',')
+                .map(|group| group.parse().ok().filter(|group| *group > 0))
+                .collect::<Option<_>>()
+                .ok_or_else(bad)?;
+
+            if springs.chars().any(|ch| !".#?".contains(ch)) {
+                return Err(bad());
+            }
+
+            Ok((springs.chars().collect(), groups))
+        })
+        .collect()
+}
+
+fn groups(springs: &[char]) -> Vec<usize> {
+    springs
+        .split(|spring| *spring != '#')
+        .map(|run| run.len())
+        .filter(|len| *len > 0)
+        .collect()
+}
+
+// -- Tries every assignment of the unknown springs.
+fn brute_force(springs: &[char], expected: &[usize]) -> u64 {
+    let unknowns: Vec<usize> = (0..springs.len()).filter(|i| springs[*i] == '?').collect();
+    let mut springs = springs.to_vec();
+
+    (0..1_u64 << unknowns.len())
+        .filter(|bits| {
+            for (bit, index) in unknowns.iter().enumerate() {
+                springs[*index] = if bits >> bit & 1 == 1 { '#' } else { '.' };
+            }
+            groups(&springs) == expected
+        })
+        .count() as u64
+}
+
+// -- Runs the record through the automaton of ".*#{a}.+#{b}.+ ... .*", counting the
+// -- paths to every state rather than enumerating them.
+fn automaton(springs: &[char], expected: &[usize]) -> u64 {
+    let mut pattern = vec!['.'];
+    for group in expected {
+        pattern.extend(std::iter::repeat_n('#', *group));
+        pattern.push('.');
+    }
+
+    let mut counts = vec![0_u64; pattern.len()];
+    counts[0] = 1;
+
+    for spring in springs {
+        let mut next = vec![0_u64; pattern.len()];
+
+        for (state, count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            let follows = |ch| pattern.get(state + 1) == Some(&ch);
+
+            if *spring != '#' {
+                if pattern[state] == '.' {
+                    next[state] += count;
+                }
+                if follows('.') {
+                    next[state + 1] += count;
+                }
+            }
+            if *spring != '.' && follows('#') {
+                next[state + 1] += count;
+            }
+        }
+
+        counts = next;
+    }
+
+    counts[pattern.len() - 1] + counts[pattern.len() - 2]
+}
+
+fn unfold(springs: &[char], groups: &[usize]) -> (Vec<char>, Vec<usize>) {
+    let springs = [springs; 5].join(&'?');
+    (springs, groups.repeat(5))
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let sum: u64 = records(input)?
+        .iter()
+        .map(|(springs, groups)| brute_force(springs, groups))
+        .sum();
+    Ok(sum.into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let sum: u64 = records(input)?
+        .iter()
+        .map(|(springs, groups)| {
+            let (springs, groups) = unfold(springs, groups);
+            automaton(&springs, &groups)
+        })
+        .sum();
+    Ok(sum.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: small,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use common::report::Part;
+
+fn main() -> ExitCode {
+    let part = Part {
+        year: 2023,
+        day: 13,
+        part: 2,
+        timed: common::timed!(point_of_incidence::part2),
+    };
+    common::cli::main(env!("CARGO_MANIFEST_DIR"), &part)
+}
@@ -0,0 +1,151 @@
+use common::random::Rng;
+
+// -- A reflection line between rows (or columns, when `vertical`) `line - 1` and `line`.
+#[derive(Clone, Copy)]
+struct Mirror {
+    vertical: bool,
+    line: usize,
+}
+
+impl Mirror {
+    fn partner(
+        &self,
+        (row, col): (usize, usize),
+        nrows: usize,
+        ncols: usize,
+    ) -> Option<(usize, usize)> {
+        let (index, len) = if self.vertical {
+            (col, ncols)
+        } else {
+            (row, nrows)
+        };
+        let mirrored = (2 * self.line).checked_sub(index + 1)?;
+
+        match (mirrored < len, self.vertical) {
+            (false, _) => None,
+            (true, true) => Some((row, mirrored)),
+            (true, false) => Some((mirrored, col)),
+        }
+    }
+}
+
+fn find(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[index] = root;
+    root
+}
+
+// -- Counts the differing cells for every horizontal line, then every vertical one.
+fn mismatches(pattern: &[Vec<bool>]) -> Vec<usize> {
+    let (nrows, ncols) = (pattern.len(), pattern[0].len());
+    let mut counts = Vec::new();
+
+    for vertical in [false, true] {
+        let len = if vertical { ncols } else { nrows };
+
+        for line in 1..len {
+            let mirror = Mirror { vertical, line };
+            let count = (0..nrows)
+                .flat_map(|row| (0..ncols).map(move |col| (row, col)))
+                .filter(|&(row, col)| {
+                    mirror
+                        .partner((row, col), nrows, ncols)
+                        .is_some_and(|(r, c)| pattern[row][col] != pattern[r][c])
+                })
+                .count();
+            counts.push(count / 2);
+        }
+    }
+
+    counts
+}
+
+fn random_mirror(rng: &mut Rng, nrows: usize, ncols: usize) -> Mirror {
+    let vertical = rng.chance(0.5);
+    let len = if vertical { ncols } else { nrows };
+    Mirror {
+        vertical,
+        line: rng.range_usize(1..=len - 1),
+    }
+}
+
+// -- A pattern with exactly one perfect reflection and exactly one reflection that is off
+// -- by a single cell: symmetric about two random lines, then one cell is flipped that only
+// -- the second line pairs up.
+fn pattern(rng: &mut Rng) -> Vec<Vec<bool>> {
+    loop {
+        let nrows = rng.range_usize(5..=17);
+        let ncols = rng.range_usize(5..=17);
+        let perfect = random_mirror(rng, nrows, ncols);
+        let smudged = random_mirror(rng, nrows, ncols);
+
+        let mut parents: Vec<usize> = (0..nrows * ncols).collect();
+        for row in 0..nrows {
+            for col in 0..ncols {
+                for mirror in [perfect, smudged] {
+                    if let Some((r, c)) = mirror.partner((row, col), nrows, ncols) {
+                        let (a, b) = (
+                            find(&mut parents, row * ncols + col),
+                            find(&mut parents, r * ncols + c),
+                        );
+                        parents[a] = b;
+                    }
+                }
+            }
+        }
+
+        let values: Vec<bool> = (0..nrows * ncols).map(|_| rng.chance(0.5)).collect();
+        let mut pattern: Vec<Vec<bool>> = (0..nrows)
+            .map(|row| {
+                (0..ncols)
+                    .map(|col| values[find(&mut parents, row * ncols + col)])
+                    .collect()
+            })
+            .collect();
+
+        let candidates: Vec<(usize, usize)> = (0..nrows)
+            .flat_map(|row| (0..ncols).map(move |col| (row, col)))
+            .filter(|&cell| {
+                perfect.partner(cell, nrows, ncols).is_none()
+                    && smudged.partner(cell, nrows, ncols).is_some()
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            continue;
+        }
+
+        let (row, col) = *rng.choose(&candidates);
+        pattern[row][col] = !pattern[row][col];
+
+        let counts = mismatches(&pattern);
+        let exact = counts.iter().filter(|count| **count == 0).count();
+        let off_by_one = counts.iter().filter(|count| **count == 1).count();
+
+        if exact == 1 && off_by_one == 1 {
+            return pattern;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- `size` patterns separated by blank lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            pattern(rng)
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|cell| if *cell { '#' } else { '.' })
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,64 @@
+use common::{Answer, Error};
+use grid::Grid;
+
+fn find_mirror_smudge(grid: &Grid<bool>) -> usize {
+    let rows: Vec<_> = grid.rows().collect();
+
+    for row_index in 1..rows.len() {
+        let above = &rows[..row_index];
+        let below = &rows[row_index..];
+
+        let mut num_smudges = 0;
+
+        'row: for (above_row, below_row) in above.iter().rev().zip(below.iter()) {
+            for (above_col, below_col) in above_row.iter().zip(below_row.iter()) {
+                if above_col != below_col {
+                    num_smudges += 1;
+                }
+
+                if num_smudges > 1 {
+                    break 'row;
+                }
+            }
+        }
+
+        if num_smudges == 1 {
+            return row_index;
+        }
+    }
+
+    0
+}
+
+fn score(grid: &Grid<bool>) -> usize {
+    (find_mirror_smudge(grid) * 100) + find_mirror_smudge(&grid.transpose())
+}
+
+fn parse_grids(input: &str) -> Result<Vec<Grid<bool>>, Error> {
+    let mut grids = Vec::new();
+
+    for block in common::split_blocks(input) {
+        let grid = Grid::parse_lines(block, |_, c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(Error::new(format!("invalid character: '{}'", c))),
+        })?;
+
+        grids.push(grid);
+    }
+
+    Ok(grids)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid<bool>>, Error> {
+    parse_grids(input)
+}
+
+pub fn answer(grids: &[Grid<bool>]) -> Result<Answer, Error> {
+    let sum: usize = grids.iter().map(score).sum();
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,111 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+type Pattern = Vec<Vec<bool>>;
+
+fn patterns(input: &str) -> Result<Vec<Pattern>, Error> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            let pattern: Pattern = block
+                .lines()
+                .map(|line| line.chars().map(|ch| ch == '#').collect())
+                .collect();
+            let valid = block.chars().all(|ch| ".#\n".contains(ch));
+
+            if !valid
+                || pattern.is_empty()
+                || pattern.iter().any(|row| row.len() != pattern[0].len())
+            {
+                return Err(Error::new("malformed pattern"));
+            }
+
+            Ok(pattern)
+        })
+        .collect()
+}
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len())
+        .map(|col| pattern.iter().map(|row| row[col]).collect())
+        .collect()
+}
+
+// -- The summary value of every line the pattern reflects across: rows above a horizontal
+// -- line count a hundred each, columns left of a vertical line one each.
+fn reflections(pattern: &Pattern) -> Vec<usize> {
+    let mirrored = |rows: &Pattern, line: usize| {
+        (0..line)
+            .rev()
+            .zip(line..rows.len())
+            .all(|(above, below)| rows[above] == rows[below])
+    };
+
+    let columns = transpose(pattern);
+    let horizontal = (1..pattern.len()).filter(|line| mirrored(pattern, *line));
+    let vertical = (1..columns.len()).filter(|line| mirrored(&columns, *line));
+
+    horizontal.map(|line| 100 * line).chain(vertical).collect()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let mut sum = 0;
+
+    for pattern in patterns(input)? {
+        match reflections(&pattern)[..] {
+            [value] => sum += value,
+            _ => return Err(Error::new("pattern without exactly one reflection")),
+        }
+    }
+
+    Ok(sum.into())
+}
+
+// -- Flips every cell in turn and looks for a reflection the pattern did not have.
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let mut sum = 0;
+
+    for mut pattern in patterns(input)? {
+        let original = reflections(&pattern);
+        let mut found = Vec::new();
+
+        for row in 0..pattern.len() {
+            for col in 0..pattern[row].len() {
+                pattern[row][col] = !pattern[row][col];
+                found.extend(
+                    reflections(&pattern)
+                        .into_iter()
+                        .filter(|value| !original.contains(value)),
+                );
+                pattern[row][col] = !pattern[row][col];
+            }
+        }
+
+        found.sort();
+        found.dedup();
+
+        match found[..] {
+            [value] => sum += value,
+            _ => return Err(Error::new("pattern without exactly one smudged reflection")),
+        }
+    }
+
+    Ok(sum.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,22 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` platform of rounded rocks, cube rocks and empty ground.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        for _ in 0..size {
+            let roll = rng.unit();
+            input.push(match roll {
+                roll if roll < 0.2 => 'O',
+                roll if roll < 0.3 => '#',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+
+    input
+}
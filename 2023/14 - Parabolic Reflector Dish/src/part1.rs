@@ -0,0 +1,122 @@
+use common::{visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+use Direction::*;
+
+// -- TileType.
+#[derive(Clone, PartialEq)]
+pub enum TileType {
+    RoundRock,
+    CubeRock,
+    Ground,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'O' => Ok(RoundRock),
+            '#' => Ok(CubeRock),
+            '.' => Ok(Ground),
+            _ => Err(Error::new(format!("invalid tile character: '{}'", ch))),
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            RoundRock => 'O',
+            CubeRock => '#',
+            Ground => '.',
+        }
+    }
+}
+
+fn range_iter(start: usize, stop: usize) -> Box<dyn Iterator<Item = usize>> {
+    if start < stop {
+        Box::new(start..stop)
+    } else {
+        Box::new((stop..start).rev())
+    }
+}
+
+fn do_tilt(grid: &mut Grid<TileType>, direction: &Direction) {
+    let nrows = grid.nrows();
+    let ncols = grid.ncols();
+
+    for row in match direction {
+        North | West | East => range_iter(0, nrows),
+        South => range_iter(nrows, 0),
+    } {
+        for col in match direction {
+            North | South | West => range_iter(0, ncols),
+            East => range_iter(ncols, 0),
+        } {
+            let coord = Coord2D::from_indices(col, row);
+
+            if grid[coord] == RoundRock {
+                let mut curr = coord;
+
+                while let Some(next) = grid.step(&curr, *direction) {
+                    if grid[next] == Ground {
+                        grid[curr] = Ground;
+                        grid[next] = RoundRock;
+                    } else {
+                        break;
+                    }
+
+                    curr = next;
+                }
+            }
+        }
+
+        // -- One frame per row, so the rocks can be seen sliding into place.
+        visualize::frame(|| render(grid));
+    }
+}
+
+fn render(grid: &Grid<TileType>) -> String {
+    let round_rocks = grid
+        .iter()
+        .filter(|(_, tile)| **tile == RoundRock)
+        .map(|(coord, _)| coord);
+
+    Canvas::from_grid(grid, TileType::glyph)
+        .layer(Color::Gray, grid.coords())
+        .layer(Color::Yellow, round_rocks)
+        .render()
+}
+
+fn get_load(grid: &Grid<TileType>) -> usize {
+    let mut load = 0;
+
+    for (row, tiles) in grid.rows().enumerate() {
+        let nround = tiles.iter().filter(|tile| **tile == RoundRock).count();
+        load += nround * (grid.nrows() - row);
+    }
+
+    load
+}
+
+fn parse_grid(input: &str) -> Result<Grid<TileType>, Error> {
+    Grid::try_from(input)
+}
+
+pub fn parse(input: &str) -> Result<Grid<TileType>, Error> {
+    parse_grid(input)
+}
+
+pub fn answer(grid: &Grid<TileType>) -> Result<Answer, Error> {
+    let mut grid = grid.clone();
+    visualize::frame(|| render(&grid));
+    do_tilt(&mut grid, &North);
+    Ok(get_load(&grid).into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
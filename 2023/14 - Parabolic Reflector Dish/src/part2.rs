@@ -0,0 +1,164 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use common::{diagnostics, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+use Direction::*;
+
+pub(crate) const CYCLE_DIRECTONS: [Direction; 4] = [North, West, South, East];
+
+// -- TileType.
+#[derive(Clone, PartialEq, Hash)]
+pub enum TileType {
+    RoundRock,
+    CubeRock,
+    Ground,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'O' => Ok(RoundRock),
+            '#' => Ok(CubeRock),
+            '.' => Ok(Ground),
+            _ => Err(Error::new(format!("invalid tile character: '{}'", ch))),
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            RoundRock => 'O',
+            CubeRock => '#',
+            Ground => '.',
+        }
+    }
+}
+
+fn range_iter(start: usize, stop: usize) -> Box<dyn Iterator<Item = usize>> {
+    if start < stop {
+        Box::new(start..stop)
+    } else {
+        Box::new((stop..start).rev())
+    }
+}
+
+pub(crate) fn do_tilt(grid: &mut Grid<TileType>, direction: &Direction) {
+    let nrows = grid.nrows();
+    let ncols = grid.ncols();
+
+    for row in match direction {
+        North | West | East => range_iter(0, nrows),
+        South => range_iter(nrows, 0),
+    } {
+        for col in match direction {
+            North | South | West => range_iter(0, ncols),
+            East => range_iter(ncols, 0),
+        } {
+            let coord = Coord2D::from_indices(col, row);
+
+            if grid[coord] == RoundRock {
+                let mut curr = coord;
+
+                while let Some(next) = grid.step(&curr, *direction) {
+                    if grid[next] == Ground {
+                        grid[curr] = Ground;
+                        grid[next] = RoundRock;
+                    } else {
+                        break;
+                    }
+
+                    curr = next;
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn get_load(grid: &Grid<TileType>) -> usize {
+    let mut load = 0;
+
+    for (row, tiles) in grid.rows().enumerate() {
+        let nround = tiles.iter().filter(|tile| **tile == RoundRock).count();
+        load += nround * (grid.nrows() - row);
+    }
+
+    load
+}
+
+pub(crate) fn render(grid: &Grid<TileType>) -> String {
+    let round_rocks = grid
+        .iter()
+        .filter(|(_, tile)| **tile == RoundRock)
+        .map(|(coord, _)| coord);
+
+    Canvas::from_grid(grid, TileType::glyph)
+        .layer(Color::Gray, grid.coords())
+        .layer(Color::Yellow, round_rocks)
+        .render()
+}
+
+pub(crate) fn hash_grid(grid: &Grid<TileType>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    grid.hash(&mut hasher);
+    hasher.finish()
+}
+
+const NUM_CYCLES: usize = 1_000_000_000;
+
+fn do_cycles(grid: &mut Grid<TileType>, ncycles: usize) {
+    let mut tracker = Vec::new();
+
+    for cycle in 0..ncycles {
+        // -- Do cycle.
+        for direction in CYCLE_DIRECTONS {
+            do_tilt(grid, &direction);
+        }
+        visualize::frame(|| render(grid));
+
+        // -- Check for pattern.
+        let curr_hash = hash_grid(grid);
+
+        if let Some(position) = tracker
+            .iter()
+            .position(|(grid_hash, _)| *grid_hash == curr_hash)
+        {
+            diagnostics::record("cycle_start", position + 1);
+            diagnostics::record("cycle_length", cycle - position);
+
+            // -- `tracker[i]` holds the grid after `i + 1` cycles.
+            let index = position + (NUM_CYCLES - position - 1) % (cycle - position);
+            (_, *grid) = tracker.swap_remove(index);
+
+            // -- The last frame is the platform as it stands after all the cycles.
+            visualize::frame(|| render(grid));
+            return;
+        }
+
+        tracker.push((curr_hash, grid.clone()));
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Grid<TileType>, Error> {
+    Grid::try_from(input)
+}
+
+pub fn parse(input: &str) -> Result<Grid<TileType>, Error> {
+    parse_grid(input)
+}
+
+pub fn answer(grid: &Grid<TileType>) -> Result<Answer, Error> {
+    let mut grid = grid.clone();
+    do_cycles(&mut grid, NUM_CYCLES);
+    Ok(get_load(&grid).into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+type Platform = Vec<Vec<char>>;
+
+fn platform(input: &str) -> Result<Platform, Error> {
+    let rows: Platform = input.lines().map(|line| line.chars().collect()).collect();
+
+    if rows.is_empty()
+        || rows.iter().any(|row| row.len() != rows[0].len())
+        || rows.iter().flatten().any(|ch| !"O#.".contains(*ch))
+    {
+        return Err(Error::new("malformed platform"));
+    }
+
+    Ok(rows)
+}
+
+// -- Rolls every rock one tile at a time until none of them can move any further.
+fn tilt(rows: &mut Platform, (dr, dc): (isize, isize)) {
+    let (nrows, ncols) = (rows.len() as isize, rows[0].len() as isize);
+    let mut moved = true;
+
+    while moved {
+        moved = false;
+
+        for row in 0..nrows {
+            for col in 0..ncols {
+                let (to_row, to_col) = (row + dr, col + dc);
+
+                if !(0..nrows).contains(&to_row) || !(0..ncols).contains(&to_col) {
+                    continue;
+                }
+
+                let (from, to) = (
+                    (row as usize, col as usize),
+                    (to_row as usize, to_col as usize),
+                );
+                if rows[from.0][from.1] == 'O' && rows[to.0][to.1] == '.' {
+                    rows[from.0][from.1] = '.';
+                    rows[to.0][to.1] = 'O';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn load(rows: &Platform) -> usize {
+    rows.iter()
+        .enumerate()
+        .map(|(row, cells)| (rows.len() - row) * cells.iter().filter(|ch| **ch == 'O').count())
+        .sum()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let mut rows = platform(input)?;
+    tilt(&mut rows, (-1, 0));
+    Ok(load(&rows).into())
+}
+
+// -- Spins until a platform repeats, remembering every one seen.
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    const CYCLES: usize = 1_000_000_000;
+
+    let mut rows = platform(input)?;
+    let mut seen = HashMap::new();
+    let mut history = vec![rows.clone()];
+
+    for cycle in 1..=CYCLES {
+        for direction in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            tilt(&mut rows, direction);
+        }
+
+        if let Some(first) = seen.insert(rows.clone(), cycle) {
+            let remaining = (CYCLES - cycle) % (cycle - first);
+            return Ok(load(&history[first + remaining]).into());
+        }
+
+        history.push(rows.clone());
+    }
+
+    Ok(load(&rows).into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use common::stepper::{Simulation, Start};
+use common::Error;
+use grid::{Direction, Grid};
+
+use crate::part2::{self, TileType, CYCLE_DIRECTONS};
+
+// -----------------------------------------------------------------------------
+// -- The spin cycles of part 2, one tilt at a time; the first tilt is all of part 1.
+struct Platform {
+    grid: Grid<TileType>,
+    tilts: usize,
+    // -- The first tilt count each state was seen at, keyed by grid hash and the
+    // -- direction of the next tilt, as part 2 finds its cycle.
+    seen: HashMap<(u64, usize), usize>,
+}
+
+impl Platform {
+    fn last_direction(&self) -> Option<Direction> {
+        let index = self.tilts.checked_sub(1)?;
+        Some(CYCLE_DIRECTONS[index % CYCLE_DIRECTONS.len()])
+    }
+}
+
+impl Simulation for Platform {
+    fn step(&mut self) -> bool {
+        let direction = CYCLE_DIRECTONS[self.tilts % CYCLE_DIRECTONS.len()];
+        part2::do_tilt(&mut self.grid, &direction);
+        self.tilts += 1;
+        true
+    }
+
+    fn advance(&mut self, steps: usize) -> usize {
+        let target = self.tilts + steps;
+
+        while self.tilts < target {
+            let phase = self.tilts % CYCLE_DIRECTONS.len();
+            let key = (part2::hash_grid(&self.grid), phase);
+
+            // -- Back to a state seen before, so whole laps of the loop change nothing.
+            if let Some(first) = self.seen.insert(key, self.tilts) {
+                let lap = self.tilts - first;
+                self.tilts += (target - self.tilts) / lap * lap;
+            }
+
+            if self.tilts < target {
+                self.step();
+            }
+        }
+
+        steps
+    }
+
+    fn render(&self) -> String {
+        part2::render(&self.grid)
+    }
+
+    fn status(&self) -> String {
+        let tilted = match self.last_direction() {
+            Some(direction) => format!("tilted {:?}", direction),
+            None => String::from("not tilted yet"),
+        };
+
+        format!(
+            "cycle {}, {}, load {}",
+            self.tilts.div_ceil(CYCLE_DIRECTONS.len()),
+            tilted,
+            part2::get_load(&self.grid)
+        )
+    }
+}
+
+pub fn start(input: &str) -> Result<Start, Error> {
+    let grid = Rc::new(part2::parse(input)?);
+
+    Ok(Box::new(move || {
+        Box::new(Platform {
+            grid: (*grid).clone(),
+            tilts: 0,
+            seen: HashMap::new(),
+        })
+    }))
+}
@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- One line of `size` steps. Labels come from a pool a tenth the size of the sequence,
+// -- so lenses are replaced and removed as well as added.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size / 10 + 1)
+        .map(|_| {
+            let len = rng.range_usize(2..=6);
+            rng.word(len)
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+
+            if rng.chance(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.range(1..=9))
+            }
+        })
+        .collect();
+
+    steps.join(",") + "\n"
+}
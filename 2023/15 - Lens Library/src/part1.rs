@@ -0,0 +1,31 @@
+use common::{Answer, Error};
+
+fn holiday_hash(input: &str) -> usize {
+    let mut value = 0;
+
+    for char in input.chars() {
+        value += char as usize;
+        value *= 17;
+        value %= 256;
+    }
+
+    value
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, Error> {
+    let steps = input
+        .lines()
+        .next()
+        .ok_or_else(|| Error::new("input is empty"))?
+        .split(',');
+    Ok(steps.map(String::from).collect())
+}
+
+pub fn answer(steps: &[String]) -> Result<Answer, Error> {
+    let sum: usize = steps.iter().map(|step| holiday_hash(step)).sum();
+    Ok(sum.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
-use std::io::{stdin, BufRead};
 use std::rc::Rc;
 
+use common::{Answer, Error, Line};
+
 fn holiday_hash(input: &str) -> usize {
     let mut value = 0;
 
@@ -14,7 +15,8 @@ fn holiday_hash(input: &str) -> usize {
     value
 }
 
-enum Command {
+#[derive(Clone)]
+pub enum Command {
     Put {
         label: Rc<String>,
         focal: usize,
@@ -28,30 +30,26 @@ enum Command {
 
 use Command::*;
 
-impl From<&str> for Command {
-    fn from(input: &str) -> Self {
-        if input.contains('=') {
-            let mut components = input.split('=');
-            let label = components.next().unwrap().to_string();
-            let focal = components.next().unwrap().parse().unwrap();
-            let holiday_hash = holiday_hash(&label);
-            let label = label.into();
-            return Put {
+impl Command {
+    fn parse(line: &Line, input: &str) -> Result<Self, Error> {
+        if let Some((label, focal)) = input.split_once('=') {
+            let focal = line.parse(focal)?;
+            let holiday_hash = holiday_hash(label);
+            let label = label.to_string().into();
+            return Ok(Put {
                 label,
                 focal,
                 holiday_hash,
-            };
-        } else if input.contains('-') {
-            let mut components = input.split('-');
-            let label = components.next().unwrap().to_string();
-            let holiday_hash = holiday_hash(&label);
-            let label = label.into();
-            return Get {
+            });
+        } else if let Some((label, _)) = input.split_once('-') {
+            let holiday_hash = holiday_hash(label);
+            let label = label.to_string().into();
+            return Ok(Get {
                 label,
                 holiday_hash,
-            };
+            });
         }
-        panic!("bad command: \"{}\"", input);
+        Err(line.error_in(input, format!("bad command: \"{}\"", input)))
     }
 }
 
@@ -61,41 +59,38 @@ struct Lens {
     holiday_hash: usize,
 }
 
-impl From<Command> for Lens {
-    fn from(cmd: Command) -> Self {
-        if let Put {
-            label,
-            focal,
-            holiday_hash,
-        } = cmd
-        {
-            Self {
-                label,
-                focal,
-                holiday_hash,
-            }
-        } else {
-            panic!("can only form Lens from \"Put\" command")
-        }
-    }
+pub fn parse(input: &str) -> Result<Vec<Command>, Error> {
+    let line = common::lines(input)
+        .next()
+        .ok_or_else(|| Error::new("input is empty"))?;
+    line.as_str()
+        .split(',')
+        .map(|step| Command::parse(&line, step))
+        .collect()
 }
 
-fn main() {
+pub fn answer(commands: &[Command]) -> Result<Answer, Error> {
     let mut boxes: HashMap<usize, Vec<Lens>> = HashMap::new();
 
-    for cmd_str in stdin().lock().lines().next().unwrap().unwrap().split(',') {
-        let command = cmd_str.into();
-
-        match &command {
-            Put { .. } => {
-                let new_lens: Lens = command.into();
+    for command in commands {
+        match command {
+            Put {
+                label,
+                focal,
+                holiday_hash,
+            } => {
+                let new_lens = Lens {
+                    label: label.clone(),
+                    focal: *focal,
+                    holiday_hash: *holiday_hash,
+                };
 
                 if let Some(lenses) = boxes.get_mut(&new_lens.holiday_hash) {
                     if let Some(pos) = lenses
                         .iter()
                         .position(|curr_lens| curr_lens.label == new_lens.label)
                     {
-                        *lenses.get_mut(pos).unwrap() = new_lens;
+                        lenses[pos] = new_lens;
                     } else {
                         lenses.push(new_lens);
                     }
@@ -116,7 +111,7 @@ fn main() {
         }
     }
 
-    let mut total = 0;
+    let mut total = 0_usize;
 
     for (boxnum, lenses) in boxes.iter() {
         for (slotnum, lens) in lenses.iter().enumerate() {
@@ -124,5 +119,9 @@ fn main() {
         }
     }
 
-    println!("{}", total);
+    Ok(total.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
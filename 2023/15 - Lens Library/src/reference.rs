@@ -0,0 +1,72 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+fn hash(text: &str) -> usize {
+    text.bytes()
+        .fold(0, |value, byte| (value + usize::from(byte)) * 17 % 256)
+}
+
+fn steps(input: &str) -> Result<Vec<&str>, Error> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| Error::new("input is empty"))?;
+    Ok(line.split(',').collect())
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let sum: usize = steps(input)?.iter().map(|step| hash(step)).sum();
+    Ok(sum.into())
+}
+
+// -- Keeps all 256 boxes as plain lists of (label, focal length).
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+
+    for step in steps(input)? {
+        if let Some((label, focal)) = step.split_once('=') {
+            let focal = focal
+                .parse()
+                .map_err(|_| Error::new(format!("bad focal length in '{}'", step)))?;
+            let lenses = &mut boxes[hash(label)];
+
+            match lenses.iter_mut().find(|(other, _)| *other == label) {
+                Some(lens) => lens.1 = focal,
+                None => lenses.push((label, focal)),
+            }
+        } else if let Some(label) = step.strip_suffix('-') {
+            boxes[hash(label)].retain(|(other, _)| *other != label);
+        } else {
+            return Err(Error::new(format!("bad step '{}'", step)));
+        }
+    }
+
+    let power: usize = boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(number, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(slot, (_, focal))| (number + 1) * (slot + 1) * focal)
+        })
+        .sum();
+    Ok(power.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,24 @@
+use common::random::Rng;
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` contraption, roughly one tile in ten holding a mirror or splitter.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        for _ in 0..size {
+            let tile = if rng.chance(0.1) {
+                *rng.choose(&DEVICES)
+            } else {
+                '.'
+            };
+            input.push(tile);
+        }
+        input.push('\n');
+    }
+
+    input
+}
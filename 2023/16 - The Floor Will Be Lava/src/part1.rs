@@ -0,0 +1,292 @@
+use common::{image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+use Direction::*;
+
+// -----------------------------------------------------------------------------
+#[derive(PartialEq, Eq)]
+enum TileType {
+    MirrorSlash,
+    MirrorBackSlash,
+    SplitHorz,
+    SplitVert,
+    Empty,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '/' => Ok(MirrorSlash),
+            '\\' => Ok(MirrorBackSlash),
+            '-' => Ok(SplitHorz),
+            '|' => Ok(SplitVert),
+            '.' => Ok(Empty),
+            _ => Err(Error::new(format!("invalid tile character: '{}'", ch))),
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            MirrorSlash => '/',
+            MirrorBackSlash => '\\',
+            SplitHorz => '-',
+            SplitVert => '|',
+            Empty => '.',
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+struct LaserHead {
+    coord: Coord2D,
+    direction: Direction,
+}
+
+impl LaserHead {
+    fn new(coord: &Coord2D, direction: &Direction) -> Self {
+        let coord = *coord;
+        let direction = *direction;
+        Self { coord, direction }
+    }
+
+    fn update(&mut self, new_coord: &Coord2D) {
+        self.coord = *new_coord;
+    }
+
+    fn split(&mut self, new_coord: &Coord2D, tile: &TileType) -> Option<LaserHead> {
+        let (direction_a, direction_b) = match (tile, &self.direction) {
+            (SplitVert, East | West) => (North, South),
+            (SplitHorz, North | South) => (East, West),
+            (SplitVert, North | South) | (SplitHorz, East | West) => {
+                self.update(new_coord);
+                return None;
+            }
+            _ => panic!("invalid tile type for split"),
+        };
+        self.coord = *new_coord;
+        self.direction = direction_a;
+        Some(LaserHead::new(new_coord, &direction_b))
+    }
+
+    fn reflect(&mut self, new_coord: &Coord2D, tile: &TileType) {
+        let new_direction = match (tile, &self.direction) {
+            (MirrorSlash, North) => East,
+            (MirrorSlash, East) => North,
+            (MirrorSlash, South) => West,
+            (MirrorSlash, West) => South,
+            (MirrorBackSlash, North) => West,
+            (MirrorBackSlash, East) => South,
+            (MirrorBackSlash, South) => East,
+            (MirrorBackSlash, West) => North,
+            _ => panic!("invalid tile type for reflect"),
+        };
+        self.coord = *new_coord;
+        self.direction = new_direction;
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Default)]
+struct DirectionsVisted {
+    north: bool,
+    east: bool,
+    south: bool,
+    west: bool,
+}
+
+impl DirectionsVisted {
+    fn set_visit(&mut self, direction: &Direction) {
+        match direction {
+            North => self.north = true,
+            East => self.east = true,
+            South => self.south = true,
+            West => self.west = true,
+        }
+    }
+
+    fn get_visit(&self, direction: &Direction) -> bool {
+        match direction {
+            North => self.north,
+            East => self.east,
+            South => self.south,
+            West => self.west,
+        }
+    }
+
+    fn been_visited(&self) -> bool {
+        self.north || self.east || self.south || self.west
+    }
+}
+
+// -----------------------------------------------------------------------------
+struct VisitationGrid {
+    grid: Grid<DirectionsVisted>,
+}
+
+impl VisitationGrid {
+    fn new(ncols: usize, nrows: usize) -> Self {
+        let grid = Grid::new(ncols, nrows, Default::default());
+        Self { grid }
+    }
+
+    fn get(&self, coord: &Coord2D, direction: &Direction) -> bool {
+        self.grid[*coord].get_visit(direction)
+    }
+
+    fn set(&mut self, coord: &Coord2D, direction: &Direction) {
+        self.grid[*coord].set_visit(direction)
+    }
+
+    fn visited(&self) -> impl Iterator<Item = Coord2D> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, dir_visted)| dir_visted.been_visited())
+            .map(|(coord, _)| coord)
+    }
+
+    fn count_visited(&self) -> usize {
+        self.grid
+            .values()
+            .filter(|dir_visted| dir_visted.been_visited())
+            .count()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- The laser heads still moving, and every tile any of them has crossed so far.
+pub(crate) struct Beam {
+    laser_heads: Vec<LaserHead>,
+    visited: VisitationGrid,
+}
+
+impl Beam {
+    pub(crate) fn is_done(&self) -> bool {
+        self.laser_heads.is_empty()
+    }
+
+    pub(crate) fn num_heads(&self) -> usize {
+        self.laser_heads.len()
+    }
+
+    pub(crate) fn count_visited(&self) -> usize {
+        self.visited.count_visited()
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct Board {
+    grid: Grid<TileType>,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::try_from(input)?;
+        Ok(Self { grid })
+    }
+}
+
+impl Board {
+    pub(crate) fn beam(&self, start: &Coord2D, direction: &Direction) -> Beam {
+        Beam {
+            laser_heads: vec![LaserHead::new(start, direction)],
+            visited: VisitationGrid::new(self.grid.ncols(), self.grid.nrows()),
+        }
+    }
+
+    // -- Move every laser head on by one tile.
+    pub(crate) fn advance(&self, beam: &mut Beam) {
+        let Beam {
+            laser_heads,
+            visited,
+        } = beam;
+
+        let mut to_remove = Vec::new();
+        let mut new_heads = Vec::new();
+
+        for (pos, laser_head) in laser_heads.iter_mut().enumerate() {
+            let new_coord = self.grid.step(&laser_head.coord, laser_head.direction);
+
+            // -- New coordinate off map, laser dead.
+            if new_coord.is_none() {
+                to_remove.push(pos);
+                continue;
+            }
+
+            // -- Check if new coord has been visited by this laser head.
+            let new_coord = new_coord.unwrap();
+
+            if visited.get(&new_coord, &laser_head.direction) {
+                to_remove.push(pos);
+                continue;
+            }
+
+            // -- Log that this coord has been visited.
+            visited.set(&new_coord, &laser_head.direction);
+
+            // -- Check action to perform based on new cood's tile type.
+            let new_tile = &self.grid[new_coord];
+
+            match new_tile {
+                MirrorSlash | MirrorBackSlash => {
+                    laser_head.reflect(&new_coord, new_tile);
+                }
+                SplitHorz | SplitVert => {
+                    if let Some(split) = laser_head.split(&new_coord, new_tile) {
+                        new_heads.push(split);
+                    }
+                }
+                Empty => laser_head.update(&new_coord),
+            }
+        }
+
+        // -- Remove dead lasers.
+        for pos in to_remove.into_iter().rev() {
+            laser_heads.remove(pos);
+        }
+
+        // -- Add new lasers.
+        for new_laser_head in new_heads {
+            laser_heads.push(new_laser_head);
+        }
+    }
+
+    fn fire_laser(&self, start: &Coord2D, direction: &Direction) -> usize {
+        let mut beam = self.beam(start, direction);
+
+        while !beam.is_done() {
+            self.advance(&mut beam);
+            visualize::frame(|| self.canvas(&beam).render());
+        }
+
+        image::record(|| self.canvas(&beam).to_drawing());
+        beam.count_visited()
+    }
+
+    pub(crate) fn canvas(&self, beam: &Beam) -> Canvas {
+        Canvas::from_grid(&self.grid, TileType::glyph)
+            .layer(Color::Yellow, beam.visited.visited())
+            .highlight(beam.laser_heads.iter().map(|laser_head| laser_head.coord))
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    let num_tiles = board.fire_laser(&Coord2D::new(-1, 0), &East);
+    Ok(num_tiles.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
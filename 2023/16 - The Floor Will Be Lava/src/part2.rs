@@ -1,37 +1,9 @@
-use std::io::{stdin, BufRead, Stdin};
-
-// -----------------------------------------------------------------------------
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+use common::pool::Pool;
+use common::{image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
 
 use Direction::*;
 
-// -----------------------------------------------------------------------------
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord2D {
-    col: isize,
-    row: isize,
-}
-
-impl Coord2D {
-    fn new(col: isize, row: isize) -> Self {
-        Self { col, row }
-    }
-
-    fn ucol(&self) -> usize {
-        self.col.try_into().unwrap()
-    }
-
-    fn urow(&self) -> usize {
-        self.row.try_into().unwrap()
-    }
-}
-
 // -----------------------------------------------------------------------------
 #[derive(PartialEq, Eq)]
 enum TileType {
@@ -44,15 +16,29 @@ enum TileType {
 
 use TileType::*;
 
-impl From<char> for TileType {
-    fn from(ch: char) -> Self {
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
         match ch {
-            '/' => MirrorSlash,
-            '\\' => MirrorBackSlash,
-            '-' => SplitHorz,
-            '|' => SplitVert,
-            '.' => Empty,
-            _ => panic!("invalid tile character: '{}'", ch),
+            '/' => Ok(MirrorSlash),
+            '\\' => Ok(MirrorBackSlash),
+            '-' => Ok(SplitHorz),
+            '|' => Ok(SplitVert),
+            '.' => Ok(Empty),
+            _ => Err(Error::new(format!("invalid tile character: '{}'", ch))),
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            MirrorSlash => '/',
+            MirrorBackSlash => '\\',
+            SplitHorz => '-',
+            SplitVert => '|',
+            Empty => '.',
         }
     }
 }
@@ -107,7 +93,7 @@ impl LaserHead {
 }
 
 // -----------------------------------------------------------------------------
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct DirectionsVisted {
     north: bool,
     east: bool,
@@ -141,117 +127,67 @@ impl DirectionsVisted {
 
 // -----------------------------------------------------------------------------
 struct VisitationGrid {
-    grid: Vec<Vec<DirectionsVisted>>,
+    grid: Grid<DirectionsVisted>,
 }
 
 impl VisitationGrid {
     fn new(ncols: usize, nrows: usize) -> Self {
-        let mut grid = Vec::new();
-
-        for _ in 0..nrows {
-            let mut row = Vec::new();
-
-            for _ in 0..ncols {
-                row.push(Default::default());
-            }
-
-            grid.push(row);
-        }
-
+        let grid = Grid::new(ncols, nrows, Default::default());
         Self { grid }
     }
 
     fn get(&self, coord: &Coord2D, direction: &Direction) -> bool {
-        self.grid[coord.urow()][coord.ucol()].get_visit(direction)
+        self.grid[*coord].get_visit(direction)
     }
 
     fn set(&mut self, coord: &Coord2D, direction: &Direction) {
-        self.grid[coord.urow()][coord.ucol()].set_visit(direction)
+        self.grid[*coord].set_visit(direction)
     }
 
-    fn count_visited(&self) -> usize {
-        let mut total = 0;
-
-        for row in self.grid.iter() {
-            for dir_visted in row.iter() {
-                if dir_visted.been_visited() {
-                    total += 1;
-                }
-            }
-        }
+    fn visited(&self) -> impl Iterator<Item = Coord2D> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, dir_visted)| dir_visted.been_visited())
+            .map(|(coord, _)| coord)
+    }
 
-        total
+    fn count_visited(&self) -> usize {
+        self.grid
+            .values()
+            .filter(|dir_visted| dir_visted.been_visited())
+            .count()
     }
 }
 
 // -----------------------------------------------------------------------------
-struct Board {
-    grid: Vec<Vec<TileType>>,
-    ncols: usize,
-    nrows: usize,
+pub struct Board {
+    grid: Grid<TileType>,
 }
 
-impl From<&Stdin> for Board {
-    fn from(stdin: &Stdin) -> Self {
-        let mut grid = Vec::new();
-
-        for line in stdin.lock().lines() {
-            let line = line.unwrap();
-            let mut row = Vec::new();
-
-            for ch in line.chars() {
-                row.push(ch.into());
-            }
-
-            grid.push(row);
-        }
+impl TryFrom<&str> for Board {
+    type Error = Error;
 
-        let nrows = grid.len();
-        let ncols = grid[0].len();
-
-        Self { grid, ncols, nrows }
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::try_from(input)?;
+        Ok(Self { grid })
     }
 }
 
 impl Board {
-    fn get(&self, coord: &Coord2D) -> &TileType {
-        &self.grid[coord.urow()][coord.ucol()]
-    }
-
-    fn go_direction(&self, coord: &Coord2D, direction: &Direction) -> Option<Coord2D> {
-        let mut new_coord = *coord;
-
-        match direction {
-            North => new_coord.row -= 1,
-            South => new_coord.row += 1,
-            East => new_coord.col += 1,
-            West => new_coord.col -= 1,
-        }
-
-        let ncols = self.ncols.try_into().unwrap();
-        let nrows = self.nrows.try_into().unwrap();
-
-        if new_coord.col >= 0
-            && new_coord.col < ncols
-            && new_coord.row >= 0
-            && new_coord.row < nrows
-        {
-            return Some(new_coord);
-        }
-
-        None
+    fn fire_laser(&self, start: &Coord2D, direction: &Direction) -> usize {
+        self.energize(start, direction).count_visited()
     }
 
-    fn fire_laser(&self, start: &Coord2D, direction: &Direction) -> usize {
+    fn energize(&self, start: &Coord2D, direction: &Direction) -> VisitationGrid {
         let mut laser_heads = vec![LaserHead::new(start, direction)];
-        let mut visited = VisitationGrid::new(self.ncols, self.nrows);
+        let mut visited = VisitationGrid::new(self.grid.ncols(), self.grid.nrows());
 
         while !laser_heads.is_empty() {
             let mut to_remove = Vec::new();
             let mut new_heads = Vec::new();
 
             for (pos, laser_head) in laser_heads.iter_mut().enumerate() {
-                let new_coord = self.go_direction(&laser_head.coord, &laser_head.direction);
+                let new_coord = self.grid.step(&laser_head.coord, laser_head.direction);
 
                 // -- New coordinate off map, laser dead.
                 if new_coord.is_none() {
@@ -271,7 +207,7 @@ impl Board {
                 visited.set(&new_coord, &laser_head.direction);
 
                 // -- Check action to perform based on new cood's tile type.
-                let new_tile = self.get(&new_coord);
+                let new_tile = &self.grid[new_coord];
 
                 match new_tile {
                     MirrorSlash | MirrorBackSlash => {
@@ -297,12 +233,64 @@ impl Board {
             }
         }
 
-        visited.count_visited()
+        visited
     }
+
+    // -- Returns the most tiles energized, and the start and direction of the beam that
+    // -- energized them. Every beam is independent, so they are fired in parallel.
+    fn fire_barrage(&self) -> (usize, Coord2D, Direction) {
+        let (ncols, nrows) = (self.grid.ncols(), self.grid.nrows());
+        let mut starts = Vec::with_capacity(2 * (ncols + nrows));
+
+        // -- Horzontal
+        for row in 0..nrows {
+            starts.push((Coord2D::from_indices(0, row).step(West), East));
+            starts.push((Coord2D::from_indices(ncols, row), West));
+        }
+
+        // -- Vertical
+        for col in 0..ncols {
+            starts.push((Coord2D::from_indices(col, 0).step(North), South));
+            starts.push((Coord2D::from_indices(col, nrows), North));
+        }
+
+        let energized = Pool::available().map(&starts, |(start, direction)| {
+            self.fire_laser(start, direction)
+        });
+
+        energized
+            .into_iter()
+            .zip(starts)
+            .map(|(num_energized, (start, direction))| (num_energized, start, direction))
+            .max()
+            .unwrap_or((usize::MIN, Coord2D::default(), East))
+    }
+
+    fn canvas(&self, visited: &VisitationGrid, laser_heads: &[LaserHead]) -> Canvas {
+        Canvas::from_grid(&self.grid, TileType::glyph)
+            .layer(Color::Yellow, visited.visited())
+            .highlight(laser_heads.iter().map(|laser_head| laser_head.coord))
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    let (most_energized, start, direction) = board.fire_barrage();
+
+    // -- Only the winning beam is drawn, fired once more from where it started.
+    if visualize::enabled() || image::enabled() {
+        let canvas = board.canvas(&board.energize(&start, &direction), &[]);
+        visualize::frame(|| canvas.render());
+        image::record(|| canvas.to_drawing());
+    }
+
+    Ok(most_energized.into())
 }
 
-fn main() {
-    let board = Board::from(&stdin());
-    let num_tiles = board.fire_laser(&Coord2D::new(-1, 0), &East);
-    println!("{}", num_tiles);
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
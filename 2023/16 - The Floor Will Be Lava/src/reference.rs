@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+type Beam = ((isize, isize), (isize, isize));
+
+fn contraption(input: &str) -> Result<Vec<Vec<char>>, Error> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    if rows.is_empty()
+        || rows.iter().any(|row| row.len() != rows[0].len())
+        || rows.iter().flatten().any(|ch| !"./\\|-".contains(*ch))
+    {
+        return Err(Error::new("malformed contraption"));
+    }
+
+    Ok(rows)
+}
+
+// -- Moves all beams one tile per tick until every beam has left the grid or repeats a
+// -- tile and direction it was in before.
+fn energized(rows: &[Vec<char>], start: Beam) -> usize {
+    let (nrows, ncols) = (rows.len() as isize, rows[0].len() as isize);
+    let mut seen: HashSet<Beam> = HashSet::new();
+    let mut beams = vec![start];
+
+    while !beams.is_empty() {
+        let mut next = Vec::new();
+
+        for ((row, col), (dr, dc)) in beams {
+            if !(0..nrows).contains(&row) || !(0..ncols).contains(&col) {
+                continue;
+            }
+            if !seen.insert(((row, col), (dr, dc))) {
+                continue;
+            }
+
+            let directions = match rows[row as usize][col as usize] {
+                '/' => vec![(-dc, -dr)],
+                '\\' => vec![(dc, dr)],
+                '|' if dc != 0 => vec![(-1, 0), (1, 0)],
+                '-' if dr != 0 => vec![(0, -1), (0, 1)],
+                _ => vec![(dr, dc)],
+            };
+
+            for (dr, dc) in directions {
+                next.push(((row + dr, col + dc), (dr, dc)));
+            }
+        }
+
+        beams = next;
+    }
+
+    let tiles: HashSet<(isize, isize)> = seen.into_iter().map(|(tile, _)| tile).collect();
+    tiles.len()
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let rows = contraption(input)?;
+    Ok(energized(&rows, ((0, 0), (0, 1))).into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let rows = contraption(input)?;
+    let (last_row, last_col) = (rows.len() as isize - 1, rows[0].len() as isize - 1);
+
+    let mut starts = Vec::new();
+    for row in 0..=last_row {
+        starts.push(((row, 0), (0, 1)));
+        starts.push(((row, last_col), (0, -1)));
+    }
+    for col in 0..=last_col {
+        starts.push(((0, col), (1, 0)));
+        starts.push(((last_row, col), (-1, 0)));
+    }
+
+    let best = starts
+        .into_iter()
+        .map(|start| energized(&rows, start))
+        .max()
+        .unwrap_or(0);
+    Ok(best.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use common::stepper::{Simulation, Start};
+use common::Error;
+use grid::{Coord2D, Direction};
+
+use crate::part1::{self, Beam, Board};
+
+// -----------------------------------------------------------------------------
+// -- Part 1's beam, entering the top-left corner heading east, one move of every laser
+// -- head at a time.
+struct Laser {
+    board: Rc<Board>,
+    beam: Beam,
+}
+
+impl Simulation for Laser {
+    fn step(&mut self) -> bool {
+        if self.beam.is_done() {
+            return false;
+        }
+
+        self.board.advance(&mut self.beam);
+        true
+    }
+
+    fn render(&self) -> String {
+        self.board.canvas(&self.beam).render()
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "{} laser head(s), {} tile(s) energized",
+            self.beam.num_heads(),
+            self.beam.count_visited()
+        )
+    }
+}
+
+pub fn start(input: &str) -> Result<Start, Error> {
+    let board = Rc::new(part1::parse(input)?);
+
+    Ok(Box::new(move || {
+        let beam = board.beam(&Coord2D::new(-1, 0), &Direction::East);
+        Box::new(Laser {
+            board: board.clone(),
+            beam,
+        })
+    }))
+}
@@ -0,0 +1,18 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- A `size` x `size` map of heat losses. At least 5 x 5, so the ultra crucible, which
+// -- must move four blocks before it may stop, can reach the far corner.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(char::from(b'0' + rng.range(1..=9) as u8));
+        }
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,193 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use common::{image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+// -----------------------------------------------------------------------------
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+struct Node {
+    heat_loss: usize,
+    heuristic: usize,
+    coord: Coord2D,
+    direction: Option<Direction>,
+    num_steps: u8,
+    // -- Seen hash of the node this one was reached from, to trace the path back.
+    parent: u64,
+}
+
+impl Node {
+    fn seen_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.coord.hash(&mut hasher);
+        self.direction.hash(&mut hasher);
+        self.num_steps.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.heuristic.cmp(&other.heuristic).reverse()
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+const RADIX: u32 = 10;
+
+// -----------------------------------------------------------------------------
+pub struct Board {
+    grid: Grid<u8>,
+    goal: Coord2D,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(input, |_, ch| {
+            ch.to_digit(RADIX)
+                .map(|value| value as u8)
+                .ok_or_else(|| Error::new(format!("invalid heat loss digit: '{}'", ch)))
+        })?;
+
+        if grid.is_empty() {
+            return Err(Error::new("input is empty"));
+        }
+
+        let goal = Coord2D::from_indices(grid.ncols() - 1, grid.nrows() - 1);
+
+        Ok(Self { grid, goal })
+    }
+}
+
+impl Board {
+    fn add_next_node(
+        &self,
+        node: &Node,
+        direction: &Direction,
+        queue: &mut BinaryHeap<Node>,
+        reset: bool,
+    ) {
+        if let Some(next_coord) = self.grid.step(&node.coord, *direction) {
+            let mut next = *node;
+            next.direction = Some(*direction);
+            next.coord = next_coord;
+            next.heat_loss += usize::from(self.grid[next.coord]);
+            next.heuristic = next.heat_loss + next.coord.manhattan_distance(&self.goal);
+            if reset {
+                next.num_steps = 1;
+            } else {
+                next.num_steps += 1;
+            }
+            common::count!("heap_pushes");
+            queue.push(next);
+        }
+    }
+
+    fn least_heat_loss(&self) -> Option<usize> {
+        const DIRECTION_MAX: u8 = 3;
+
+        let mut seen: HashSet<u64> = HashSet::new();
+        let mut queue = BinaryHeap::from([Node::default()]);
+
+        // -- Only kept up to date while visualizing.
+        let tracing = visualize::enabled() || image::enabled();
+        let mut came_from = HashMap::new();
+        let mut explored = HashSet::new();
+
+        while let Some(node) = queue.pop() {
+            // -- Check if found goal.
+            if node.coord == self.goal {
+                if tracing {
+                    let mut path = vec![node.coord];
+                    let mut parent = node.parent;
+
+                    while let Some((coord, next)) = came_from.get(&parent) {
+                        path.push(*coord);
+                        parent = *next;
+                    }
+
+                    let canvas = self.canvas(&explored, &path);
+                    visualize::frame(|| canvas.render());
+                    image::record(|| canvas.to_drawing());
+                }
+
+                return Some(node.heat_loss);
+            }
+
+            // -- Skip already seen.
+            let node_hash = node.seen_hash();
+
+            if seen.contains(&node_hash) {
+                continue;
+            }
+
+            seen.insert(node_hash);
+
+            if tracing {
+                came_from.insert(node_hash, (node.coord, node.parent));
+                explored.insert(node.coord);
+
+                if seen.len() % self.grid.len() == 0 {
+                    visualize::frame(|| self.canvas(&explored, &[]).render());
+                }
+            }
+
+            // -- Children point back at this node.
+            let node = Node {
+                parent: node_hash,
+                ..node
+            };
+
+            // -- Generate children.
+            if let Some(node_direction) = node.direction {
+                if node.num_steps < DIRECTION_MAX {
+                    self.add_next_node(&node, &node_direction, &mut queue, false);
+                }
+            }
+
+            for direction in Direction::ALL {
+                if let Some(node_direction) = node.direction {
+                    if node_direction == direction || node_direction == -direction {
+                        continue;
+                    }
+                }
+
+                self.add_next_node(&node, &direction, &mut queue, true);
+            }
+        }
+
+        None
+    }
+
+    fn canvas(&self, explored: &HashSet<Coord2D>, path: &[Coord2D]) -> Canvas {
+        Canvas::from_grid(&self.grid, |heat_loss| char::from(b'0' + heat_loss))
+            .layer(Color::Gray, self.grid.coords())
+            .layer(Color::Blue, explored.iter().copied())
+            .layer(Color::Red, path.iter().copied())
+            .highlight(path.iter().copied())
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    let min = board
+        .least_heat_loss()
+        .ok_or_else(|| Error::new("search failed"))?;
+    Ok(min.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
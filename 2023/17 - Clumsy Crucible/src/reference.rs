@@ -0,0 +1,111 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+// -- Relaxes every (tile, direction, run length) state over and over until no heat loss
+// -- improves any more, instead of visiting them in order like Dijkstra.
+fn least_heat_loss(input: &str, min_run: usize, max_run: usize) -> Result<Answer, Error> {
+    let rows: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|ch| ch.to_digit(10))
+                .collect::<Option<_>>()
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(|| Error::new("malformed map"))?;
+
+    if rows.is_empty() || rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(Error::new("malformed map"));
+    }
+
+    let (nrows, ncols) = (rows.len(), rows[0].len());
+    let index = |row: usize, col: usize, dir: usize, run: usize| {
+        ((row * ncols + col) * 4 + dir) * (max_run + 1) + run
+    };
+    let mut loss = vec![u32::MAX; nrows * ncols * 4 * (max_run + 1)];
+
+    // -- Leaving the start counts as a run of nothing in either direction.
+    loss[index(0, 0, 1, 0)] = 0;
+    loss[index(0, 0, 2, 0)] = 0;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for row in 0..nrows {
+            for col in 0..ncols {
+                for dir in 0..4 {
+                    for run in 0..=max_run {
+                        let current = loss[index(row, col, dir, run)];
+                        if current == u32::MAX {
+                            continue;
+                        }
+
+                        for turn in [dir, (dir + 1) % 4, (dir + 3) % 4] {
+                            let straight = turn == dir;
+                            if (straight && run == max_run)
+                                || (!straight && run > 0 && run < min_run)
+                            {
+                                continue;
+                            }
+
+                            let (dr, dc) = STEPS[turn];
+                            let (Some(next_row), Some(next_col)) =
+                                (row.checked_add_signed(dr), col.checked_add_signed(dc))
+                            else {
+                                continue;
+                            };
+                            if next_row >= nrows || next_col >= ncols {
+                                continue;
+                            }
+
+                            let next_run = if straight { run + 1 } else { 1 };
+                            let next = index(next_row, next_col, turn, next_run);
+                            let total = current + rows[next_row][next_col];
+
+                            if total < loss[next] {
+                                loss[next] = total;
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (0..4)
+        .flat_map(|dir| (min_run.max(1)..=max_run).map(move |run| (dir, run)))
+        .map(|(dir, run)| loss[index(nrows - 1, ncols - 1, dir, run)])
+        .min()
+        .filter(|loss| *loss != u32::MAX)
+        .map(Answer::from)
+        .ok_or_else(|| Error::new("no path"))
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    least_heat_loss(input, 1, 3)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    least_heat_loss(input, 4, 10)
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,66 @@
+use common::random::Rng;
+
+// -- The outline of a histogram as (direction, length) moves: right along the base, then
+// -- back over the top of each column from the right, then down to the start. Neighboring
+// -- columns differ in height, so every move turns.
+fn histogram(rng: &mut Rng, columns: usize, max_width: i64, max_height: i64) -> Vec<(char, i64)> {
+    let widths: Vec<i64> = (0..columns).map(|_| rng.range(1..=max_width)).collect();
+    let mut heights: Vec<i64> = Vec::with_capacity(columns);
+
+    while heights.len() < columns {
+        let height = rng.range(1..=max_height);
+
+        if heights.last() != Some(&height) {
+            heights.push(height);
+        }
+    }
+
+    let mut moves = vec![('R', widths.iter().sum()), ('U', heights[columns - 1])];
+
+    for column in (0..columns).rev() {
+        moves.push(('L', widths[column]));
+
+        if column > 0 {
+            let step = heights[column - 1] - heights[column];
+            moves.push(if step > 0 { ('U', step) } else { ('D', -step) });
+        }
+    }
+
+    moves.push(('D', heights[0]));
+    moves
+}
+
+fn hex_direction(direction: char) -> u8 {
+    match direction {
+        'R' => 0,
+        'D' => 1,
+        'L' => 2,
+        'U' => 3,
+        _ => unreachable!("not a direction: '{}'", direction),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- Two lagoons with `size` columns each: a small one in the plain instructions and a
+// -- large one hidden in the colors.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let columns = size.max(1);
+    let small = histogram(rng, columns, 10, 20);
+    // -- The base is one move, so the columns together must fit in five hex digits.
+    let max_width = (0xfffff / columns as i64).clamp(1, 20_000);
+    let large = histogram(rng, columns, max_width, 500_000);
+
+    small
+        .iter()
+        .zip(large.iter())
+        .map(|((direction, length), (hex, hex_length))| {
+            format!(
+                "{} {} (#{:05x}{})\n",
+                direction,
+                length,
+                hex_length,
+                hex_direction(*hex)
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use common::image::{self, Drawing};
+use common::{visualize, Answer, Error, Line};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+use Direction::*;
+
+// -----------------------------------------------------------------------------
+fn direction(value: char) -> Result<Direction, Error> {
+    match value {
+        'U' => Ok(North),
+        'R' => Ok(East),
+        'D' => Ok(South),
+        'L' => Ok(West),
+        _ => Err(Error::new(format!(
+            "invalid character for direction: '{}'",
+            value
+        ))),
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct DigCmd {
+    direction: Direction,
+    distance: isize,
+}
+
+impl TryFrom<Line<'_>> for DigCmd {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let mut components = line.split_whitespace();
+
+        let direction_str = line.expect(components.next(), "direction")?;
+        let mut chars = direction_str.chars();
+        let direction = match (chars.next(), chars.next()) {
+            (Some(ch), None) => direction(ch).map_err(|err| line.locate(direction_str, err))?,
+            _ => return Err(line.error_in(direction_str, "expected a single direction character")),
+        };
+
+        let distance_str = line.expect(components.next(), "distance")?;
+        let distance: isize = line.parse(distance_str)?;
+
+        if distance < 0 {
+            return Err(line.error_in(distance_str, "distance must not be negative"));
+        }
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+fn process_dig_commands(dig_cmds: &[DigCmd]) -> (Vec<Coord2D>, isize) {
+    let mut total_len = 0;
+    let mut curr_coord = Coord2D::new(0, 0);
+    let mut points = vec![curr_coord];
+
+    for cmd in dig_cmds {
+        total_len += cmd.distance;
+        curr_coord = curr_coord.step_by(cmd.direction, cmd.distance);
+        points.push(curr_coord);
+    }
+
+    (points, total_len)
+}
+
+fn area(dig_cmds: &[DigCmd]) -> isize {
+    let (points, total_len) = process_dig_commands(dig_cmds);
+
+    if visualize::enabled() {
+        for dug in 2..=points.len() {
+            visualize::frame(|| render(&points[..dug], false));
+        }
+        visualize::frame(|| render(&points, true));
+    }
+    image::record(|| drawing(&points));
+
+    // -- Shoelace formula.
+    let mut sum = 0;
+
+    for i in 1..points.len() {
+        let a = points[i].col;
+        let b = points[(i + 1) % points.len()].row;
+        let c = points[i - 1].row;
+        sum += a * (b - c)
+    }
+
+    // -- Pick's theorem.
+    let a = sum.abs() / 2;
+    let b = total_len;
+    let i = a - (b / 2) + 1;
+    i + b
+}
+
+// -----------------------------------------------------------------------------
+// -- Canvas positions of the distinct values along one axis, each on its own line and
+// -- with a single line standing in for any gap between two of them, so that even huge
+// -- lagoons fit on screen.
+fn compress(mut values: Vec<isize>) -> HashMap<isize, usize> {
+    values.sort_unstable();
+    values.dedup();
+
+    let mut positions = HashMap::new();
+    let mut position = 0;
+
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 && value - values[i - 1] > 1 {
+            position += 1;
+        }
+        positions.insert(*value, position);
+        position += 1;
+    }
+
+    positions
+}
+
+// -- The trench dug along `points` so far, and the lagoon inside it once `filled`.
+fn render(points: &[Coord2D], filled: bool) -> String {
+    let cols = compress(points.iter().map(|point| point.col).collect());
+    let rows = compress(points.iter().map(|point| point.row).collect());
+    let at = |point: &Coord2D| (cols[&point.col] + 1, rows[&point.row] + 1);
+
+    // -- A border of one around the trench leaves room to flood the outside.
+    let ncols = cols.values().max().map_or(0, |max| max + 3);
+    let nrows = rows.values().max().map_or(0, |max| max + 3);
+    let mut trench = Grid::new(ncols, nrows, false);
+
+    for pair in points.windows(2) {
+        let (col_a, row_a) = at(&pair[0]);
+        let (col_b, row_b) = at(&pair[1]);
+
+        for col in col_a.min(col_b)..=col_a.max(col_b) {
+            for row in row_a.min(row_b)..=row_a.max(row_b) {
+                trench[Coord2D::from_indices(col, row)] = true;
+            }
+        }
+    }
+
+    let mut outside = Grid::new(ncols, nrows, !filled);
+    let mut queue = vec![Coord2D::new(0, 0)];
+
+    while let Some(coord) = queue.pop() {
+        if filled && !trench[coord] && !outside[coord] {
+            outside[coord] = true;
+            queue.extend(trench.neighbors4(&coord));
+        }
+    }
+
+    let lagoon = trench
+        .coords()
+        .filter(|coord| !trench[*coord] && !outside[*coord]);
+    let dug = trench.coords().filter(|coord| trench[*coord]);
+    let (col, row) = points.last().map_or((0, 0), at);
+
+    Canvas::from_fn(ncols, nrows, |coord| {
+        if trench[coord] || !outside[coord] {
+            '#'
+        } else {
+            '.'
+        }
+    })
+    .layer(Color::Gray, trench.coords())
+    .layer(Color::Yellow, lagoon)
+    .layer(Color::Red, dug)
+    .highlight([Coord2D::from_indices(col, row)])
+    .render()
+}
+
+// -- The trench's polygon in its true proportions, through the middle of its cubes.
+fn drawing(points: &[Coord2D]) -> Drawing {
+    let min_col = points.iter().map(|point| point.col).min().unwrap_or(0);
+    let min_row = points.iter().map(|point| point.row).min().unwrap_or(0);
+    let max_col = points.iter().map(|point| point.col).max().unwrap_or(0);
+    let max_row = points.iter().map(|point| point.row).max().unwrap_or(0);
+
+    let mut drawing = Drawing::new(
+        (max_col - min_col + 1) as f64,
+        (max_row - min_row + 1) as f64,
+        image::BACKGROUND,
+    );
+    drawing.polygon(
+        points
+            .iter()
+            .map(|point| {
+                (
+                    (point.col - min_col) as f64 + 0.5,
+                    (point.row - min_row) as f64 + 0.5,
+                )
+            })
+            .collect(),
+        Color::Yellow.rgb(),
+    );
+    drawing
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Vec<DigCmd>, Error> {
+    common::lines(input).map(DigCmd::try_from).collect()
+}
+
+pub fn answer(cmds: &[DigCmd]) -> Result<Answer, Error> {
+    Ok(area(cmds).into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
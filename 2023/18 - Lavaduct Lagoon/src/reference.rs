@@ -0,0 +1,141 @@
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+type Move = ((i64, i64), i64);
+
+fn step(direction: char) -> Option<(i64, i64)> {
+    match direction {
+        'R' | '0' => Some((1, 0)),
+        'D' | '1' => Some((0, 1)),
+        'L' | '2' => Some((-1, 0)),
+        'U' | '3' => Some((0, -1)),
+        _ => None,
+    }
+}
+
+fn moves(input: &str, from_color: bool) -> Result<Vec<Move>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let bad = || Error::new(format!("malformed instruction '{}'", line));
+            let mut tokens = line.split_whitespace();
+            let (direction, length, color) = (tokens.next(), tokens.next(), tokens.next());
+
+            let (direction, length) = if from_color {
+                let hex = color
+                    .and_then(|color| color.strip_prefix("(#")?.strip_suffix(')'))
+                    .filter(|hex| hex.len() == 6 && hex.is_ascii())
+                    .ok_or_else(bad)?;
+                let length = i64::from_str_radix(&hex[..5], 16).map_err(|_| bad())?;
+                (hex[5..].chars().next(), length)
+            } else {
+                let length = length
+                    .and_then(|length| length.parse().ok())
+                    .ok_or_else(bad)?;
+                (
+                    direction.and_then(|direction| direction.chars().next()),
+                    length,
+                )
+            };
+
+            Ok((direction.and_then(step).ok_or_else(bad)?, length))
+        })
+        .collect()
+}
+
+// -- Digs the trench on a compressed grid whose rows and columns are the stretches between
+// -- the corners, floods the outside and adds up the blocks that stay dry.
+fn lagoon(moves: &[Move]) -> Result<Answer, Error> {
+    let mut corners = vec![(0, 0)];
+    for ((dx, dy), length) in moves {
+        let (x, y) = corners[corners.len() - 1];
+        corners.push((x + dx * length, y + dy * length));
+    }
+
+    if corners.last() != Some(&(0, 0)) {
+        return Err(Error::new("the trench does not close"));
+    }
+
+    // -- Every corner bounds the stretches on both sides of its unit square, and the outer
+    // -- ring leaves room to flood around the trench.
+    let bounds = |coord: fn(&(i64, i64)) -> i64| {
+        let mut bounds: Vec<i64> = corners
+            .iter()
+            .flat_map(|corner| [coord(corner), coord(corner) + 1])
+            .collect();
+        let (min, max) = (
+            bounds.iter().min().unwrap() - 1,
+            bounds.iter().max().unwrap() + 1,
+        );
+        bounds.extend([min, max]);
+        bounds.sort();
+        bounds.dedup();
+        bounds
+    };
+    let (xs, ys) = (bounds(|corner| corner.0), bounds(|corner| corner.1));
+    let (ncols, nrows) = (xs.len() - 1, ys.len() - 1);
+
+    let mut trench = vec![vec![false; ncols]; nrows];
+    for pair in corners.windows(2) {
+        let (x1, x2) = (pair[0].0.min(pair[1].0), pair[0].0.max(pair[1].0) + 1);
+        let (y1, y2) = (pair[0].1.min(pair[1].1), pair[0].1.max(pair[1].1) + 1);
+
+        for row in (0..nrows).filter(|row| ys[*row] >= y1 && ys[row + 1] <= y2) {
+            for col in (0..ncols).filter(|col| xs[*col] >= x1 && xs[col + 1] <= x2) {
+                trench[row][col] = true;
+            }
+        }
+    }
+
+    let mut outside = vec![vec![false; ncols]; nrows];
+    let mut stack = vec![(0_usize, 0_usize)];
+    outside[0][0] = true;
+
+    while let Some((row, col)) = stack.pop() {
+        let neighbors = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+
+        for (row, col) in neighbors {
+            if row < nrows && col < ncols && !trench[row][col] && !outside[row][col] {
+                outside[row][col] = true;
+                stack.push((row, col));
+            }
+        }
+    }
+
+    let area: i64 = (0..nrows)
+        .flat_map(|row| (0..ncols).map(move |col| (row, col)))
+        .filter(|(row, col)| !outside[*row][*col])
+        .map(|(row, col)| (xs[col + 1] - xs[col]) * (ys[row + 1] - ys[row]))
+        .sum();
+    Ok(area.into())
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    lagoon(&moves(input, false)?)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    lagoon(&moves(input, true)?)
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
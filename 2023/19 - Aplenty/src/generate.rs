@@ -0,0 +1,70 @@
+use common::random::Rng;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+// -- Workflow names: "in" first, then distinct words that cannot clash with it or with
+// -- the 'A' and 'R' destinations.
+fn names(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut names = vec![String::from("in")];
+
+    while names.len() < count {
+        let len = rng.range_usize(2..=4);
+        let name = rng.word(len);
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+// -----------------------------------------------------------------------------
+// -- `size` workflows and `size` parts. Rules only send parts to later workflows, so every
+// -- part is eventually accepted or rejected.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let names = names(rng, count);
+    let mut input = String::new();
+
+    for (index, name) in names.iter().enumerate() {
+        let destination = |rng: &mut Rng| {
+            if index + 1 < count && rng.chance(0.6) {
+                names[rng.range_usize(index + 1..=count - 1)].clone()
+            } else {
+                String::from(*rng.choose(&["A", "R"]))
+            }
+        };
+
+        let rules: Vec<String> = (0..rng.range_usize(1..=3))
+            .map(|_| {
+                format!(
+                    "{}{}{}:{}",
+                    rng.choose(&CATEGORIES),
+                    rng.choose(&['<', '>']),
+                    rng.range(1..=4000),
+                    destination(rng)
+                )
+            })
+            .collect();
+
+        input.push_str(&format!(
+            "{}{{{},{}}}\n",
+            name,
+            rules.join(","),
+            destination(rng)
+        ));
+    }
+
+    input.push('\n');
+
+    for _ in 0..count {
+        let ratings: Vec<String> = CATEGORIES
+            .iter()
+            .map(|category| format!("{}={}", category, rng.range(1..=4000)))
+            .collect();
+        input.push_str(&format!("{{{}}}\n", ratings.join(",")));
+    }
+
+    input
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use common::{Answer, Error, Line};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+use Category::*;
+
+impl TryFrom<char> for Category {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(X),
+            'm' => Ok(M),
+            'a' => Ok(A),
+            's' => Ok(S),
+            _ => Err(Error::new(format!(
+                "invalid char for category: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+enum CmpOp {
+    GreaterThan,
+    LessThan,
+}
+
+use CmpOp::*;
+
+impl TryFrom<char> for CmpOp {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(LessThan),
+            '>' => Ok(GreaterThan),
+            _ => Err(Error::new(format!(
+                "invalid char for comparison operator: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+// -- Parse the leading character of `token` with `convert`, returning it and the remainder.
+fn split_first<'a, T>(
+    line: &Line,
+    token: &'a str,
+    what: &str,
+    convert: impl Fn(char) -> Result<T, Error>,
+) -> Result<(T, &'a str), Error> {
+    let ch = token
+        .chars()
+        .next()
+        .ok_or_else(|| line.error_in(token, format!("expected {}", what)))?;
+    let (first, rest) = token.split_at(ch.len_utf8());
+    let value = convert(ch).map_err(|err| line.locate(first, err))?;
+    Ok((value, rest))
+}
+
+// -----------------------------------------------------------------------------
+struct Rule {
+    category: Category,
+    cmp_op: CmpOp,
+    value: usize,
+    dst: String,
+}
+
+impl Rule {
+    fn parse(line: &Line, value: &str) -> Result<Self, Error> {
+        let (condition, dst) = value
+            .split_once(':')
+            .ok_or_else(|| line.error_in(value, "expected 'CONDITION:DESTINATION'"))?;
+        let (category, rest) = split_first(line, condition, "category", Category::try_from)?;
+        let (cmp_op, rest) = split_first(line, rest, "comparison operator", CmpOp::try_from)?;
+        let value = line.parse(rest)?;
+        let dst = dst.into();
+        Ok(Self {
+            category,
+            cmp_op,
+            value,
+            dst,
+        })
+    }
+}
+
+impl Rule {
+    fn process(&self, part: &Part) -> Option<String> {
+        let part_value = part.get(&self.category);
+        if match self.cmp_op {
+            GreaterThan => part_value > self.value,
+            LessThan => part_value < self.value,
+        } {
+            return Some(self.dst.clone());
+        }
+
+        None
+    }
+}
+
+// -----------------------------------------------------------------------------
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    dst: String,
+}
+
+impl TryFrom<Line<'_>> for Workflow {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let (name, rules_str) = line
+            .as_str()
+            .split_once('{')
+            .ok_or_else(|| line.error("expected 'NAME{RULES}'"))?;
+        let rules_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| line.error_at_end("expected '}'"))?;
+        let mut rule_strs: Vec<_> = rules_str.split(',').collect();
+        let dst = String::from(rule_strs.pop().unwrap_or_default());
+
+        let mut rules = Vec::new();
+
+        for rule_str in rule_strs.into_iter() {
+            rules.push(Rule::parse(&line, rule_str)?);
+        }
+
+        let name = name.into();
+        Ok(Self { name, rules, dst })
+    }
+}
+
+impl Workflow {
+    fn process(&self, part: &Part) -> String {
+        for rule in self.rules.iter() {
+            if let Some(dst) = rule.process(part) {
+                return dst;
+            }
+        }
+
+        self.dst.clone()
+    }
+}
+
+// -----------------------------------------------------------------------------
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl TryFrom<Line<'_>> for Part {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let fields = line
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| line.error("expected '{x=..,m=..,a=..,s=..}'"))?;
+
+        let mut ratings = HashMap::new();
+
+        for field in fields.split(',') {
+            let (category, rest) = split_first(&line, field, "category", Category::try_from)?;
+            let value = rest
+                .strip_prefix('=')
+                .ok_or_else(|| line.error_in(field, "expected 'CATEGORY=VALUE'"))?;
+            ratings.insert(category, line.parse(value)?);
+        }
+
+        let mut rating = |category, name| {
+            ratings
+                .remove(&category)
+                .ok_or_else(|| line.error(format!("missing '{}' rating", name)))
+        };
+
+        let x = rating(X, 'x')?;
+        let m = rating(M, 'm')?;
+        let a = rating(A, 'a')?;
+        let s = rating(S, 's')?;
+        Ok(Self { x, m, a, s })
+    }
+}
+
+impl Part {
+    fn value(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+impl Part {
+    fn get(&self, category: &Category) -> usize {
+        match category {
+            X => self.x,
+            M => self.m,
+            A => self.a,
+            S => self.s,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl TryFrom<&str> for System {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
+
+        // -- Parse workflows.
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut workflow_lines = Vec::new();
+
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            let workflow = Workflow::try_from(line)?;
+            let name = workflow.name.clone();
+            workflows.insert(name, workflow);
+            workflow_lines.push(line);
+        }
+
+        // -- Make sure every destination exists.
+        if !workflows.contains_key("in") {
+            return Err(Error::new("no 'in' workflow"));
+        }
+
+        for line in workflow_lines {
+            let (_, rules_str) = line.as_str().split_once('{').unwrap_or_default();
+            let rules_str = rules_str.strip_suffix('}').unwrap_or_default();
+            let dsts = rules_str
+                .split(',')
+                .map(|rule| rule.rsplit(':').next().unwrap_or(rule));
+
+            for dst in dsts {
+                if dst != "A" && dst != "R" && !workflows.contains_key(dst) {
+                    return Err(line.error_in(dst, format!("unknown workflow '{}'", dst)));
+                }
+            }
+        }
+
+        // -- Parse parts.
+        let mut parts = Vec::new();
+
+        for line in lines.by_ref() {
+            parts.push(Part::try_from(line)?);
+        }
+
+        Ok(Self { workflows, parts })
+    }
+}
+
+impl System {
+    fn process(&self) -> usize {
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+
+        for part in self.parts.iter() {
+            let mut curr_workflow_name = String::from("in");
+
+            while let Some(workflow) = self.workflows.get(&curr_workflow_name) {
+                let dst = workflow.process(part);
+
+                if dst == "A" {
+                    accepted.push(part);
+                } else if dst == "R" {
+                    rejected.push(part);
+                }
+
+                curr_workflow_name = dst;
+            }
+        }
+
+        accepted.iter().map(|part| part.value()).sum()
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<System, Error> {
+    System::try_from(input)
+}
+
+pub fn answer(system: &System) -> Result<Answer, Error> {
+    Ok(system.process().into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use common::{Answer, Error, Line};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone)]
+struct InclusiveRange {
+    begin: usize,
+    end: usize,
+}
+
+impl InclusiveRange {
+    fn new(begin: usize, end: usize) -> Self {
+        Self { begin, end }
+    }
+
+    fn len(&self) -> usize {
+        self.end - self.begin + 1
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+use Category::*;
+
+const CATEGORIES: [Category; 4] = [X, M, A, S];
+
+impl TryFrom<char> for Category {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(X),
+            'm' => Ok(M),
+            'a' => Ok(A),
+            's' => Ok(S),
+            _ => Err(Error::new(format!(
+                "invalid char for category: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+enum CmpOp {
+    GreaterThan,
+    LessThan,
+}
+
+use CmpOp::*;
+
+impl TryFrom<char> for CmpOp {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(LessThan),
+            '>' => Ok(GreaterThan),
+            _ => Err(Error::new(format!(
+                "invalid char for comparison operator: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+// -- Parse the leading character of `token` with `convert`, returning it and the remainder.
+fn split_first<'a, T>(
+    line: &Line,
+    token: &'a str,
+    what: &str,
+    convert: impl Fn(char) -> Result<T, Error>,
+) -> Result<(T, &'a str), Error> {
+    let ch = token
+        .chars()
+        .next()
+        .ok_or_else(|| line.error_in(token, format!("expected {}", what)))?;
+    let (first, rest) = token.split_at(ch.len_utf8());
+    let value = convert(ch).map_err(|err| line.locate(first, err))?;
+    Ok((value, rest))
+}
+
+// -----------------------------------------------------------------------------
+struct Rule {
+    category: Category,
+    cmp_op: CmpOp,
+    value: usize,
+    dst: String,
+}
+
+impl Rule {
+    fn parse(line: &Line, value: &str) -> Result<Self, Error> {
+        let (condition, dst) = value
+            .split_once(':')
+            .ok_or_else(|| line.error_in(value, "expected 'CONDITION:DESTINATION'"))?;
+        let (category, rest) = split_first(line, condition, "category", Category::try_from)?;
+        let (cmp_op, rest) = split_first(line, rest, "comparison operator", CmpOp::try_from)?;
+        let value = line.parse(rest)?;
+        let dst = dst.into();
+        Ok(Self {
+            category,
+            cmp_op,
+            value,
+            dst,
+        })
+    }
+}
+
+impl Rule {
+    // -- Splits into the values that match the rule and those that do not; either may be
+    // -- empty, but neither reaches outside `range`.
+    fn split_range(&self, range: &InclusiveRange) -> (InclusiveRange, InclusiveRange) {
+        match self.cmp_op {
+            GreaterThan => (
+                InclusiveRange::new(range.begin.max(self.value + 1), range.end),
+                InclusiveRange::new(range.begin, range.end.min(self.value)),
+            ),
+            LessThan => (
+                InclusiveRange::new(range.begin, range.end.min(self.value.saturating_sub(1))),
+                InclusiveRange::new(range.begin.max(self.value), range.end),
+            ),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    dst: String,
+}
+
+impl TryFrom<Line<'_>> for Workflow {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let (name, rules_str) = line
+            .as_str()
+            .split_once('{')
+            .ok_or_else(|| line.error("expected 'NAME{RULES}'"))?;
+        let rules_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| line.error_at_end("expected '}'"))?;
+        let mut rule_strs: Vec<_> = rules_str.split(',').collect();
+        let dst = String::from(rule_strs.pop().unwrap_or_default());
+
+        let mut rules = Vec::new();
+
+        for rule_str in rule_strs.into_iter() {
+            rules.push(Rule::parse(&line, rule_str)?);
+        }
+
+        let name = name.into();
+        Ok(Self { name, rules, dst })
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl TryFrom<&str> for System {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = common::lines(input);
+
+        // -- Parse workflows.
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut workflow_lines = Vec::new();
+
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            let workflow = Workflow::try_from(line)?;
+            let name = workflow.name.clone();
+            workflows.insert(name, workflow);
+            workflow_lines.push(line);
+        }
+
+        // -- Make sure every destination exists.
+        if !workflows.contains_key("in") {
+            return Err(Error::new("no 'in' workflow"));
+        }
+
+        for line in workflow_lines {
+            let (_, rules_str) = line.as_str().split_once('{').unwrap_or_default();
+            let rules_str = rules_str.strip_suffix('}').unwrap_or_default();
+            let dsts = rules_str
+                .split(',')
+                .map(|rule| rule.rsplit(':').next().unwrap_or(rule));
+
+            for dst in dsts {
+                if dst != "A" && dst != "R" && !workflows.contains_key(dst) {
+                    return Err(line.error_in(dst, format!("unknown workflow '{}'", dst)));
+                }
+            }
+        }
+
+        Ok(Self { workflows })
+    }
+}
+
+impl System {
+    fn combinations(&self, mut ranges: HashMap<Category, InclusiveRange>, dst: String) -> usize {
+        // -- Result settled, calculate possibilities.
+        if dst == "R" {
+            return 0;
+        } else if dst == "A" {
+            return ranges.values().map(InclusiveRange::len).product();
+        }
+
+        // -- Continue calculating combinations.
+        let mut total = 0;
+        let mut do_fallback = true;
+
+        let workflow = self.workflows.get(&dst).unwrap();
+
+        for rule in workflow.rules.iter() {
+            let range = ranges.get(&rule.category).unwrap();
+            let (true_range, false_range) = rule.split_range(range);
+
+            if true_range.begin <= true_range.end {
+                let mut new_ranges = ranges.clone();
+                new_ranges.insert(rule.category, true_range);
+                total += self.combinations(new_ranges, rule.dst.clone());
+            }
+
+            if false_range.begin <= false_range.end {
+                ranges.insert(rule.category, false_range);
+            } else {
+                do_fallback = false;
+                break;
+            }
+        }
+
+        if do_fallback {
+            total += self.combinations(ranges, workflow.dst.clone());
+        }
+
+        total
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<System, Error> {
+    System::try_from(input)
+}
+
+pub fn answer(system: &System) -> Result<Answer, Error> {
+    let ranges: HashMap<Category, InclusiveRange> = CATEGORIES
+        .iter()
+        .map(|category| (*category, InclusiveRange::new(1, 4000)))
+        .collect();
+    let combinations = system.combinations(ranges, String::from("in"));
+    Ok(combinations.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
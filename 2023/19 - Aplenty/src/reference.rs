@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+const CATEGORIES: &str = "xmas";
+const MAX_RATING: u64 = 4000;
+
+// -- (category, '<' or '>', value, destination), with the fallback as a rule that always
+// -- matches.
+type Rule<'a> = (usize, char, u64, &'a str);
+type Workflows<'a> = HashMap<&'a str, Vec<Rule<'a>>>;
+
+fn system(input: &str) -> Result<(Workflows<'_>, Vec<[u64; 4]>), Error> {
+    let (workflows, parts) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::new("missing blank line"))?;
+    let bad = |text: &str| Error::new(format!("malformed '{}'", text));
+
+    let mut map = HashMap::new();
+    for line in workflows.lines() {
+        let (name, rules) = line.split_once('{').ok_or_else(|| bad(line))?;
+        let rules = rules.strip_suffix('}').ok_or_else(|| bad(line))?;
+
+        let rules = rules
+            .split(',')
+            .map(|rule| match rule.split_once(':') {
+                None => Ok((0, '>', 0, rule)),
+                Some((condition, destination)) => {
+                    let category = CATEGORIES
+                        .find(condition.get(..1).unwrap_or_default())
+                        .ok_or_else(|| bad(rule))?;
+                    let op = condition.chars().nth(1).filter(|op| "<>".contains(*op));
+                    let value = condition.get(2..).and_then(|value| value.parse().ok());
+                    Ok((
+                        category,
+                        op.ok_or_else(|| bad(rule))?,
+                        value.ok_or_else(|| bad(rule))?,
+                        destination,
+                    ))
+                }
+            })
+            .collect::<Result<_, Error>>()?;
+        map.insert(name, rules);
+    }
+
+    let parts = parts
+        .lines()
+        .map(|line| {
+            let ratings: Vec<u64> = line
+                .trim_matches(['{', '}'])
+                .split(',')
+                .map(|rating| rating.get(2..)?.parse().ok())
+                .collect::<Option<_>>()
+                .ok_or_else(|| bad(line))?;
+            ratings.try_into().map_err(|_| bad(line))
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok((map, parts))
+}
+
+fn accepted(workflows: &Workflows, part: &[u64; 4]) -> Result<bool, Error> {
+    let mut name = "in";
+
+    // -- A cycle would revisit a workflow, so more steps than workflows means one.
+    for _ in 0..=workflows.len() {
+        match name {
+            "A" => return Ok(true),
+            "R" => return Ok(false),
+            _ => (),
+        }
+
+        let rules = workflows
+            .get(name)
+            .ok_or_else(|| Error::new(format!("unknown workflow '{}'", name)))?;
+        name = rules
+            .iter()
+            .find(|(category, op, value, _)| match op {
+                '<' => part[*category] < *value,
+                _ => part[*category] > *value,
+            })
+            .map(|(_, _, _, destination)| *destination)
+            .ok_or_else(|| Error::new("no rule matched"))?;
+    }
+
+    Err(Error::new("workflows loop"))
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let (workflows, parts) = system(input)?;
+    let mut sum = 0;
+
+    for part in parts {
+        if accepted(&workflows, &part)? {
+            sum += part.iter().sum::<u64>();
+        }
+    }
+
+    Ok(sum.into())
+}
+
+// -- Only the values in the rules split the ratings into stretches that all take the same
+// -- path, so trying one rating from every combination of stretches covers all of them.
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let (workflows, _) = system(input)?;
+
+    let stretches: Vec<Vec<(u64, u64)>> = (0..4)
+        .map(|index| {
+            // -- Each stretch starts at one of these.
+            let mut starts = vec![1, MAX_RATING + 1];
+            for (category, op, value, _) in workflows.values().flatten() {
+                if *category == index && (1..=MAX_RATING).contains(value) {
+                    starts.push(if *op == '<' { *value } else { value + 1 });
+                }
+            }
+            starts.retain(|start| *start <= MAX_RATING + 1);
+            starts.sort();
+            starts.dedup();
+            starts
+                .windows(2)
+                .map(|pair| (pair[0], pair[1] - pair[0]))
+                .collect()
+        })
+        .collect();
+
+    let mut count = 0;
+    for (x, x_len) in &stretches[0] {
+        for (m, m_len) in &stretches[1] {
+            for (a, a_len) in &stretches[2] {
+                for (s, s_len) in &stretches[3] {
+                    if accepted(&workflows, &[*x, *m, *a, *s])? {
+                        count += x_len * m_len * a_len * s_len;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: generate::generate,
+    },
+];
@@ -0,0 +1,73 @@
+use common::random::Rng;
+
+fn names(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(count);
+
+    while names.len() < count {
+        let len = rng.range_usize(2..=3);
+        let name = rng.word(len);
+
+        if name != "rx" && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+// -----------------------------------------------------------------------------
+// -- The shape of the puzzle input: the broadcaster drives `size` 12-bit counters made of
+// -- flip-flops. Each counter's hub conjunction listens to the bits set in its period and
+// -- resets the others, so the hub fires once every period presses. The hubs report through
+// -- an inverter each to the conjunction in front of rx. The answer is the product of the
+// -- periods and has to fit in a u64, so there are at most five counters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    network(rng, size.clamp(1, 5), 12)
+}
+
+// -- `counters` counters of `bits` bits each.
+pub fn network(rng: &mut Rng, counters: usize, bits: usize) -> String {
+    let mut names = names(rng, counters * (bits + 2) + 1);
+    let last = names.pop().unwrap();
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+
+    for counter in names.chunks(bits + 2) {
+        let (flip_flops, rest) = counter.split_at(bits);
+        let (hub, inverter) = (&rest[0], &rest[1]);
+
+        // -- Lowest and highest bits set, so the period is odd and at least 2^(bits - 1).
+        let period = rng.range_u64(1 << (bits - 1)..=(1 << bits) - 1) | 1;
+        let mut resets = Vec::new();
+
+        for (bit, name) in flip_flops.iter().enumerate() {
+            let mut destinations = Vec::new();
+
+            if let Some(next) = flip_flops.get(bit + 1) {
+                destinations.push(next.as_str());
+            }
+
+            if period & (1 << bit) != 0 {
+                destinations.push(hub);
+            } else {
+                resets.push(name.as_str());
+            }
+
+            lines.push(format!("%{} -> {}", name, destinations.join(", ")));
+        }
+
+        resets.push(&flip_flops[0]);
+        resets.push(inverter);
+        lines.push(format!("&{} -> {}", hub, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+
+        starts.push(flip_flops[0].as_str());
+    }
+
+    lines.push(format!("&{} -> rx", last));
+    rng.shuffle(&mut lines);
+    lines.insert(0, format!("broadcaster -> {}", starts.join(", ")));
+
+    lines.join("\n") + "\n"
+}
@@ -1,11 +1,12 @@
 use std::collections::{HashMap, VecDeque};
-use std::io::{stdin, BufRead, Stdin};
 use std::ops::Neg;
 use std::rc::Rc;
 
+use common::{Answer, Error, Line};
+
 // -----------------------------------------------------------------------------
 #[derive(Clone, Copy, PartialEq)]
-enum Pulse {
+pub(crate) enum Pulse {
     Low,
     High,
 }
@@ -24,10 +25,10 @@ impl Neg for Pulse {
 }
 
 // -----------------------------------------------------------------------------
-struct Node {
-    src: Rc<String>,
-    pulse: Pulse,
-    dst: Rc<String>,
+pub(crate) struct Node {
+    pub(crate) src: Rc<String>,
+    pub(crate) pulse: Pulse,
+    pub(crate) dst: Rc<String>,
 }
 
 impl Node {
@@ -37,7 +38,11 @@ impl Node {
         Self { src, pulse, dst }
     }
 
-    fn send_pulse(&self, modules: &mut HashMap<Rc<String>, Module>, queue: &mut VecDeque<Node>) {
+    pub(crate) fn send_pulse(
+        &self,
+        modules: &mut HashMap<Rc<String>, Module>,
+        queue: &mut VecDeque<Node>,
+    ) {
         if let Some(module) = modules.get_mut(&self.dst) {
             for next_node in module.propagate_pulse(self.pulse, &self.src) {
                 queue.push_back(next_node);
@@ -48,7 +53,7 @@ impl Node {
 
 // -----------------------------------------------------------------------------
 #[derive(Clone, PartialEq)]
-enum ModuleType {
+pub(crate) enum ModuleType {
     Broadcast,
     FlipFlop,
     Conjunction,
@@ -63,55 +68,67 @@ const MODULE_FLIPFLOP_SIGIL: char = '%';
 const MODULE_CONJUNCTION_SIGIL: char = '&';
 
 #[derive(Clone)]
-struct Module {
-    name: Rc<String>,
-    dsts: Vec<Rc<String>>,
-    mtype: ModuleType,
-    switch: bool,
-    mem: HashMap<Rc<String>, Pulse>,
+pub(crate) struct Module {
+    pub(crate) name: Rc<String>,
+    pub(crate) dsts: Vec<Rc<String>>,
+    pub(crate) mtype: ModuleType,
+    pub(crate) switch: bool,
+    pub(crate) mem: HashMap<Rc<String>, Pulse>,
 }
 
-impl From<String> for Module {
-    fn from(line: String) -> Self {
-        let mut components = line.split_whitespace();
-        let mut name = String::from(components.next().unwrap());
+impl TryFrom<Line<'_>> for Module {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        // -- Split at arrow.
+        let (name_str, dsts_str) = line
+            .split_once("->")
+            .ok_or_else(|| line.error("expected 'NAME -> DESTINATIONS'"))?;
+        let name_str = name_str.trim();
 
         // -- Determine switch type.
-        let mtype = if name == MODULE_BROADCAST_NAME {
-            Broadcast
-        } else if name.starts_with(MODULE_FLIPFLOP_SIGIL) {
-            name = String::from(&name[1..]);
-            FlipFlop
-        } else if name.starts_with(MODULE_CONJUNCTION_SIGIL) {
-            name = String::from(&name[1..]);
-            Conjunction
+        let (mtype, name) = if name_str == MODULE_BROADCAST_NAME {
+            (Broadcast, name_str)
+        } else if let Some(name) = name_str.strip_prefix(MODULE_FLIPFLOP_SIGIL) {
+            (FlipFlop, name)
+        } else if let Some(name) = name_str.strip_prefix(MODULE_CONJUNCTION_SIGIL) {
+            (Conjunction, name)
         } else {
-            panic!("unable to determine module type for '{}'", name)
+            return Err(line.error_in(
+                name_str,
+                format!("unable to determine module type for '{}'", name_str),
+            ));
         };
 
-        // -- Skip arrow.
-        assert_eq!(components.next().unwrap().trim(), "->");
+        if name.is_empty() {
+            return Err(line.error_in(name_str, "expected module name"));
+        }
 
         // -- Parse destinations.
         let mut dsts = Vec::new();
 
-        for dst in components {
-            let dst = String::from(dst.replace(',', "").trim());
-            dsts.push(dst.into());
+        for dst in dsts_str.split(',') {
+            let dst = dst.trim();
+
+            if dst.is_empty() {
+                return Err(line.error_in(dsts_str, "expected destination module name"));
+            }
+
+            dsts.push(String::from(dst).into());
         }
 
         // -- Construct module.
-        let name = name.into();
+        let name = String::from(name).into();
         let switch = false;
         let mem = HashMap::new();
 
-        Self {
+        Ok(Self {
             name,
             dsts,
             mtype,
             switch,
             mem,
-        }
+        })
     }
 }
 
@@ -160,24 +177,28 @@ impl Module {
 
 // -----------------------------------------------------------------------------
 #[derive(Clone)]
-struct System {
+pub struct System {
     modules: Vec<Module>,
 }
 
-impl From<&Stdin> for System {
-    fn from(stdin: &Stdin) -> Self {
-        let modules: Vec<_> = stdin
-            .lock()
-            .lines()
-            .map(|line| Module::from(line.unwrap()))
-            .collect();
+impl TryFrom<&str> for System {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let modules = common::lines(input)
+            .map(Module::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
 
         // -- Initalize memory for conjunction modules.
         let mut system = Self { modules };
 
         let mut conj_module_name_to_inputs = HashMap::new();
 
-        for conj_module in system.modules.iter().filter(|module| module.mtype == Conjunction) {
+        for conj_module in system
+            .modules
+            .iter()
+            .filter(|module| module.mtype == Conjunction)
+        {
             conj_module_name_to_inputs.insert(
                 conj_module.name.clone(),
                 system.find_module_inputs(&conj_module.name),
@@ -192,7 +213,7 @@ impl From<&Stdin> for System {
             }
         }
 
-        system
+        Ok(system)
     }
 }
 
@@ -209,14 +230,22 @@ impl System {
         inputs
     }
 
-    fn init_module_map(&self) -> HashMap<Rc<String>, Module> {
+    // -- Module names in the order they appear in the input.
+    pub(crate) fn module_names(&self) -> Vec<Rc<String>> {
+        self.modules
+            .iter()
+            .map(|module| module.name.clone())
+            .collect()
+    }
+
+    pub(crate) fn init_module_map(&self) -> HashMap<Rc<String>, Module> {
         self.modules
             .iter()
             .map(|module| (module.name.clone(), (*module).clone()))
             .collect()
     }
 
-    fn init_queue() -> VecDeque<Node> {
+    pub(crate) fn init_queue() -> VecDeque<Node> {
         let start = Node::new(
             &String::from(MODULE_BUTTON_NAME).into(),
             Low,
@@ -251,8 +280,14 @@ impl System {
 }
 
 // -----------------------------------------------------------------------------
-fn main() {
-    let system = System::from(&stdin());
-    let solution = system.press_button(1000);
-    println!("{}", solution);
+pub fn parse(input: &str) -> Result<System, Error> {
+    System::try_from(input)
+}
+
+pub fn answer(system: &System) -> Result<Answer, Error> {
+    Ok(system.press_button(1000).into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
@@ -1,37 +1,10 @@
 use std::collections::{HashMap, VecDeque};
-use std::io::{stdin, BufRead, Stdin};
 use std::ops::Neg;
 use std::rc::Rc;
 
-// -----------------------------------------------------------------------------
-fn gcd(a: usize, b: usize) -> usize {
-    let mut m = a;
-    let mut n = b;
-
-    if m == 0 || n == 0 {
-        return m | n;
-    }
-
-    let shift = (m | n).trailing_zeros();
-    m >>= m.trailing_zeros();
-    n >>= n.trailing_zeros();
-
-    while m != n {
-        if m > n {
-            m -= n;
-            m >>= m.trailing_zeros();
-        } else {
-            n -= m;
-            n >>= n.trailing_zeros();
-        }
-    }
-
-    m << shift
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    (a * b) / gcd(a, b)
-}
+use common::json::Value;
+use common::math::{self, Congruence};
+use common::{diagnostics, Answer, Error, Line};
 
 // -----------------------------------------------------------------------------
 #[derive(Clone, Copy, PartialEq)]
@@ -101,47 +74,59 @@ struct Module {
     mem: HashMap<Rc<String>, Pulse>,
 }
 
-impl From<String> for Module {
-    fn from(line: String) -> Self {
-        let mut components = line.split_whitespace();
-        let mut name = String::from(components.next().unwrap());
+impl TryFrom<Line<'_>> for Module {
+    type Error = Error;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        // -- Split at arrow.
+        let (name_str, dsts_str) = line
+            .split_once("->")
+            .ok_or_else(|| line.error("expected 'NAME -> DESTINATIONS'"))?;
+        let name_str = name_str.trim();
 
         // -- Determine switch type.
-        let mtype = if name == MODULE_BROADCAST_NAME {
-            Broadcast
-        } else if name.starts_with(MODULE_FLIPFLOP_SIGIL) {
-            name = String::from(&name[1..]);
-            FlipFlop
-        } else if name.starts_with(MODULE_CONJUNCTION_SIGIL) {
-            name = String::from(&name[1..]);
-            Conjunction
+        let (mtype, name) = if name_str == MODULE_BROADCAST_NAME {
+            (Broadcast, name_str)
+        } else if let Some(name) = name_str.strip_prefix(MODULE_FLIPFLOP_SIGIL) {
+            (FlipFlop, name)
+        } else if let Some(name) = name_str.strip_prefix(MODULE_CONJUNCTION_SIGIL) {
+            (Conjunction, name)
         } else {
-            panic!("unable to determine module type for '{}'", name)
+            return Err(line.error_in(
+                name_str,
+                format!("unable to determine module type for '{}'", name_str),
+            ));
         };
 
-        // -- Skip arrow.
-        assert_eq!(components.next().unwrap().trim(), "->");
+        if name.is_empty() {
+            return Err(line.error_in(name_str, "expected module name"));
+        }
 
         // -- Parse destinations.
         let mut dsts = Vec::new();
 
-        for dst in components {
-            let dst = String::from(dst.replace(',', "").trim());
-            dsts.push(dst.into());
+        for dst in dsts_str.split(',') {
+            let dst = dst.trim();
+
+            if dst.is_empty() {
+                return Err(line.error_in(dsts_str, "expected destination module name"));
+            }
+
+            dsts.push(String::from(dst).into());
         }
 
         // -- Construct module.
-        let name = name.into();
+        let name = String::from(name).into();
         let switch = false;
         let mem = HashMap::new();
 
-        Self {
+        Ok(Self {
             name,
             dsts,
             mtype,
             switch,
             mem,
-        }
+        })
     }
 }
 
@@ -190,24 +175,28 @@ impl Module {
 
 // -----------------------------------------------------------------------------
 #[derive(Clone)]
-struct System {
+pub struct System {
     modules: Vec<Module>,
 }
 
-impl From<&Stdin> for System {
-    fn from(stdin: &Stdin) -> Self {
-        let modules: Vec<_> = stdin
-            .lock()
-            .lines()
-            .map(|line| Module::from(line.unwrap()))
-            .collect();
+impl TryFrom<&str> for System {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let modules = common::lines(input)
+            .map(Module::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
 
         // -- Initalize memory for conjunction modules.
         let mut system = Self { modules };
 
         let mut conj_module_name_to_inputs = HashMap::new();
 
-        for conj_module in system.modules.iter().filter(|module| module.mtype == Conjunction) {
+        for conj_module in system
+            .modules
+            .iter()
+            .filter(|module| module.mtype == Conjunction)
+        {
             conj_module_name_to_inputs.insert(
                 conj_module.name.clone(),
                 system.find_module_inputs(&conj_module.name),
@@ -222,7 +211,7 @@ impl From<&Stdin> for System {
             }
         }
 
-        system
+        Ok(system)
     }
 }
 
@@ -255,12 +244,16 @@ impl System {
         [start].into()
     }
 
+    // -- Each input is taken to first send a high pulse after `first` presses and then
+    // -- again every `period` presses.
     fn find_cycles(
         &self,
         target: &Rc<String>,
-        inputs: &Vec<Rc<String>>,
-    ) -> HashMap<Rc<String>, usize> {
+        inputs: &[Rc<String>],
+    ) -> Result<HashMap<Rc<String>, (usize, usize)>, Error> {
+        let _timer = common::timer!("find_cycles");
         let mut modules = self.init_module_map();
+        let mut first_tracker = HashMap::new();
         let mut cycle_tracker = HashMap::new();
 
         for num_button_presses in 1..usize::MAX {
@@ -273,47 +266,92 @@ impl System {
                     && inputs.contains(&curr_node.src)
                     && !cycle_tracker.contains_key(&curr_node.src)
                 {
-                    cycle_tracker.insert(curr_node.src.clone(), num_button_presses);
-
-                    if cycle_tracker.len() == inputs.len() {
-                        return cycle_tracker;
+                    match first_tracker.get(&curr_node.src) {
+                        None => {
+                            first_tracker.insert(curr_node.src.clone(), num_button_presses);
+                        }
+                        Some(&first) if first < num_button_presses => {
+                            let period = num_button_presses - first;
+                            cycle_tracker.insert(curr_node.src.clone(), (first, period));
+
+                            if cycle_tracker.len() == inputs.len() {
+                                return Ok(cycle_tracker);
+                            }
+                        }
+                        Some(_) => (),
                     }
                 }
 
                 // -- Send pulse.
+                common::count!("pulses_sent");
                 curr_node.send_pulse(&mut modules, &mut queue);
             }
         }
 
-        panic!("no cycles found!");
+        Err(Error::new("no cycles found"))
     }
 
-    fn find_required_button_presses(&self, name: &str) -> usize {
+    fn find_required_button_presses(&self, name: &str) -> Result<u64, Error> {
         let name = Rc::new(String::from(name));
 
         // -- Find conjunction that signals to rx.
-        let final_conjunction = self.find_module_inputs(&name).first().unwrap().clone();
+        let final_conjunction = self
+            .find_module_inputs(&name)
+            .first()
+            .ok_or_else(|| Error::new(format!("no module sends pulses to '{}'", name)))?
+            .clone();
 
         // -- Find all inputs of that conjunction.
         let final_inputs = self.find_module_inputs(&final_conjunction);
 
         // -- Find where those inputs cycle.
-        let cycle_map = self.find_cycles(&final_conjunction, &final_inputs);
-
-        // -- Calculate when final module will be activated
-        let mut required_button_presses = 1;
-
-        for value in cycle_map.values() {
-            required_button_presses = lcm(required_button_presses, *value);
-        }
+        let cycle_map = self.find_cycles(&final_conjunction, &final_inputs)?;
+
+        let mut cycles: Vec<_> = cycle_map.iter().collect();
+        cycles.sort();
+        diagnostics::record(
+            "cycles",
+            Value::Object(
+                cycles
+                    .into_iter()
+                    .map(|(src, (first, period))| {
+                        let cycle = Value::Object(vec![
+                            (String::from("first"), (*first).into()),
+                            (String::from("period"), (*period).into()),
+                        ]);
+                        (src.to_string(), cycle)
+                    })
+                    .collect(),
+            ),
+        );
 
-        required_button_presses
+        // -- Calculate when final module will be activated, which is no earlier than the
+        // -- first high pulse of every input.
+        let settled = cycle_map
+            .values()
+            .map(|(first, _)| *first)
+            .max()
+            .unwrap_or(0);
+        let congruences = cycle_map
+            .values()
+            .map(|(first, period)| Congruence::new(*first as u64, *period as u64));
+
+        math::crt(congruences)
+            .and_then(|solution| solution.first_at_least(settled as u64))
+            .ok_or_else(|| Error::new("input cycles never line up"))
     }
 }
 
 // -----------------------------------------------------------------------------
-fn main() {
-    let system = System::from(&stdin());
-    let num = system.find_required_button_presses("rx");
-    println!("{}", num);
+pub fn parse(input: &str) -> Result<System, Error> {
+    System::try_from(input)
+}
+
+pub fn answer(system: &System) -> Result<Answer, Error> {
+    let num = system.find_required_button_presses("rx")?;
+    Ok(num.into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
 }
@@ -0,0 +1,150 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- Pressing the button until rx fires takes as many presses as the answer.
+const MAX_PRESSES: u64 = 1_000_000;
+
+// -- Two or three 4-bit counters fire rx within a few thousand presses.
+fn small(rng: &mut Rng, size: usize) -> String {
+    generate::network(rng, size.clamp(2, 3), 4)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Broadcast,
+    FlipFlop,
+    Conjunction,
+}
+
+struct Machine<'a> {
+    kinds: HashMap<&'a str, Kind>,
+    outputs: HashMap<&'a str, Vec<&'a str>>,
+    on: HashMap<&'a str, bool>,
+    // -- The last pulse each conjunction got from each of its inputs, high or not.
+    memory: HashMap<&'a str, HashMap<&'a str, bool>>,
+}
+
+impl<'a> Machine<'a> {
+    fn parse(input: &'a str) -> Result<Self, Error> {
+        let mut machine = Self {
+            kinds: HashMap::new(),
+            outputs: HashMap::new(),
+            on: HashMap::new(),
+            memory: HashMap::new(),
+        };
+
+        for line in input.lines() {
+            let (module, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| Error::new(format!("malformed module '{}'", line)))?;
+            let (kind, name) = match module.split_at(module.len().min(1)) {
+                ("%", name) => (Kind::FlipFlop, name),
+                ("&", name) => (Kind::Conjunction, name),
+                _ if module == "broadcaster" => (Kind::Broadcast, module),
+                _ => return Err(Error::new(format!("unknown module '{}'", module))),
+            };
+
+            if name.is_empty() || outputs.split(", ").any(str::is_empty) {
+                return Err(Error::new(format!("malformed module '{}'", line)));
+            }
+
+            machine.kinds.insert(name, kind);
+            machine.outputs.insert(name, outputs.split(", ").collect());
+        }
+
+        for (name, outputs) in machine.outputs.iter() {
+            for output in outputs {
+                if machine.kinds.get(output) == Some(&Kind::Conjunction) {
+                    machine
+                        .memory
+                        .entry(output)
+                        .or_default()
+                        .insert(name, false);
+                }
+            }
+        }
+
+        Ok(machine)
+    }
+
+    // -- Returns the number of low and high pulses, and whether rx got a low one.
+    fn press(&mut self) -> (u64, u64, bool) {
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        let (mut low, mut high, mut rx) = (0, 0, false);
+
+        while let Some((from, to, pulse)) = queue.pop_front() {
+            if pulse {
+                high += 1;
+            } else {
+                low += 1;
+                rx |= to == "rx";
+            }
+
+            let send = match self.kinds.get(to) {
+                Some(Kind::Broadcast) => pulse,
+                Some(Kind::FlipFlop) if !pulse => {
+                    let on = self.on.entry(to).or_default();
+                    *on = !*on;
+                    *on
+                }
+                Some(Kind::Conjunction) => {
+                    let memory = self.memory.get_mut(to).unwrap();
+                    memory.insert(from, pulse);
+                    !memory.values().all(|high| *high)
+                }
+                _ => continue,
+            };
+
+            for output in self.outputs[to].iter() {
+                queue.push_back((to, output, send));
+            }
+        }
+
+        (low, high, rx)
+    }
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    let mut machine = Machine::parse(input)?;
+    let (mut low, mut high) = (0, 0);
+
+    for _ in 0..1000 {
+        let (pressed_low, pressed_high, _) = machine.press();
+        low += pressed_low;
+        high += pressed_high;
+    }
+
+    Ok((low * high).into())
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let mut machine = Machine::parse(input)?;
+
+    for presses in 1..=MAX_PRESSES {
+        if machine.press().2 {
+            return Ok(presses.into());
+        }
+    }
+
+    Err(Error::new("rx never got a low pulse"))
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: part2::solve,
+        slow: slow_part2,
+        generate: small,
+    },
+];
@@ -0,0 +1,139 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+use std::rc::Rc;
+
+use common::stepper::{Simulation, Start};
+use common::Error;
+
+use crate::part1::{self, Module, ModuleType, Node, Pulse, System};
+
+// -----------------------------------------------------------------------------
+fn pulse_name(pulse: Pulse) -> &'static str {
+    match pulse {
+        Pulse::Low => "low",
+        Pulse::High => "high",
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- Button presses as in part 1, one pulse delivered at a time; pressing the button is
+// -- a step of its own whenever the queue runs dry.
+struct Machine {
+    names: Rc<Vec<Rc<String>>>,
+    modules: HashMap<Rc<String>, Module>,
+    queue: VecDeque<Node>,
+    delivered: Option<Node>,
+    presses: usize,
+    num_low: usize,
+    num_high: usize,
+}
+
+impl Machine {
+    fn module_state(module: &Module) -> String {
+        match module.mtype {
+            ModuleType::Broadcast => String::from("broadcaster"),
+            ModuleType::FlipFlop => {
+                format!(
+                    "%{} {}",
+                    module.name,
+                    if module.switch { "on" } else { "off" }
+                )
+            }
+            ModuleType::Conjunction => {
+                let mut inputs: Vec<_> = module.mem.iter().collect();
+                inputs.sort_by_key(|(a, _)| *a);
+                let memory: Vec<_> = inputs
+                    .iter()
+                    .map(|(name, pulse)| format!("{}={}", name, pulse_name(**pulse)))
+                    .collect();
+                format!("&{} [{}]", module.name, memory.join(" "))
+            }
+        }
+    }
+}
+
+impl Simulation for Machine {
+    fn step(&mut self) -> bool {
+        let Some(node) = self.queue.pop_front() else {
+            self.queue = System::init_queue();
+            self.presses += 1;
+            self.delivered = None;
+            return true;
+        };
+
+        match node.pulse {
+            Pulse::Low => self.num_low += 1,
+            Pulse::High => self.num_high += 1,
+        }
+
+        node.send_pulse(&mut self.modules, &mut self.queue);
+        self.delivered = Some(node);
+        true
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+
+        // -- The module that just received a pulse is marked.
+        let receiver = self.delivered.as_ref().map(|node| &node.dst);
+
+        for name in self.names.iter() {
+            let module = &self.modules[name];
+            let dsts: Vec<_> = module.dsts.iter().map(|dst| dst.as_str()).collect();
+            let marker = if receiver == Some(name) { '>' } else { ' ' };
+
+            writeln!(
+                text,
+                "{} {:<40} -> {}",
+                marker,
+                Machine::module_state(module),
+                dsts.join(", ")
+            )
+            .unwrap();
+        }
+
+        writeln!(text, "\nqueue:").unwrap();
+        for node in self.queue.iter() {
+            writeln!(
+                text,
+                "  {} -{}-> {}",
+                node.src,
+                pulse_name(node.pulse),
+                node.dst
+            )
+            .unwrap();
+        }
+
+        text
+    }
+
+    fn status(&self) -> String {
+        let delivered = match &self.delivered {
+            Some(node) => format!("{} -{}-> {}", node.src, pulse_name(node.pulse), node.dst),
+            None if self.presses > 0 => String::from("button pressed"),
+            None => String::from("waiting for the button"),
+        };
+
+        format!(
+            "press {}, {}, {} low and {} high pulse(s)",
+            self.presses, delivered, self.num_low, self.num_high
+        )
+    }
+}
+
+pub fn start(input: &str) -> Result<Start, Error> {
+    let system = Rc::new(part1::parse(input)?);
+    let names = Rc::new(system.module_names());
+
+    Ok(Box::new(move || {
+        Box::new(Machine {
+            names: names.clone(),
+            modules: system.init_module_map(),
+            queue: VecDeque::new(),
+            delivered: None,
+            presses: 0,
+            num_low: 0,
+            num_high: 0,
+        })
+    }))
+}
@@ -0,0 +1,30 @@
+use common::random::Rng;
+
+// -----------------------------------------------------------------------------
+// -- A square garden with an odd side of at least `size`, the start in the middle and the
+// -- middle row, middle column and border free of rocks, like the puzzle input. The
+// -- extrapolated answer to part two is only exact when the side divides 2 * 26501365 + 1
+// -- (as 131 does), but every side exercises the same work.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(5) | 1;
+    let middle = side / 2;
+    let mut input = String::with_capacity(side * (side + 1));
+
+    for row in 0..side {
+        for col in 0..side {
+            let clear =
+                row == middle || col == middle || row % (side - 1) == 0 || col % (side - 1) == 0;
+
+            input.push(if row == middle && col == middle {
+                'S'
+            } else if !clear && rng.chance(0.15) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+
+    input
+}
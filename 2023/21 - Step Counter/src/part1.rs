@@ -0,0 +1,135 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, PartialEq)]
+enum TileType {
+    Rock,
+    Garden,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' | 'S' => Ok(Garden),
+            '#' => Ok(Rock),
+            _ => Err(Error::new(format!(
+                "invalid char for tile type: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            Rock => '#',
+            Garden => '.',
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct Board {
+    grid: Grid<TileType>,
+    start: Coord2D,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut start = None;
+        let grid = Grid::parse(input, |coord, ch| {
+            let tile = TileType::try_from(ch)?;
+
+            if ch == 'S' {
+                if start.is_some() {
+                    return Err(Error::new("found more than one start tile"));
+                }
+                start = Some(coord);
+            }
+
+            Ok(tile)
+        })?;
+
+        let start = start.ok_or_else(|| Error::new("no start tile found"))?;
+
+        Ok(Self { grid, start })
+    }
+}
+
+impl Board {
+    fn go_direction(&self, coord: &Coord2D, direction: Direction) -> Option<Coord2D> {
+        let new_coord = self.grid.step(coord, direction)?;
+
+        if self.grid[new_coord] == Rock {
+            return None;
+        }
+
+        Some(new_coord)
+    }
+
+    pub(crate) fn start_queue(&self) -> VecDeque<Coord2D> {
+        VecDeque::from([self.start])
+    }
+
+    // -- The plots reachable with one more step from those in the queue.
+    pub(crate) fn step_queue(&self, mut queue: VecDeque<Coord2D>) -> VecDeque<Coord2D> {
+        let mut next_queue = VecDeque::new();
+        let mut queued = HashSet::new();
+
+        while let Some(curr) = queue.pop_front() {
+            for direction in Direction::ALL {
+                if let Some(next) = self.go_direction(&curr, direction) {
+                    if queued.insert(next) {
+                        next_queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        next_queue
+    }
+
+    fn fill(&self, limit: usize) -> Vec<Coord2D> {
+        let mut queue = self.start_queue();
+
+        for _ in 0..limit {
+            queue = self.step_queue(queue);
+            visualize::frame(|| self.canvas(&queue).render());
+        }
+
+        image::record(|| self.canvas(&queue).to_drawing());
+        queue.into()
+    }
+
+    pub(crate) fn canvas(&self, reached: &VecDeque<Coord2D>) -> Canvas {
+        let rocks = self.grid.coords().filter(|coord| self.grid[*coord] == Rock);
+
+        Canvas::from_grid(&self.grid, TileType::glyph)
+            .layer(Color::Gray, rocks)
+            .layer(Color::Green, reached.iter().copied())
+            .highlight([self.start])
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    Ok(board.fill(64).len().into())
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
@@ -0,0 +1,277 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{image, visualize, Answer, Error};
+use grid::{Canvas, Color, Coord2D, Direction, Grid};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, PartialEq)]
+enum TileType {
+    Rock,
+    Garden,
+}
+
+use TileType::*;
+
+impl TryFrom<char> for TileType {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' | 'S' => Ok(Garden),
+            '#' => Ok(Rock),
+            _ => Err(Error::new(format!(
+                "invalid char for tile type: '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Point<T> {
+    fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+// -----------------------------------------------------------------------------
+fn lagrange_iterpolation(points: &[Point<usize>]) -> Vec<f64> {
+    let points: Vec<Point<f64>> = points
+        .iter()
+        .map(|point| {
+            // -- Forcing isize to f64 conversion.
+            let x = point.x as f64;
+            let y = point.y as f64;
+
+            if x as usize != point.x || y as usize != point.y {
+                panic!(
+                    "usize to f64 converson error: ({}, {}) != ({}, {})",
+                    point.x, point.y, x, y
+                );
+            }
+
+            Point::<f64>::new(x, y)
+        })
+        .collect();
+    let n = points.len();
+    let mut polynomial_points = vec![f64::default(); n];
+
+    for i in 0..n {
+        let mut product = 1.0;
+
+        for j in 0..n {
+            if j == i {
+                continue;
+            }
+            product *= points[i].x - points[j].x;
+        }
+
+        product = points[i].y / product;
+
+        let mut term = vec![f64::default(); n];
+        term[0] = product;
+
+        for (j, point) in points.iter().enumerate() {
+            if j == i {
+                continue;
+            }
+
+            for k in (1..n).rev() {
+                term[k] += term[k - 1];
+                term[k - 1] *= -point.x;
+            }
+        }
+
+        for j in 0..n {
+            polynomial_points[j] += term[j]
+        }
+    }
+
+    polynomial_points
+}
+
+fn lagrange_eval(x: usize, polynomial_points: &[f64]) -> f64 {
+    // -- Convert usize to f64.
+    let x_f64 = x as f64;
+
+    if x_f64 as usize != x {
+        panic!("usize to f64 conversion error: {} != {}", x, x_f64);
+    }
+
+    let x = x_f64;
+
+    // -- Evaluate the Lagrange polynomial.
+    let mut sum = 0.0;
+
+    for (i, point) in polynomial_points.iter().enumerate() {
+        // -- Convert i from usize to f64.
+        let i_f64 = i as f64;
+
+        if i_f64 as usize != i {
+            panic!("usize to f64 conversion error: {} != {}", i, i_f64);
+        }
+
+        sum += point * x.powf(i_f64);
+    }
+
+    sum
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            Rock => '#',
+            Garden => '.',
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub struct Board {
+    grid: Grid<TileType>,
+    start: Coord2D,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut start = None;
+        let grid = Grid::parse(input, |coord, ch| {
+            let tile = TileType::try_from(ch)?;
+
+            if ch == 'S' {
+                if start.is_some() {
+                    return Err(Error::new("found more than one start tile"));
+                }
+                start = Some(coord);
+            }
+
+            Ok(tile)
+        })?;
+
+        let start = start.ok_or_else(|| Error::new("no start tile found"))?;
+
+        Ok(Self { grid, start })
+    }
+}
+
+impl Board {
+    fn go_direction_wrap(&self, coord: &Coord2D, direction: Direction) -> Option<Coord2D> {
+        let new_coord = coord.step(direction);
+
+        if *self.grid.get_wrap(&new_coord) == Rock {
+            return None;
+        }
+
+        Some(new_coord)
+    }
+
+    fn fill(&self, limit: usize) -> Option<usize> {
+        const NUM_SAMPLES: usize = 3;
+
+        let mut points = Vec::with_capacity(NUM_SAMPLES);
+        let mut queue = VecDeque::from([self.start]);
+        let mut seen_odd = HashSet::new();
+        let mut seen_even = HashSet::new();
+
+        let ncols = self.grid.ncols();
+        assert_eq!(ncols, self.grid.nrows());
+
+        for num_steps in 0..limit {
+            // -- Collect approixmation points.
+            if num_steps % ncols == ncols / 2 {
+                let reached = if num_steps % 2 == 0 {
+                    &seen_odd
+                } else {
+                    &seen_even
+                };
+                points.push(Point::<usize>::new(num_steps, reached.len()));
+                visualize::frame(|| self.canvas(num_steps, reached).render());
+
+                // -- The widest of the samples, all the later ones are extrapolated.
+                if points.len() == NUM_SAMPLES {
+                    image::record(|| self.canvas(num_steps, reached).to_drawing());
+                }
+
+                if points.len() == NUM_SAMPLES {
+                    let poly_points = lagrange_iterpolation(&points);
+                    let solution = lagrange_eval(limit, &poly_points);
+                    return Some(solution.round() as usize);
+                }
+            }
+
+            let mut next_queue = VecDeque::new();
+
+            while let Some(curr) = queue.pop_front() {
+                for direction in Direction::ALL {
+                    if let Some(next) = self.go_direction_wrap(&curr, direction) {
+                        if next_queue.contains(&next) {
+                            continue;
+                        }
+
+                        if num_steps % 2 == 0 {
+                            if seen_even.contains(&next) {
+                                continue;
+                            }
+                            seen_even.insert(next);
+                        } else {
+                            if seen_odd.contains(&next) {
+                                continue;
+                            }
+                            seen_odd.insert(next);
+                        }
+
+                        next_queue.push_back(next);
+                    }
+                }
+            }
+
+            queue = next_queue;
+        }
+
+        None
+    }
+
+    // -- The tiles of the infinite garden that `num_steps` steps can reach.
+    fn canvas(&self, num_steps: usize, reached: &HashSet<Coord2D>) -> Canvas {
+        let (ncols, nrows) = (self.grid.ncols(), self.grid.nrows());
+        let tiles = 2 * (num_steps / ncols.max(1)) + 1;
+        let origin = Coord2D::from_indices(tiles / 2 * ncols, tiles / 2 * nrows);
+        let shift = |coord: &Coord2D| coord.offset(origin.col, origin.row);
+        let rocks = self.grid.coords().filter(|coord| self.grid[*coord] == Rock);
+
+        Canvas::from_fn(tiles * ncols, tiles * nrows, |coord| {
+            let coord = coord.offset(-origin.col, -origin.row);
+            self.grid.get_wrap(&coord).glyph()
+        })
+        // -- The original garden's rocks in gray, its copies' plain.
+        .layer(Color::Gray, rocks.map(|coord| shift(&coord)))
+        .layer(Color::Green, reached.iter().map(shift))
+        .highlight([shift(&self.start)])
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn parse(input: &str) -> Result<Board, Error> {
+    Board::try_from(input)
+}
+
+pub fn answer_steps(board: &Board, steps: usize) -> Result<Answer, Error> {
+    let num_plots = board.fill(steps).ok_or_else(|| Error::new("fill failed"))?;
+    Ok(num_plots.into())
+}
+
+pub fn answer(board: &Board) -> Result<Answer, Error> {
+    answer_steps(board, 26501365)
+}
+
+pub fn solve(input: &str) -> Result<Answer, Error> {
+    answer(&parse(input)?)
+}
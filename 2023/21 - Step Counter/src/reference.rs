@@ -0,0 +1,122 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+use crate::{generate, part1, part2};
+
+// -- The extrapolation is only exact on step counts of the form side / 2 + k * side, so part
+// -- two is checked on a count small enough to walk the infinite garden tile by tile.
+const TILES: usize = 4;
+
+fn small(rng: &mut Rng, size: usize) -> String {
+    generate::generate(rng, 5 + size.min(3) * 2)
+}
+
+fn side(input: &str) -> usize {
+    input.lines().count()
+}
+
+// -- Every plot within `steps` of the start whose distance has the same parity as `steps`.
+fn walk(input: &str, steps: usize, wrap: bool) -> Result<usize, Error> {
+    let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let (nrows, ncols) = (
+        rows.len() as i64,
+        rows.first().map_or(0, |row| row.len()) as i64,
+    );
+
+    if nrows == 0 || rows.iter().any(|row| row.len() as i64 != ncols) {
+        return Err(Error::new("garden is not rectangular"));
+    }
+
+    let mut starts = rows.iter().enumerate().flat_map(|(row, line)| {
+        line.iter()
+            .enumerate()
+            .filter(|(_, ch)| **ch == b'S')
+            .map(move |(col, _)| (row as i64, col as i64))
+    });
+    let start = starts
+        .next()
+        .ok_or_else(|| Error::new("no start tile found"))?;
+
+    if starts.next().is_some() {
+        return Err(Error::new("found more than one start tile"));
+    }
+
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut count = 0;
+
+    while let Some(((row, col), distance)) = queue.pop_front() {
+        if distance % 2 == steps % 2 {
+            count += 1;
+        }
+
+        if distance == steps {
+            continue;
+        }
+
+        for (next_row, next_col) in [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ] {
+            let inside = (0..nrows).contains(&next_row) && (0..ncols).contains(&next_col);
+
+            if !wrap && !inside {
+                continue;
+            }
+
+            let tile =
+                rows[next_row.rem_euclid(nrows) as usize][next_col.rem_euclid(ncols) as usize];
+
+            match tile {
+                b'#' => continue,
+                b'.' | b'S' => (),
+                _ => {
+                    return Err(Error::new(format!(
+                        "invalid char for tile type: '{}'",
+                        tile as char
+                    )))
+                }
+            }
+
+            if seen.insert((next_row, next_col)) {
+                queue.push_back(((next_row, next_col), distance + 1));
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+fn slow_part1(input: &str) -> Result<Answer, Error> {
+    Ok(walk(input, 64, false)?.into())
+}
+
+fn fast_part2(input: &str) -> Result<Answer, Error> {
+    let side = side(input);
+    part2::answer_steps(&part2::parse(input)?, side / 2 + TILES * side)
+}
+
+fn slow_part2(input: &str) -> Result<Answer, Error> {
+    let side = side(input);
+    Ok(walk(input, side / 2 + TILES * side, true)?.into())
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        part: 1,
+        fast: part1::solve,
+        slow: slow_part1,
+        generate: generate::generate,
+    },
+    Reference {
+        part: 2,
+        fast: fast_part2,
+        slow: slow_part2,
+        generate: small,
+    },
+];
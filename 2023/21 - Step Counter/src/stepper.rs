@@ -0,0 +1,69 @@
+use std::collections::{HashSet, VecDeque};
+use std::rc::Rc;
+
+use common::stepper::{Simulation, Start};
+use common::Error;
+use grid::Coord2D;
+
+use crate::part1::{self, Board};
+
+// -----------------------------------------------------------------------------
+// -- Part 1's walk on the single garden, one step of the elf at a time.
+struct Walk {
+    board: Rc<Board>,
+    queue: VecDeque<Coord2D>,
+    // -- The plots reached a step earlier.
+    before: HashSet<Coord2D>,
+    // -- Back to the plots of two steps earlier, so it alternates between two sets now.
+    settled: bool,
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> bool {
+        let queue = std::mem::take(&mut self.queue);
+        let reached: HashSet<Coord2D> = queue.iter().copied().collect();
+        self.queue = self.board.step_queue(queue);
+
+        self.settled = self.queue.len() == self.before.len()
+            && self.queue.iter().all(|plot| self.before.contains(plot));
+        self.before = reached;
+        true
+    }
+
+    fn advance(&mut self, steps: usize) -> usize {
+        for taken in 0..steps {
+            if self.settled {
+                // -- Only whether an odd number of steps is left matters.
+                if (steps - taken) % 2 == 1 {
+                    self.step();
+                }
+                break;
+            }
+
+            self.step();
+        }
+
+        steps
+    }
+
+    fn render(&self) -> String {
+        self.board.canvas(&self.queue).render()
+    }
+
+    fn status(&self) -> String {
+        format!("{} plot(s) reachable", self.queue.len())
+    }
+}
+
+pub fn start(input: &str) -> Result<Start, Error> {
+    let board = Rc::new(part1::parse(input)?);
+
+    Ok(Box::new(move || {
+        Box::new(Walk {
+            board: board.clone(),
+            queue: board.start_queue(),
+            before: HashSet::new(),
+            settled: false,
+        })
+    }))
+}
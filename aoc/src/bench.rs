@@ -0,0 +1,311 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::json::Value;
+
+use crate::days::{Day, UNDATED_YEAR};
+use crate::table;
+
+const HISTORY_NAME: &str = "bench-history.json";
+
+// -- Differences smaller than this are treated as noise regardless of the threshold.
+const MIN_DELTA: Duration = Duration::from_micros(100);
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Sample {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Object(vec![
+            (String::from("year"), self.year.into()),
+            (String::from("day"), self.day.into()),
+            (String::from("part"), self.part.into()),
+            (String::from("answer"), self.answer.as_str().into()),
+            (
+                String::from("parse_ns"),
+                (self.parse.as_nanos() as u64).into(),
+            ),
+            (
+                String::from("solve_ns"),
+                (self.solve.as_nanos() as u64).into(),
+            ),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let field = |name| value.get(name).and_then(Value::as_u64);
+
+        Some(Self {
+            year: match value.get("year") {
+                Some(_) => field("year")?.try_into().ok()?,
+                None => UNDATED_YEAR,
+            },
+            day: field("day")?.try_into().ok()?,
+            part: field("part")?.try_into().ok()?,
+            answer: String::from(value.get("answer")?.as_str()?),
+            parse: Duration::from_nanos(field("parse_ns")?),
+            solve: Duration::from_nanos(field("solve_ns")?),
+        })
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+pub fn measure(day: &Day, part: u8, input: &str, iterations: u32) -> Result<Sample, String> {
+    let timed = day
+        .timed(part)
+        .ok_or_else(|| format!("day {:02} has no part {}", day.number, part))?;
+
+    let mut answer = None;
+    let mut parse = Vec::new();
+    let mut solve = Vec::new();
+
+    for _ in 0..iterations.max(1) {
+        let timing =
+            timed(input).map_err(|err| format!("day {:02} part {}: {}", day.number, part, err))?;
+
+        answer = Some(timing.answer);
+        parse.push(timing.parse);
+        solve.push(timing.solve);
+    }
+
+    Ok(Sample {
+        year: day.year,
+        day: day.number,
+        part,
+        answer: answer.unwrap().to_string(),
+        parse: median(parse),
+        solve: median(solve),
+    })
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub struct Run {
+    pub timestamp: u64,
+    pub iterations: u32,
+    pub samples: Vec<Sample>,
+}
+
+impl Run {
+    pub fn new(iterations: u32, samples: Vec<Sample>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        Self {
+            timestamp,
+            iterations,
+            samples,
+        }
+    }
+
+    pub fn sample(&self, year: u16, day: u8, part: u8) -> Option<&Sample> {
+        self.samples
+            .iter()
+            .find(|sample| sample.year == year && sample.day == day && sample.part == part)
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Object(vec![
+            (String::from("timestamp"), self.timestamp.into()),
+            (String::from("iterations"), self.iterations.into()),
+            (
+                String::from("samples"),
+                Value::Array(self.samples.iter().map(Sample::to_json).collect()),
+            ),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let samples = value.get("samples")?.as_array()?;
+
+        Some(Self {
+            timestamp: value.get("timestamp")?.as_u64()?,
+            iterations: value.get("iterations")?.as_u64()?.try_into().ok()?,
+            samples: samples
+                .iter()
+                .map(Sample::from_json)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(HISTORY_NAME)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let value = Value::parse(text)?;
+        let runs = value
+            .get("runs")
+            .and_then(Value::as_array)
+            .ok_or("missing 'runs' array")?;
+
+        let runs = runs
+            .iter()
+            .enumerate()
+            .map(|(index, run)| Run::from_json(run).ok_or(format!("malformed run #{}", index + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { runs })
+    }
+
+    // -- A missing history file is simply an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("'{}': {}", path.display(), err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("unable to read '{}': {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, format!("{}\n", self))
+            .map_err(|err| format!("unable to write '{}': {}", path.display(), err))
+    }
+
+    // -- The most recent recorded sample for a day and part, if any.
+    pub fn previous(&self, year: u16, day: u8, part: u8) -> Option<&Sample> {
+        self.runs
+            .iter()
+            .rev()
+            .find_map(|run| run.sample(year, day, part))
+    }
+}
+
+impl std::fmt::Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // -- One run per line keeps the file readable without a pretty printer.
+        writeln!(f, "{{\"runs\":[")?;
+
+        for (index, run) in self.runs.iter().enumerate() {
+            let separator = if index + 1 < self.runs.len() { "," } else { "" };
+            writeln!(f, "{}{}", run.to_json(), separator)?;
+        }
+
+        write!(f, "]}}")
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    New,
+    Steady,
+    Faster,
+    Slower,
+}
+
+use Trend::*;
+
+impl Trend {
+    pub fn between(previous: Option<&Sample>, current: &Sample, threshold: f64) -> Self {
+        let Some(previous) = previous else {
+            return New;
+        };
+
+        let before = previous.total();
+        let after = current.total();
+
+        if before.abs_diff(after) < MIN_DELTA {
+            return Steady;
+        }
+
+        let change = after.as_secs_f64() / before.as_secs_f64().max(f64::EPSILON) - 1.0;
+
+        if change > threshold {
+            Slower
+        } else if change < -threshold {
+            Faster
+        } else {
+            Steady
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            New => "new",
+            Steady => "ok",
+            Faster => "faster",
+            Slower => "REGRESSION",
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000_000 {
+        format!("{:.1}µs", duration.as_secs_f64() * 1e6)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.3}ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.3}s", duration.as_secs_f64())
+    }
+}
+
+pub fn table(samples: &[Sample], history: &History, threshold: f64) -> String {
+    let header = [
+        "year", "day", "part", "parse", "solve", "total", "previous", "change", "status",
+    ];
+    let rows: Vec<_> = samples
+        .iter()
+        .map(|sample| {
+            let previous = history.previous(sample.year, sample.day, sample.part);
+            let trend = Trend::between(previous, sample, threshold);
+
+            let (before, change) = match previous {
+                Some(previous) => {
+                    let before = previous.total().as_secs_f64().max(f64::EPSILON);
+                    let change = (sample.total().as_secs_f64() / before - 1.0) * 100.0;
+                    (
+                        format_duration(previous.total()),
+                        format!("{:+.1}%", change),
+                    )
+                }
+                None => (String::from("-"), String::from("-")),
+            };
+
+            [
+                sample.year.to_string(),
+                format!("{:02}", sample.day),
+                sample.part.to_string(),
+                format_duration(sample.parse),
+                format_duration(sample.solve),
+                format_duration(sample.total()),
+                before,
+                change,
+                String::from(trend.label()),
+            ]
+        })
+        .collect();
+
+    table::render(header, &rows)
+}
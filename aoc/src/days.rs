@@ -0,0 +1,163 @@
+use std::path::PathBuf;
+
+use common::random::Generator;
+use common::reference::Reference;
+use common::report::Part;
+use common::stepper::Loader;
+use common::{Explainer, Querier, Solver, Timed};
+
+// -- Ledgers and bench histories written before the workspace had several years only
+// -- ever held this one's answers.
+pub const UNDATED_YEAR: u16 = 2023;
+
+// -----------------------------------------------------------------------------
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    pub title: &'static str,
+    pub parts: [Solver; 2],
+    pub timed: [Timed; 2],
+    pub generate: Generator,
+    pub references: &'static [Reference],
+    // -- The simulation-style days can be stepped through one tick at a time.
+    pub stepper: Option<Loader>,
+    // -- Days that can show how each line of their input was read.
+    pub explain: Option<Explainer>,
+    // -- Days with a query language for questions beyond the two answers.
+    pub query: Option<Querier>,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("{:02} - {}", self.number, self.title)
+    }
+
+    // -- The crate's directory relative to the workspace root, e.g. "2023/07 - Camel Cards".
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.year, self.name())
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.year.to_string())
+            .join(self.name())
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.parts.get(index).copied()
+    }
+
+    pub fn timed(&self, part: u8) -> Option<Timed> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.timed.get(index).copied()
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        let timed = self.timed(part)?;
+        Some(Part {
+            year: self.year,
+            day: self.number,
+            part,
+            timed,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- Optional extras follow the crate name: 'stepper', 'explain' and 'query'.
+macro_rules! day {
+    (@stepper $krate:ident, stepper $(, $rest:ident)*) => {
+        Some($krate::stepper::start)
+    };
+    (@explain $krate:ident, explain $(, $rest:ident)*) => {
+        Some($krate::explain::explain)
+    };
+    (@query $krate:ident, query $(, $rest:ident)*) => {
+        Some($krate::query::query)
+    };
+    (@$field:ident $krate:ident, $other:ident $(, $rest:ident)*) => {
+        day!(@$field $krate $(, $rest)*)
+    };
+    (@$field:ident $krate:ident) => {
+        None
+    };
+    ($year:literal, $number:literal, $title:literal, $krate:ident $(, $extra:ident)*) => {
+        Day {
+            year: $year,
+            number: $number,
+            title: $title,
+            parts: [$krate::part1::solve, $krate::part2::solve],
+            timed: [common::timed!($krate::part1), common::timed!($krate::part2)],
+            generate: $krate::generate::generate,
+            references: $krate::reference::REFERENCES,
+            stepper: day!(@stepper $krate $(, $extra)*),
+            explain: day!(@explain $krate $(, $extra)*),
+            query: day!(@query $krate $(, $extra)*),
+        }
+    };
+}
+
+// -- Registered in order of year, then day.
+pub static DAYS: [Day; 21] = [
+    day!(2023, 1, "Trebuchet?!", trebuchet, explain),
+    day!(2023, 2, "Cube Conundrum", cube_conundrum, query),
+    day!(2023, 3, "Gear Ratios", gear_ratios),
+    day!(2023, 4, "Scratchcards", scratchcards),
+    day!(
+        2023,
+        5,
+        "If You Give A Seed A Fertilizer",
+        if_you_give_a_seed_a_fertilizer
+    ),
+    day!(2023, 6, "Wait For It", wait_for_it),
+    day!(2023, 7, "Camel Cards", camel_cards),
+    day!(2023, 8, "Haunted Wasteland", haunted_wasteland),
+    day!(2023, 9, "Mirage Maintenance", mirage_maintenance),
+    day!(2023, 10, "Pipe Maze", pipe_maze),
+    day!(2023, 11, "Cosmic Expansion", cosmic_expansion),
+    day!(2023, 12, "Hot Springs", hot_springs),
+    day!(2023, 13, "Point of Incidence", point_of_incidence),
+    day!(
+        2023,
+        14,
+        "Parabolic Reflector Dish",
+        parabolic_reflector_dish,
+        stepper
+    ),
+    day!(2023, 15, "Lens Library", lens_library),
+    day!(
+        2023,
+        16,
+        "The Floor Will Be Lava",
+        the_floor_will_be_lava,
+        stepper
+    ),
+    day!(2023, 17, "Clumsy Crucible", clumsy_crucible),
+    day!(2023, 18, "Lavaduct Lagoon", lavaduct_lagoon),
+    day!(2023, 19, "Aplenty", aplenty),
+    day!(2023, 20, "Pulse Propagation", pulse_propagation, stepper),
+    day!(2023, 21, "Step Counter", step_counter, stepper),
+];
+
+pub fn find(year: u16, number: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.year == year && day.number == number)
+}
+
+// -- The year a bare '--day' refers to.
+pub fn latest_year() -> u16 {
+    DAYS.last().map_or(UNDATED_YEAR, |day| day.year)
+}
+
+// -- Every day of the given year, or of every year.
+pub fn of_year(year: Option<u16>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|day| year.is_none_or(|year| day.year == year))
+        .collect()
+}
@@ -0,0 +1,240 @@
+use std::any::Any;
+use std::ops::Range;
+use std::panic;
+
+use common::random::Rng;
+use common::reference::Reference;
+use common::Solver;
+
+use crate::days::Day;
+use crate::table;
+
+pub const DEFAULT_SEEDS: u64 = 20;
+pub const DEFAULT_SIZES: [usize; 3] = [1, 3, 6];
+
+// -- Every candidate runs both solvers, so shrinking gives up after this many.
+const MAX_SHRINK_ATTEMPTS: usize = 5_000;
+
+// -----------------------------------------------------------------------------
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => String::from(*message),
+        (_, Some(message)) => message.clone(),
+        _ => String::from("unknown panic"),
+    }
+}
+
+// -- Answers are compared as text, so a signed and an unsigned answer of the same value
+// -- agree. A panic counts as an error.
+fn outcome(solver: Solver, input: &str) -> Result<String, String> {
+    match panic::catch_unwind(|| solver(input)) {
+        Ok(Ok(answer)) => Ok(answer.to_string()),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+// -- The input is a failing case when the reference solves it and the fast solver does
+// -- not agree. Inputs the reference rejects are skipped, which also keeps shrinking on
+// -- valid inputs.
+fn disagreement(reference: &Reference, input: &str) -> Option<(String, Result<String, String>)> {
+    let expected = outcome(reference.slow, input).ok()?;
+    let actual = outcome(reference.fast, input);
+
+    if actual.as_ref() == Ok(&expected) {
+        return None;
+    }
+
+    Some((expected, actual))
+}
+
+// -----------------------------------------------------------------------------
+fn join(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+fn numbers(line: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+
+    for (index, ch) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (ch.is_ascii_digit(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(begin)) => {
+                ranges.push(begin..index);
+                start = None;
+            }
+            _ => (),
+        }
+    }
+
+    ranges
+}
+
+// -- Smaller inputs that might still fail: first without runs of lines, then without
+// -- columns of a rectangular input, then with smaller numbers.
+fn candidates(lines: &[String]) -> Vec<Vec<String>> {
+    let mut candidates = Vec::new();
+
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        for start in (0..lines.len()).step_by(chunk) {
+            let mut smaller = lines[..start].to_vec();
+            smaller.extend_from_slice(&lines[(start + chunk).min(lines.len())..]);
+            candidates.push(smaller);
+        }
+        chunk /= 2;
+    }
+
+    let width = lines.first().map_or(0, |line| line.len());
+    if width > 1
+        && lines
+            .iter()
+            .all(|line| line.len() == width && line.is_ascii())
+    {
+        for col in 0..width {
+            let narrower = lines
+                .iter()
+                .map(|line| format!("{}{}", &line[..col], &line[col + 1..]))
+                .collect();
+            candidates.push(narrower);
+        }
+    }
+
+    for (index, line) in lines.iter().enumerate() {
+        for range in numbers(line) {
+            let Ok(value) = line[range.clone()].parse::<u128>() else {
+                continue;
+            };
+
+            for smaller in [0, 1, value / 2, value.saturating_sub(1)] {
+                if smaller < value {
+                    let mut changed = lines.to_vec();
+                    changed[index] =
+                        format!("{}{}{}", &line[..range.start], smaller, &line[range.end..]);
+                    candidates.push(changed);
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+// -- Greedily replaces the input by the first smaller candidate that still fails, until
+// -- none does or the attempts run out.
+pub fn shrink<F>(input: &str, fails: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    let mut attempts = 0;
+
+    'shrink: loop {
+        for candidate in candidates(&lines) {
+            attempts += 1;
+            if attempts > MAX_SHRINK_ATTEMPTS {
+                break 'shrink;
+            }
+
+            if fails(&join(&candidate)) {
+                lines = candidate;
+                continue 'shrink;
+            }
+        }
+
+        break;
+    }
+
+    join(&lines)
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: Result<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub cases: usize,
+    pub skipped: usize,
+    pub mismatch: Option<Mismatch>,
+}
+
+// -- Stops at the first failing case, which is shrunk before it is reported.
+pub fn check(day: &Day, reference: &Reference, seeds: Range<u64>, sizes: &[usize]) -> Summary {
+    let mut summary = Summary {
+        year: day.year,
+        day: day.number,
+        part: reference.part,
+        cases: 0,
+        skipped: 0,
+        mismatch: None,
+    };
+
+    for seed in seeds {
+        for size in sizes {
+            let input = (reference.generate)(&mut Rng::new(seed), *size);
+            summary.cases += 1;
+
+            if outcome(reference.slow, &input).is_err() {
+                summary.skipped += 1;
+                continue;
+            }
+
+            if disagreement(reference, &input).is_none() {
+                continue;
+            }
+
+            let input = shrink(&input, |input| disagreement(reference, input).is_some());
+            let (expected, actual) = disagreement(reference, &input).unwrap();
+            summary.mismatch = Some(Mismatch {
+                seed,
+                size: *size,
+                input,
+                expected,
+                actual,
+            });
+            return summary;
+        }
+    }
+
+    summary
+}
+
+pub fn table(summaries: &[Summary]) -> String {
+    let header = ["year", "day", "part", "cases", "skipped", "result"];
+    let rows: Vec<_> = summaries
+        .iter()
+        .map(|summary| {
+            let result = match &summary.mismatch {
+                None => String::from("ok"),
+                Some(mismatch) => {
+                    format!("MISMATCH (seed {}, size {})", mismatch.seed, mismatch.size)
+                }
+            };
+
+            [
+                summary.year.to_string(),
+                format!("{:02}", summary.day),
+                summary.part.to_string(),
+                summary.cases.to_string(),
+                summary.skipped.to_string(),
+                result,
+            ]
+        })
+        .collect();
+
+    table::render(header, &rows)
+}
@@ -0,0 +1,173 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::http;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const CACHE_NAME: &str = ".input-cache";
+const STAMP_NAME: &str = ".last-request";
+const USER_AGENT: &str = "aoc input fetcher (std-only, cached, rate limited)";
+
+// -- Never hit the server more often than this, across every process sharing the cache.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+// -----------------------------------------------------------------------------
+// -- The session token comes from AOC_SESSION, otherwise from the file named by
+// -- AOC_SESSION_FILE, otherwise from ~/.config/aoc/session.
+pub fn session_path() -> Option<PathBuf> {
+    match env::var_os("AOC_SESSION_FILE") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/aoc/session")),
+    }
+}
+
+pub fn find_session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(String::from(session.trim()));
+        }
+    }
+
+    let path = session_path().ok_or("no session token: AOC_SESSION is not set")?;
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(String::from(session.trim())),
+        Ok(_) => Err(format!("session file '{}' is empty", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Err(format!(
+            "no session token: set AOC_SESSION or write it to '{}'",
+            path.display()
+        )),
+        Err(err) => Err(format!("unable to read '{}': {}", path.display(), err)),
+    }
+}
+
+pub fn default_cache_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(CACHE_NAME)
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+    pub interval: Duration,
+}
+
+impl Fetcher {
+    pub fn new(session: String) -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+
+        Self {
+            base_url,
+            session,
+            cache_dir: default_cache_dir(),
+            interval: DEFAULT_INTERVAL,
+        }
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+
+    // -- Returns the cached input when there is one, so a day is downloaded only once.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.cache_path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() == ErrorKind::NotFound => (),
+            Err(err) => return Err(format!("unable to read '{}': {}", path.display(), err)),
+        }
+
+        let input = self.download(year, day)?;
+
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("unable to create '{}': {}", dir.display(), err))?;
+        fs::write(&path, &input)
+            .map_err(|err| format!("unable to write '{}': {}", path.display(), err))?;
+
+        Ok(input)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, String> {
+        self.wait_turn()?;
+
+        let url = self.url(year, day);
+        let cookie = format!("session={}", self.session);
+        let response = http::get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+
+        match response.status {
+            200 if response.body.is_empty() => Err(format!("'{}' returned an empty input", url)),
+            200 => Ok(response.body),
+            404 => Err(format!("day {} of {} is not available (yet)", day, year)),
+            400 | 401 | 403 => Err(format!(
+                "'{}' refused the session token (status {})",
+                url, response.status
+            )),
+            500..=599 => Err(format!(
+                "'{}' had a server error (status {}), try again later",
+                url, response.status
+            )),
+            status => Err(format!("'{}' returned unexpected status {}", url, status)),
+        }
+    }
+
+    // -- The time of the last request is kept in the cache directory, so separate runs
+    // -- respect the interval too.
+    fn wait_turn(&self) -> Result<(), String> {
+        let stamp = self.cache_dir.join(STAMP_NAME);
+
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(Duration::from_millis);
+
+        if let Some(last) = last {
+            let next = last + self.interval;
+            let now = now();
+
+            // -- A stamp from the future (clock changes) never costs more than one interval.
+            if next > now {
+                thread::sleep((next - now).min(self.interval));
+            }
+        }
+
+        write_stamp(&stamp, now())
+    }
+}
+
+fn write_stamp(path: &Path, time: Duration) -> Result<(), String> {
+    let dir = path.parent().unwrap();
+    fs::create_dir_all(dir)
+        .map_err(|err| format!("unable to create '{}': {}", dir.display(), err))?;
+    // -- Rounded up, so the next request never goes out a fraction of a millisecond early.
+    let millis = time.as_nanos().div_ceil(1_000_000);
+    fs::write(path, millis.to_string())
+        .map_err(|err| format!("unable to write '{}': {}", path.display(), err))
+}
@@ -0,0 +1,65 @@
+use common::random::Rng;
+
+use crate::bench::{self, Sample};
+use crate::days::Day;
+use crate::table;
+
+pub const DEFAULT_SIZE: usize = 20;
+pub const DEFAULT_SIZES: [usize; 4] = [10, 20, 40, 80];
+
+// -----------------------------------------------------------------------------
+// -- The same day, size and seed always give the same input.
+pub fn input(day: &Day, size: usize, seed: u64) -> String {
+    (day.generate)(&mut Rng::new(seed), size)
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub size: usize,
+    pub bytes: usize,
+    pub sample: Sample,
+}
+
+// -- Solves a generated input of every size, to see how parse and solve times grow.
+pub fn scale(
+    day: &Day,
+    part: u8,
+    sizes: &[usize],
+    seed: u64,
+    iterations: u32,
+) -> Result<Vec<Point>, String> {
+    sizes
+        .iter()
+        .map(|size| {
+            let input = input(day, *size, seed);
+            let sample = bench::measure(day, part, &input, iterations)
+                .map_err(|err| format!("size {}: {}", size, err))?;
+
+            Ok(Point {
+                size: *size,
+                bytes: input.len(),
+                sample,
+            })
+        })
+        .collect()
+}
+
+pub fn table(points: &[Point]) -> String {
+    let header = ["size", "bytes", "parse", "solve", "total", "answer"];
+    let rows: Vec<_> = points
+        .iter()
+        .map(|point| {
+            [
+                point.size.to_string(),
+                point.bytes.to_string(),
+                bench::format_duration(point.sample.parse),
+                bench::format_duration(point.sample.solve),
+                bench::format_duration(point.sample.total()),
+                point.sample.answer.clone(),
+            ]
+        })
+        .collect();
+
+    table::render(header, &rows)
+}
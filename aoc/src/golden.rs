@@ -0,0 +1,138 @@
+use std::fs;
+use std::path::PathBuf;
+
+use common::{Answer, Error};
+
+use crate::days::Day;
+use crate::table;
+
+const MANIFEST_NAME: &str = "answers.txt";
+
+// -----------------------------------------------------------------------------
+pub struct Case {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub fixture: String,
+    pub expected: String,
+}
+
+impl Case {
+    fn parse(day: &Day, line: &common::Line) -> Result<Self, Error> {
+        let mut components = line.split_whitespace();
+
+        let fixture = line.expect(components.next(), "fixture path")?;
+        let part = line.expect(components.next(), "part")?;
+        let expected = line.expect(components.next(), "expected answer")?;
+
+        if let Some(extra) = components.next() {
+            return Err(line.error_in(extra, "unexpected trailing text"));
+        }
+
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(line.error_in(part, format!("no such part: '{}'", part))),
+        };
+
+        Ok(Self {
+            year: day.year,
+            day: day.number,
+            part,
+            fixture: String::from(fixture),
+            expected: String::from(expected),
+        })
+    }
+}
+
+pub fn manifest_path(day: &Day) -> PathBuf {
+    day.dir().join(MANIFEST_NAME)
+}
+
+pub fn load_cases(day: &Day) -> Result<Vec<Case>, String> {
+    let path = manifest_path(day);
+    let manifest = fs::read_to_string(&path)
+        .map_err(|err| format!("unable to read '{}': {}", path.display(), err))?;
+
+    let mut cases = Vec::new();
+
+    for line in common::lines(&manifest) {
+        // -- Skip blank lines and comments.
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let case = Case::parse(day, &line).map_err(|err| format!("{}: {}", path.display(), err))?;
+        cases.push(case);
+    }
+
+    Ok(cases)
+}
+
+// -----------------------------------------------------------------------------
+pub struct Outcome {
+    pub case: Case,
+    pub actual: Result<Answer, String>,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        match &self.actual {
+            Ok(answer) => answer.to_string() == self.case.expected,
+            Err(_) => false,
+        }
+    }
+
+    fn actual_text(&self) -> String {
+        match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("error: {}", err.lines().next().unwrap_or_default()),
+        }
+    }
+}
+
+pub fn check_day(day: &Day) -> Result<Vec<Outcome>, String> {
+    let mut outcomes = Vec::new();
+
+    for case in load_cases(day)? {
+        let path = day.dir().join(&case.fixture);
+        let solver = day
+            .solver(case.part)
+            .ok_or_else(|| format!("day {:02} has no part {}", day.number, case.part))?;
+
+        let actual = match fs::read_to_string(&path) {
+            Ok(input) => solver(&input).map_err(|err| err.to_string()),
+            Err(err) => Err(format!("unable to read '{}': {}", path.display(), err)),
+        };
+
+        outcomes.push(Outcome { case, actual });
+    }
+
+    Ok(outcomes)
+}
+
+// -----------------------------------------------------------------------------
+pub fn table<'a, I>(outcomes: I) -> String
+where
+    I: IntoIterator<Item = &'a Outcome>,
+{
+    let header = [
+        "year", "day", "part", "fixture", "expected", "actual", "status",
+    ];
+    let rows: Vec<_> = outcomes
+        .into_iter()
+        .map(|outcome| {
+            [
+                outcome.case.year.to_string(),
+                format!("{:02}", outcome.case.day),
+                outcome.case.part.to_string(),
+                outcome.case.fixture.clone(),
+                outcome.case.expected.clone(),
+                outcome.actual_text(),
+                String::from(if outcome.passed() { "ok" } else { "MISMATCH" }),
+            ]
+        })
+        .collect();
+
+    table::render(header, &rows)
+}
@@ -0,0 +1,198 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+// -----------------------------------------------------------------------------
+struct Url<'a> {
+    host: &'a str,
+    port: u16,
+    path: &'a str,
+}
+
+fn parse_http_url(url: &str) -> Result<Url<'_>, String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("unsupported url '{}'", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse()
+                .map_err(|_| format!("invalid port in url '{}'", url))?;
+            (host, port)
+        }
+        None => (authority, 80),
+    };
+
+    if host.is_empty() {
+        return Err(format!("missing host in url '{}'", url));
+    }
+
+    Ok(Url { host, port, path })
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoded = Vec::new();
+
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or("truncated chunk header")?;
+        let size = String::from_utf8_lossy(&body[..line_end]);
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| format!("invalid chunk size '{}'", size))?;
+
+        body = &body[line_end + 2..];
+
+        if size == 0 {
+            return Ok(decoded);
+        }
+
+        let chunk = body.get(..size).ok_or("truncated chunk")?;
+        decoded.extend_from_slice(chunk);
+        body = body.get(size + 2..).ok_or("truncated chunk")?;
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let header_end = raw
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or("malformed response: no end of headers")?;
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let body = &raw[header_end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or("malformed response: bad status line")?;
+
+    let mut chunked = false;
+    let mut length = None;
+
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            length = value.parse::<usize>().ok();
+        }
+    }
+
+    let body = match (chunked, length) {
+        (true, _) => decode_chunked(body)?,
+        (false, Some(length)) => body
+            .get(..length)
+            .ok_or("malformed response: body shorter than its content length")?
+            .to_vec(),
+        (false, None) => body.to_vec(),
+    };
+
+    let body = String::from_utf8(body).map_err(|_| "response body is not valid UTF-8")?;
+    Ok(Response { status, body })
+}
+
+fn get_http(url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    let Url { host, port, path } = parse_http_url(url)?;
+
+    let mut stream = TcpStream::connect((host, port))
+        .map_err(|err| format!("unable to connect to {}:{}: {}", host, port, err))?;
+    stream.set_read_timeout(Some(TIMEOUT)).ok();
+    stream.set_write_timeout(Some(TIMEOUT)).ok();
+
+    let mut request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        path, host
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+
+    let io_error = |err: std::io::Error| format!("request to '{}' failed: {}", url, err);
+    stream.write_all(request.as_bytes()).map_err(io_error)?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(io_error)?;
+
+    parse_response(&raw).map_err(|err| format!("'{}': {}", url, err))
+}
+
+// -- std has no TLS, so https goes through curl. The headers are passed on stdin to keep
+// -- the session cookie out of the process list.
+fn get_https(url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--http1.1", "--header", "@-"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("unable to run curl for https requests: {}", err))?;
+
+    let header_lines: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}\n", name, value))
+        .collect();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(header_lines.as_bytes())
+        .map_err(|err| format!("unable to pass headers to curl: {}", err))?;
+
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("curl failed: {}", err))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "request to '{}' failed: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout =
+        String::from_utf8(output.stdout).map_err(|_| "response body is not valid UTF-8")?;
+    let (body, status) = stdout.rsplit_once('\n').ok_or("malformed curl output")?;
+    let status = status
+        .parse()
+        .map_err(|_| format!("malformed status from curl: '{}'", status))?;
+
+    Ok(Response {
+        status,
+        body: String::from(body),
+    })
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    if url.starts_with("https://") {
+        get_https(url, headers)
+    } else {
+        get_http(url, headers)
+    }
+}
@@ -0,0 +1,265 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use common::json::Value;
+use common::Answer;
+
+use crate::days::UNDATED_YEAR;
+use crate::table;
+
+const LEDGER_NAME: &str = "answer-ledger.json";
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    Rejected,
+    TooHigh,
+    TooLow,
+}
+
+use Verdict::*;
+
+impl Verdict {
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match arg {
+            "accepted" => Ok(Accepted),
+            "rejected" => Ok(Rejected),
+            "too-high" => Ok(TooHigh),
+            "too-low" => Ok(TooLow),
+            _ => Err(format!(
+                "unknown verdict '{}', expected 'accepted', 'rejected', 'too-high' or 'too-low'",
+                arg
+            )),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Accepted => "accepted",
+            Rejected => "rejected",
+            TooHigh => "too-high",
+            TooLow => "too-low",
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: i128,
+    pub verdict: Verdict,
+}
+
+impl Entry {
+    fn is_for(&self, year: u16, day: u8, part: u8, input_hash: &str) -> bool {
+        self.year == year && self.day == day && self.part == part && self.input_hash == input_hash
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Object(vec![
+            (String::from("year"), self.year.into()),
+            (String::from("day"), self.day.into()),
+            (String::from("part"), self.part.into()),
+            (String::from("input_hash"), self.input_hash.as_str().into()),
+            (String::from("answer"), Value::Integer(self.answer)),
+            (String::from("verdict"), self.verdict.label().into()),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let field = |name| value.get(name).and_then(Value::as_u64);
+
+        Some(Self {
+            year: match value.get("year") {
+                Some(_) => field("year")?.try_into().ok()?,
+                None => UNDATED_YEAR,
+            },
+            day: field("day")?.try_into().ok()?,
+            part: field("part")?.try_into().ok()?,
+            input_hash: String::from(value.get("input_hash")?.as_str()?),
+            answer: value.get("answer")?.as_i128()?,
+            verdict: Verdict::from_arg(value.get("verdict")?.as_str()?).ok()?,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Unknown,
+    Accepted,
+    Wrong(String),
+}
+
+pub fn answer_value(answer: &Answer) -> i128 {
+    match answer {
+        Answer::Unsigned(value) => i128::from(*value),
+        Answer::Signed(value) => i128::from(*value),
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(LEDGER_NAME)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let value = Value::parse(text)?;
+        let entries = value
+            .get("entries")
+            .and_then(Value::as_array)
+            .ok_or("missing 'entries' array")?;
+
+        let entries = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                Entry::from_json(entry).ok_or(format!("malformed entry #{}", index + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    // -- A missing ledger file is simply an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("'{}': {}", path.display(), err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("unable to read '{}': {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, format!("{}\n", self))
+            .map_err(|err| format!("unable to write '{}': {}", path.display(), err))
+    }
+
+    pub fn entries_for<'a>(
+        &'a self,
+        year: u16,
+        day: u8,
+        part: u8,
+        input_hash: &'a str,
+    ) -> impl Iterator<Item = &'a Entry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.is_for(year, day, part, input_hash))
+    }
+
+    // -- Recording an answer again replaces its earlier verdict. Only one answer per input
+    // -- can be accepted, and an accepted answer cannot contradict the recorded hints.
+    pub fn record(&mut self, entry: Entry) -> Result<(), String> {
+        let (year, day, part) = (entry.year, entry.day, entry.part);
+        let hash = entry.input_hash.as_str();
+
+        if entry.verdict == Accepted {
+            let others = self
+                .entries_for(year, day, part, hash)
+                .filter(|other| other.answer != entry.answer);
+
+            if let Status::Wrong(reason) = judge(others, entry.answer) {
+                return Err(format!("cannot accept {}: {}", entry.answer, reason));
+            }
+        }
+
+        self.entries
+            .retain(|other| !(other.is_for(year, day, part, hash) && other.answer == entry.answer));
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn status(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        answer: &Answer,
+    ) -> Status {
+        judge(
+            self.entries_for(year, day, part, input_hash),
+            answer_value(answer),
+        )
+    }
+}
+
+fn judge<'a, I>(entries: I, answer: i128) -> Status
+where
+    I: Iterator<Item = &'a Entry>,
+{
+    let mut status = Status::Unknown;
+
+    for entry in entries {
+        let reason = match entry.verdict {
+            Accepted if entry.answer == answer => {
+                status = Status::Accepted;
+                continue;
+            }
+            Accepted => format!("the accepted answer is {}", entry.answer),
+            Rejected if entry.answer == answer => String::from("already rejected"),
+            TooHigh if answer >= entry.answer => {
+                format!("{} was already too high", entry.answer)
+            }
+            TooLow if answer <= entry.answer => {
+                format!("{} was already too low", entry.answer)
+            }
+            Rejected | TooHigh | TooLow => continue,
+        };
+
+        return Status::Wrong(reason);
+    }
+
+    status
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // -- One entry per line keeps the file readable and its diffs small.
+        writeln!(f, "{{\"entries\":[")?;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let separator = if index + 1 < self.entries.len() {
+                ","
+            } else {
+                ""
+            };
+            writeln!(f, "{}{}", entry.to_json(), separator)?;
+        }
+
+        write!(f, "]}}")
+    }
+}
+
+pub fn table(entries: &[&Entry]) -> String {
+    let header = ["year", "day", "part", "input", "answer", "verdict"];
+    let rows: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.year.to_string(),
+                format!("{:02}", entry.day),
+                entry.part.to_string(),
+                entry.input_hash.clone(),
+                entry.answer.to_string(),
+                String::from(entry.verdict.label()),
+            ]
+        })
+        .collect();
+
+    table::render(header, &rows)
+}
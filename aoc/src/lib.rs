@@ -0,0 +1,11 @@
+pub mod bench;
+pub mod days;
+pub mod differential;
+pub mod fetch;
+pub mod generate;
+pub mod golden;
+pub mod http;
+pub mod ledger;
+pub mod scaffold;
+pub mod stats;
+pub mod table;
@@ -0,0 +1,1268 @@
+use std::env;
+use std::fs;
+use std::io::{stdin, stdout, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::bench::{self, History, Run, Trend};
+use aoc::days::{self, Day, DAYS};
+use aoc::differential;
+use aoc::fetch::{self, Fetcher};
+use aoc::generate;
+use aoc::golden;
+use aoc::ledger::{self, Entry, Ledger, Status, Verdict};
+use aoc::scaffold;
+use aoc::stats;
+use common::cli::Source;
+use common::json::Value;
+use common::pool::Pool;
+use common::report::{self, Format, Report};
+use common::stats::CountingAllocator;
+use common::stepper::Session;
+
+const USAGE: &str = "\
+usage: aoc <command> [options]
+
+commands:
+  list                                        list every available day of every year
+  run --day N [--part P] [--input PATH]       solve a single day ('-' reads stdin)
+  run --all [--jobs N]                        solve every day with its bundled input, N days
+                                              at a time (all cores by default)
+      [--year Y]                              pick the year of '--day' (the latest by default)
+                                              or limit '--all' to one year
+      [--format text|json]                    print answers or one JSON object per part
+      [--ledger PATH]                         warn about answers the ledger knows are wrong
+      [--stats]                               report time, allocations, peak memory and solver
+                                              counters per phase (one day at a time)
+  check [--year Y] [--day N]                  compare answers against each day's answers.txt
+  bench [--year Y] [--day N] [--part P]       time parse and solve for every day and part
+        [--runs N] [--threshold PCT]
+        [--history PATH] [--no-save]
+  ledger show [--year Y] [--day N]            list recorded answers and their verdicts
+  ledger record --day N --part P              record a verdict for an answer ('--answer'
+         --verdict V [--answer A]             defaults to the computed one); V is one of
+         [--input PATH]                       accepted, rejected, too-high or too-low
+  ledger ... [--year Y] [--ledger PATH]       pick the year, or use another ledger file
+  fetch --day N [--year Y] [--output PATH]    download a puzzle input into the day's input.txt
+        [--force] [--base-url URL]            ('-' prints it); needs AOC_SESSION or a session
+        [--cache DIR]                         file, and caches every download
+  generate --day N [--size S] [--seed X]      print a random, valid input for a day
+           [--year Y] [--output PATH]
+  scale --day N [--part P] [--seed X]         time a day on generated inputs of growing size
+        [--year Y] [--sizes A,B,...] [--runs N]
+  diff [--year Y] [--day N] [--part P]        compare solvers with their brute-force references
+       [--seed X] [--seeds N] [--sizes A,B,...]
+                                              on generated inputs, shrinking any mismatch
+  step --day N [--year Y] [--input PATH]      step through a simulation one tick at a time,
+                                              reading commands from stdin (days 14, 16, 20
+                                              and 21)
+  explain --day N [--part P] [--year Y]       show the digits or tokens each input line was
+          [--input PATH]                      read as and the value they make (day 1)
+  query --day N [--year Y] [--input PATH]     answer a question about the games, e.g. 'games
+        <query>                               where any round blue > 10 | count' (day 2)
+  new [--year Y] <day> <title>                create and register the crate for a new day under
+                                              its year's directory";
+
+// -- Allocations are only counted while `run --stats` is collecting.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// -----------------------------------------------------------------------------
+enum Selection {
+    All(Vec<&'static Day>),
+    Single {
+        day: &'static Day,
+        part: Option<u8>,
+        input: Option<Source>,
+    },
+}
+
+struct RunOptions {
+    selection: Selection,
+    format: Format,
+    ledger: PathBuf,
+    pool: Pool,
+    stats: bool,
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("missing value for '{}'", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for '{}': '{}'", flag, value))
+}
+
+fn parse_year(flag: &str, value: Option<String>) -> Result<u16, String> {
+    let value = value.ok_or_else(|| format!("missing value for '{}'", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for '{}': '{}'", flag, value))
+}
+
+// -- A day without a year is one of the latest year's.
+fn find_day(year: Option<u16>, number: u8) -> Result<&'static Day, String> {
+    let year = year.unwrap_or_else(days::latest_year);
+    days::find(year, number).ok_or_else(|| format!("no such day: {} of {}", number, year))
+}
+
+fn find_days(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    if let Some(number) = day {
+        return Ok(vec![find_day(year, number)?]);
+    }
+
+    let days = days::of_year(year);
+    match year {
+        Some(year) if days.is_empty() => Err(format!("no days for {}", year)),
+        _ => Ok(days),
+    }
+}
+
+fn parse_run_args<I>(mut args: I) -> Result<RunOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut all = false;
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut ledger = Ledger::default_path();
+    let mut pool = Pool::available();
+    let mut stats = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--jobs" => match parse_number(&arg, args.next())? {
+                0 => return Err(String::from("'--jobs' must be at least 1")),
+                jobs => pool = Pool::new(usize::from(jobs)),
+            },
+            "--stats" => stats = true,
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--part" => part = Some(parse_number(&arg, args.next())?),
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))?;
+                input = Some(Source::from_arg(&path));
+            }
+            "--format" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))?;
+                format = Format::from_arg(&value)?;
+            }
+            "--ledger" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))?;
+                ledger = PathBuf::from(path);
+            }
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    let selection = match (all, day) {
+        (true, None) => {
+            if part.is_some() || input.is_some() {
+                return Err(String::from(
+                    "'--all' cannot be combined with other options",
+                ));
+            }
+            Selection::All(find_days(year, None)?)
+        }
+        (false, Some(number)) => {
+            let day = find_day(year, number)?;
+
+            if let Some(part) = part {
+                day.solver(part)
+                    .ok_or_else(|| format!("no such part: {}", part))?;
+            }
+
+            Selection::Single { day, part, input }
+        }
+        (true, Some(_)) => return Err(String::from("'--all' and '--day' are mutually exclusive")),
+        (false, None) => return Err(String::from("either '--day' or '--all' is required")),
+    };
+
+    Ok(RunOptions {
+        selection,
+        format,
+        ledger,
+        pool,
+        stats,
+    })
+}
+
+// -----------------------------------------------------------------------------
+fn solve_day(day: &Day, parts: &[u8], input: Option<Source>) -> Result<Vec<Report>, String> {
+    let input = input
+        .unwrap_or_else(|| Source::File(day.input_path()))
+        .read()?;
+
+    Ok(parts
+        .iter()
+        .map(|part| Report::run(&day.part(*part).unwrap(), &input))
+        .collect())
+}
+
+fn print_reports(
+    day: &Day,
+    reports: &[Report],
+    format: Format,
+    ledger: &Ledger,
+) -> Result<(), String> {
+    let mut failed = false;
+
+    for report in reports {
+        let part = report.part;
+
+        let status = match &report.outcome {
+            Ok(timing) => ledger.status(
+                day.year,
+                day.number,
+                part,
+                &report.input_hash,
+                &timing.answer,
+            ),
+            Err(_) => Status::Unknown,
+        };
+
+        if let Status::Wrong(reason) = &status {
+            eprintln!(
+                "warning: {} day {:02} part {}: the ledger says this answer is wrong ({})",
+                day.year, day.number, part, reason
+            );
+        }
+
+        match (format, &report.outcome) {
+            (Format::Json, _) => {
+                let mut json = report.to_json();
+
+                if let Value::Object(fields) = &mut json {
+                    let label = match status {
+                        Status::Unknown => "unknown",
+                        Status::Accepted => "accepted",
+                        Status::Wrong(_) => "wrong",
+                    };
+                    fields.push((String::from("ledger"), label.into()));
+                }
+
+                println!("{}", json);
+            }
+            (Format::Text, Ok(timing)) => {
+                let note = if status == Status::Accepted {
+                    " (accepted)"
+                } else {
+                    ""
+                };
+                println!(
+                    "{} day {:02} part {}: {}{}",
+                    day.year, day.number, part, timing.answer, note
+                )
+            }
+            (Format::Text, Err(err)) => {
+                println!(
+                    "{} day {:02} part {}: error: {}",
+                    day.year, day.number, part, err
+                )
+            }
+        }
+
+        failed |= report.outcome.is_err();
+    }
+
+    if format == Format::Text && reports.iter().any(|report| report.stats.is_some()) {
+        print!("\n{}\n", stats::render(day.year, day.number, reports));
+    }
+
+    if failed {
+        return Err(format!("{} day {:02} failed", day.year, day.number));
+    }
+
+    Ok(())
+}
+
+fn run(options: RunOptions) -> Result<(), String> {
+    let format = options.format;
+    let ledger = Ledger::load(&options.ledger)?;
+
+    // -- The counters are process-wide, so days can't overlap while they're collected.
+    let pool = if options.stats {
+        common::stats::start();
+        Pool::new(1)
+    } else {
+        options.pool
+    };
+
+    match options.selection {
+        Selection::All(days) => {
+            let mut num_failed = 0;
+
+            // -- Days are solved in parallel, but reported in order once all are done.
+            let solved = pool.map(&days, |day| solve_day(day, &[1, 2], None));
+
+            for (day, reports) in days.into_iter().zip(solved) {
+                let printed =
+                    reports.and_then(|reports| print_reports(day, &reports, format, &ledger));
+
+                if let Err(err) = printed {
+                    eprintln!("error: {}", err);
+                    num_failed += 1;
+                }
+            }
+
+            if num_failed > 0 {
+                return Err(format!("{} day(s) failed", num_failed));
+            }
+        }
+        Selection::Single { day, part, input } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            print_reports(day, &solve_day(day, &parts, input)?, format, &ledger)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_check_args<I>(mut args: I) -> Result<Vec<&'static Day>, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut year = None;
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    find_days(year, day)
+}
+
+fn check(days: Vec<&'static Day>) -> Result<(), String> {
+    let mut outcomes = Vec::new();
+
+    for checked in Pool::available().map(&days, |day| golden::check_day(day)) {
+        outcomes.extend(checked?);
+    }
+
+    print!("{}", golden::table(&outcomes));
+
+    let num_failed = outcomes.iter().filter(|outcome| !outcome.passed()).count();
+
+    if num_failed > 0 {
+        return Err(format!(
+            "{} of {} case(s) did not match",
+            num_failed,
+            outcomes.len()
+        ));
+    }
+
+    println!("all {} case(s) match", outcomes.len());
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+struct BenchOptions {
+    days: Vec<&'static Day>,
+    part: Option<u8>,
+    iterations: u32,
+    threshold: f64,
+    history: PathBuf,
+    save: bool,
+}
+
+fn parse_bench_args<I>(mut args: I) -> Result<BenchOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day) = (None, None);
+    let mut options = BenchOptions {
+        days: Vec::new(),
+        part: None,
+        iterations: 5,
+        threshold: 0.1,
+        history: History::default_path(),
+        save: true,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--part" => match parse_number(&arg, args.next())? {
+                part @ (1 | 2) => options.part = Some(part),
+                part => return Err(format!("no such part: {}", part)),
+            },
+            "--runs" => match parse_number(&arg, args.next())? {
+                0 => return Err(String::from("'--runs' must be at least 1")),
+                runs => options.iterations = u32::from(runs),
+            },
+            "--threshold" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))?;
+                let percent: f64 = value
+                    .parse()
+                    .ok()
+                    .filter(|percent: &f64| *percent >= 0.0)
+                    .ok_or_else(|| format!("invalid value for '{}': '{}'", arg, value))?;
+                options.threshold = percent / 100.0;
+            }
+            "--history" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))?;
+                options.history = PathBuf::from(path);
+            }
+            "--no-save" => options.save = false,
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    options.days = find_days(year, day)?;
+    Ok(options)
+}
+
+fn bench(options: BenchOptions) -> Result<(), String> {
+    let history = History::load(&options.history)?;
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut samples = Vec::new();
+
+    for day in options.days {
+        let path = day.input_path();
+        let input = fs::read_to_string(&path)
+            .map_err(|err| format!("unable to read '{}': {}", path.display(), err))?;
+
+        for part in parts.iter() {
+            samples.push(bench::measure(day, *part, &input, options.iterations)?);
+        }
+    }
+
+    print!("{}", bench::table(&samples, &history, options.threshold));
+
+    let num_regressions = samples
+        .iter()
+        .filter(|sample| {
+            let previous = history.previous(sample.year, sample.day, sample.part);
+            Trend::between(previous, sample, options.threshold) == Trend::Slower
+        })
+        .count();
+
+    if num_regressions > 0 {
+        println!(
+            "{} regression(s) of more than {}% against the previous run",
+            num_regressions,
+            options.threshold * 100.0
+        );
+    }
+
+    if options.save {
+        let mut history = history;
+        history.runs.push(Run::new(options.iterations, samples));
+        history.save(&options.history)?;
+        println!("saved to '{}'", options.history.display());
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+enum LedgerCommand {
+    Show {
+        year: Option<u16>,
+        day: Option<u8>,
+    },
+    Record {
+        day: &'static Day,
+        part: u8,
+        verdict: Verdict,
+        answer: Option<i128>,
+        input: Option<Source>,
+    },
+}
+
+fn parse_ledger_args<I>(mut args: I) -> Result<(LedgerCommand, PathBuf), String>
+where
+    I: Iterator<Item = String>,
+{
+    let action = args.next().ok_or("missing ledger action")?;
+
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut verdict = None;
+    let mut answer = None;
+    let mut input = None;
+    let mut path = Ledger::default_path();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--part" => part = Some(parse_number(&arg, Some(value()?))?),
+            "--verdict" => verdict = Some(Verdict::from_arg(&value()?)?),
+            "--answer" => {
+                let value = value()?;
+                let parsed = value
+                    .parse()
+                    .map_err(|_| format!("invalid value for '{}': '{}'", arg, value))?;
+                answer = Some(parsed);
+            }
+            "--input" => input = Some(Source::from_arg(&value()?)),
+            "--ledger" => path = PathBuf::from(value()?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    let command = match action.as_str() {
+        "show" => LedgerCommand::Show { year, day },
+        "record" => {
+            let day = find_day(year, day.ok_or("'--day' is required")?)?;
+            let part = part.ok_or("'--part' is required")?;
+            day.part(part)
+                .ok_or_else(|| format!("no such part: {}", part))?;
+            let verdict = verdict.ok_or("'--verdict' is required")?;
+
+            LedgerCommand::Record {
+                day,
+                part,
+                verdict,
+                answer,
+                input,
+            }
+        }
+        _ => return Err(format!("unknown ledger action '{}'", action)),
+    };
+
+    Ok((command, path))
+}
+
+fn ledger(command: LedgerCommand, path: PathBuf) -> Result<(), String> {
+    let mut ledger = Ledger::load(&path)?;
+
+    match command {
+        LedgerCommand::Show { year, day } => {
+            let mut entries: Vec<_> = ledger
+                .entries
+                .iter()
+                .filter(|entry| year.is_none_or(|year| entry.year == year))
+                .filter(|entry| day.is_none_or(|day| entry.day == day))
+                .collect();
+            entries.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+            print!("{}", ledger::table(&entries));
+        }
+        LedgerCommand::Record {
+            day,
+            part,
+            verdict,
+            answer,
+            input,
+        } => {
+            let input = input
+                .unwrap_or_else(|| Source::File(day.input_path()))
+                .read()?;
+
+            // -- Without an explicit answer, record whatever the solver currently produces.
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let report = Report::run(&day.part(part).unwrap(), &input);
+                    let timing = report
+                        .outcome
+                        .map_err(|err| format!("day {:02} part {}: {}", day.number, part, err))?;
+                    ledger::answer_value(&timing.answer)
+                }
+            };
+
+            ledger.record(Entry {
+                year: day.year,
+                day: day.number,
+                part,
+                input_hash: report::input_hash(&input),
+                answer,
+                verdict,
+            })?;
+            ledger.save(&path)?;
+
+            println!(
+                "day {:02} part {}: recorded {} as {}",
+                day.number,
+                part,
+                answer,
+                verdict.label()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+// -- Where a fetched input goes when not into the day's input.txt.
+enum Destination {
+    File(PathBuf),
+    Stdout,
+}
+
+impl Destination {
+    // -- A path of '-' stands for stdout.
+    fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Destination::Stdout,
+            path => Destination::File(PathBuf::from(path)),
+        }
+    }
+}
+
+struct FetchOptions {
+    day: u8,
+    year: u16,
+    output: Option<Destination>,
+    force: bool,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+}
+
+fn parse_fetch_args<I>(mut args: I) -> Result<FetchOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut options = FetchOptions {
+        day: 0,
+        year: days::latest_year(),
+        output: None,
+        force: false,
+        base_url: None,
+        cache_dir: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--day" => match parse_number(&arg, Some(value()?))? {
+                day_number @ 1..=25 => day = Some(day_number),
+                day_number => return Err(format!("no such day: {}", day_number)),
+            },
+            "--year" => options.year = parse_year(&arg, Some(value()?))?,
+            "--output" => options.output = Some(Destination::from_arg(&value()?)),
+            "--force" => options.force = true,
+            "--base-url" => options.base_url = Some(value()?),
+            "--cache" => options.cache_dir = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    options.day = day.ok_or("'--day' is required")?;
+
+    // -- Only registered days have a crate to install the input into.
+    if options.output.is_none() && days::find(options.year, options.day).is_none() {
+        return Err(format!(
+            "day {} of {} has no crate here, pass '--output'",
+            options.day, options.year
+        ));
+    }
+
+    Ok(options)
+}
+
+fn fetch_input(options: FetchOptions) -> Result<(), String> {
+    let mut fetcher = Fetcher::new(fetch::find_session()?);
+
+    if let Some(base_url) = options.base_url {
+        fetcher.base_url = base_url;
+    }
+    if let Some(cache_dir) = options.cache_dir {
+        fetcher.cache_dir = cache_dir;
+    }
+
+    let input = fetcher.fetch(options.year, options.day)?;
+
+    let path = match options.output {
+        Some(Destination::Stdout) => {
+            print!("{}", input);
+            return Ok(());
+        }
+        Some(Destination::File(path)) => path,
+        None => days::find(options.year, options.day).unwrap().input_path(),
+    };
+
+    // -- Never clobber a different input unless asked to.
+    match fs::read_to_string(&path) {
+        Ok(existing) if existing == input => {
+            println!("'{}' is already up to date", path.display());
+            return Ok(());
+        }
+        Ok(_) if !options.force => {
+            return Err(format!(
+                "'{}' already exists with different contents, pass '--force' to replace it",
+                path.display()
+            ))
+        }
+        _ => (),
+    }
+
+    fs::write(&path, input)
+        .map_err(|err| format!("unable to write '{}': {}", path.display(), err))?;
+    println!("wrote '{}'", path.display());
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+struct GenerateOptions {
+    day: &'static Day,
+    part: Option<u8>,
+    sizes: Vec<usize>,
+    seed: u64,
+    iterations: u32,
+    output: Option<PathBuf>,
+}
+
+fn parse_size(flag: &str, value: &str) -> Result<usize, String> {
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|size| *size > 0)
+        .ok_or_else(|| format!("invalid value for '{}': '{}'", flag, value))
+}
+
+// -- 'generate' takes a single '--size', 'scale' a comma separated '--sizes'.
+fn parse_generate_args<I>(mut args: I, sizes: Vec<usize>) -> Result<GenerateOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day) = (None, None);
+    let mut options = GenerateOptions {
+        day: &DAYS[0],
+        part: None,
+        sizes,
+        seed: 0,
+        iterations: 1,
+        output: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--part" => match parse_number(&arg, Some(value()?))? {
+                part @ (1 | 2) => options.part = Some(part),
+                part => return Err(format!("no such part: {}", part)),
+            },
+            "--size" => options.sizes = vec![parse_size(&arg, &value()?)?],
+            "--sizes" => {
+                options.sizes = value()?
+                    .split(',')
+                    .map(|size| parse_size(&arg, size))
+                    .collect::<Result<_, _>>()?;
+            }
+            "--seed" => {
+                let value = value()?;
+                options.seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid value for '{}': '{}'", arg, value))?;
+            }
+            "--runs" => match parse_number(&arg, Some(value()?))? {
+                0 => return Err(String::from("'--runs' must be at least 1")),
+                runs => options.iterations = u32::from(runs),
+            },
+            "--output" => options.output = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    options.day = find_day(year, day.ok_or("'--day' is required")?)?;
+    Ok(options)
+}
+
+fn generate_input(options: GenerateOptions) -> Result<(), String> {
+    let input = generate::input(options.day, options.sizes[0], options.seed);
+
+    match options.output {
+        Some(path) => fs::write(&path, input)
+            .map_err(|err| format!("unable to write '{}': {}", path.display(), err)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn scale(options: GenerateOptions) -> Result<(), String> {
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for (index, part) in parts.into_iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        let points = generate::scale(
+            options.day,
+            part,
+            &options.sizes,
+            options.seed,
+            options.iterations,
+        )?;
+
+        println!(
+            "day {:02} part {} (seed {})",
+            options.day.number, part, options.seed
+        );
+        print!("{}", generate::table(&points));
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+struct DiffOptions {
+    days: Vec<&'static Day>,
+    part: Option<u8>,
+    seeds: std::ops::Range<u64>,
+    sizes: Vec<usize>,
+}
+
+fn parse_diff_args<I>(mut args: I) -> Result<DiffOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day) = (None, None);
+    let mut options = DiffOptions {
+        days: Vec::new(),
+        part: None,
+        seeds: 0..differential::DEFAULT_SEEDS,
+        sizes: differential::DEFAULT_SIZES.to_vec(),
+    };
+    let (mut first, mut count) = (0, differential::DEFAULT_SEEDS);
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--part" => match parse_number(&arg, Some(value()?))? {
+                part @ (1 | 2) => options.part = Some(part),
+                part => return Err(format!("no such part: {}", part)),
+            },
+            "--seed" | "--seeds" => {
+                let value = value()?;
+                let number = value
+                    .parse()
+                    .map_err(|_| format!("invalid value for '{}': '{}'", arg, value))?;
+
+                match arg.as_str() {
+                    "--seed" => first = number,
+                    _ => count = number,
+                }
+            }
+            "--sizes" => {
+                options.sizes = value()?
+                    .split(',')
+                    .map(|size| parse_size(&arg, size))
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    options.seeds = first..first.saturating_add(count);
+    options.days = find_days(year, day)?;
+    Ok(options)
+}
+
+fn diff(options: DiffOptions) -> Result<(), String> {
+    // -- Shrinking provokes the same panic over and over; the summary reports it once.
+    std::panic::set_hook(Box::new(|_| ()));
+
+    let mut summaries = Vec::new();
+
+    for day in options.days {
+        for reference in day.references {
+            if options.part.is_some_and(|part| part != reference.part) {
+                continue;
+            }
+
+            summaries.push(differential::check(
+                day,
+                reference,
+                options.seeds.clone(),
+                &options.sizes,
+            ));
+        }
+    }
+
+    let _ = std::panic::take_hook();
+
+    if summaries.is_empty() {
+        return Err(String::from("no reference solvers for that selection"));
+    }
+
+    print!("{}", differential::table(&summaries));
+
+    let mismatches: Vec<_> = summaries
+        .iter()
+        .filter_map(|summary| Some((summary, summary.mismatch.as_ref()?)))
+        .collect();
+
+    for (summary, mismatch) in mismatches.iter() {
+        let actual = match &mismatch.actual {
+            Ok(answer) => answer.clone(),
+            Err(err) => format!("error: {}", err),
+        };
+
+        println!(
+            "\nday {:02} part {}: reference {}, solver {}, on the shrunk input:\n{}",
+            summary.day, summary.part, mismatch.expected, actual, mismatch.input
+        );
+    }
+
+    if !mismatches.is_empty() {
+        return Err(format!(
+            "{} of {} solver(s) disagree with their reference",
+            mismatches.len(),
+            summaries.len()
+        ));
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+fn parse_step_args<I>(mut args: I) -> Result<(&'static Day, Option<PathBuf>), String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day) = (None, None);
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--input" => match Source::from_arg(&value()?) {
+                Source::Stdin => return Err(String::from("stdin is taken by the commands")),
+                Source::File(path) => input = Some(path),
+            },
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    let day = find_day(year, day.ok_or("'--day' is required")?)?;
+
+    if day.stepper.is_none() {
+        return Err(format!(
+            "day {} of {} has no simulation to step through",
+            day.number, day.year
+        ));
+    }
+
+    Ok((day, input))
+}
+
+// -- The screen is redrawn at every step when both ends are a terminal; piped commands
+// -- get a plain transcript instead.
+fn step(day: &Day, input: Option<PathBuf>) -> Result<(), String> {
+    let input = Source::File(input.unwrap_or_else(|| day.input_path())).read()?;
+    let start = (day.stepper.unwrap())(&input).map_err(|err| err.to_string())?;
+
+    let interactive = stdin().is_terminal() && stdout().is_terminal();
+    let session = Session {
+        interactive,
+        color: interactive && env::var_os("NO_COLOR").is_none(),
+    };
+
+    session
+        .run(start, stdin().lock(), &mut stdout().lock())
+        .map_err(|err| format!("unable to step through day {:02}: {}", day.number, err))
+}
+
+// -----------------------------------------------------------------------------
+struct ExplainOptions {
+    day: &'static Day,
+    part: Option<u8>,
+    input: Option<Source>,
+}
+
+fn parse_explain_args<I>(mut args: I) -> Result<ExplainOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day, mut part) = (None, None, None);
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--part" => match parse_number(&arg, Some(value()?))? {
+                number @ (1 | 2) => part = Some(number),
+                number => return Err(format!("no such part: {}", number)),
+            },
+            "--input" => input = Some(Source::from_arg(&value()?)),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    let day = find_day(year, day.ok_or("'--day' is required")?)?;
+
+    if day.explain.is_none() {
+        return Err(format!(
+            "day {} of {} has nothing to explain",
+            day.number, day.year
+        ));
+    }
+
+    Ok(ExplainOptions { day, part, input })
+}
+
+fn explain(options: ExplainOptions) -> Result<(), String> {
+    let day = options.day;
+    let source = options
+        .input
+        .unwrap_or_else(|| Source::File(day.input_path()));
+    let input = source.read()?;
+    let explain = day.explain.unwrap();
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for (index, part) in parts.into_iter().enumerate() {
+        let report = explain(&input, part).map_err(|err| err.to_string())?;
+
+        if index > 0 {
+            println!();
+        }
+        println!("day {:02} part {}", day.number, part);
+        print!("{}", report);
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+struct QueryOptions {
+    day: &'static Day,
+    input: Option<Source>,
+    query: String,
+}
+
+fn parse_query_args<I>(mut args: I) -> Result<QueryOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let (mut year, mut day) = (None, None);
+    let mut input = None;
+    let mut query = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, Some(value()?))?),
+            "--day" => day = Some(parse_number(&arg, Some(value()?))?),
+            "--input" => input = Some(Source::from_arg(&value()?)),
+            _ if arg.starts_with("--") => return Err(format!("unknown argument '{}'", arg)),
+            _ if query.is_some() => return Err(format!("unexpected argument '{}'", arg)),
+            _ => query = Some(arg),
+        }
+    }
+
+    let day = find_day(year, day.ok_or("'--day' is required")?)?;
+    let query = query.ok_or("missing value for '<query>'")?;
+
+    if day.query.is_none() {
+        return Err(format!(
+            "day {} of {} has no query language",
+            day.number, day.year
+        ));
+    }
+
+    Ok(QueryOptions { day, input, query })
+}
+
+fn query(options: QueryOptions) -> Result<(), String> {
+    let day = options.day;
+    let source = options
+        .input
+        .unwrap_or_else(|| Source::File(day.input_path()));
+    let input = source.read()?;
+
+    let output = (day.query.unwrap())(&input, &options.query).map_err(|err| err.to_string())?;
+    print!("{}", output);
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+fn parse_new_args<I>(mut args: I) -> Result<(u16, u8, String), String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut year = days::latest_year();
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&arg, args.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let day = parse_number("<day>", positional.next())?;
+    let title = positional.next().ok_or("missing value for '<title>'")?;
+
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+
+    Ok((year, day, title))
+}
+
+fn new_day(year: u16, day: u8, title: &str) -> Result<(), String> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let dir = scaffold::create(&root, year, day, title)?;
+
+    println!("created '{}'", dir.display());
+    println!(
+        "next: 'aoc fetch --year {} --day {}', then add the example to fixtures/ and answers.txt",
+        year, day
+    );
+    Ok(())
+}
+
+fn list() {
+    for day in DAYS.iter() {
+        println!("{}", day.path());
+    }
+}
+
+// -----------------------------------------------------------------------------
+fn usage_error(err: &str) -> ExitCode {
+    eprintln!("error: {}\n\n{}", err, USAGE);
+    ExitCode::from(2)
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        Some("run") => match parse_run_args(args) {
+            Ok(options) => run(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("check") => match parse_check_args(args) {
+            Ok(days) => check(days),
+            Err(err) => return usage_error(&err),
+        },
+        Some("bench") => match parse_bench_args(args) {
+            Ok(options) => bench(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("ledger") => match parse_ledger_args(args) {
+            Ok((command, path)) => ledger(command, path),
+            Err(err) => return usage_error(&err),
+        },
+        Some("fetch") => match parse_fetch_args(args) {
+            Ok(options) => fetch_input(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("generate") => match parse_generate_args(args, vec![generate::DEFAULT_SIZE]) {
+            Ok(options) => generate_input(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("scale") => match parse_generate_args(args, generate::DEFAULT_SIZES.to_vec()) {
+            Ok(options) => scale(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("diff") => match parse_diff_args(args) {
+            Ok(options) => diff(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("step") => match parse_step_args(args) {
+            Ok((day, input)) => step(day, input),
+            Err(err) => return usage_error(&err),
+        },
+        Some("explain") => match parse_explain_args(args) {
+            Ok(options) => explain(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("query") => match parse_query_args(args) {
+            Ok(options) => query(options),
+            Err(err) => return usage_error(&err),
+        },
+        Some("new") => match parse_new_args(args) {
+            Ok((year, day, title)) => new_day(year, day, &title),
+            Err(err) => return usage_error(&err),
+        },
+        Some("help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => return usage_error(&format!("unknown command '{}'", command)),
+        None => return usage_error("no command given"),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
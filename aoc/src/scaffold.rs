@@ -0,0 +1,339 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::days::UNDATED_YEAR;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const GENERATE_TEMPLATE: &str = include_str!("../templates/generate.rs.tmpl");
+const REFERENCE_TEMPLATE: &str = include_str!("../templates/reference.rs.tmpl");
+const PART_TEMPLATE: &str = include_str!("../templates/part.rs.tmpl");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs.tmpl");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.txt.tmpl");
+
+type Register = fn(&str, &Names) -> Result<String, String>;
+
+// -- rustfmt's default width for the arguments of a call; registry entries with longer
+// -- arguments are split like it would.
+const MAX_ARGS_WIDTH: usize = 60;
+
+// -- The first Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+// -- A package named after one of these could not be imported as a crate.
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// -----------------------------------------------------------------------------
+// -- "If You Give A Seed A Fertilizer" -> "if-you-give-a-seed-a-fertilizer".
+pub fn package_name(title: &str) -> String {
+    title
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn validate(year: u16, day: u8, title: &str) -> Result<(), String> {
+    if !(FIRST_YEAR..=9999).contains(&year) {
+        return Err(format!("no such year: {}", year));
+    }
+
+    if !(1..=25).contains(&day) {
+        return Err(format!("no such day: {}", day));
+    }
+
+    let package = package_name(title);
+    if title.trim() != title || package.is_empty() {
+        return Err(format!("invalid title '{}'", title));
+    }
+
+    // -- The package doubles as a crate name in the registry, so it must be an identifier.
+    if package.starts_with(|ch: char| ch.is_ascii_digit()) || KEYWORDS.contains(&package.as_str()) {
+        return Err(format!(
+            "title '{}' gives the package name '{}', which is not a valid crate name",
+            title, package
+        ));
+    }
+
+    if let Some(ch) = title
+        .chars()
+        .find(|ch| matches!(ch, '/' | '\\' | '"') || ch.is_control())
+    {
+        return Err(format!("title cannot contain '{}'", ch.escape_default()));
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+struct Names {
+    year: u16,
+    day: u8,
+    title: String,
+    package: String,
+    krate: String,
+}
+
+impl Names {
+    fn new(year: u16, day: u8, title: &str) -> Self {
+        let package = package_name(title);
+        let krate = package.replace('-', "_");
+
+        Self {
+            year,
+            day,
+            title: String::from(title),
+            package,
+            krate,
+        }
+    }
+
+    fn dir_name(&self) -> String {
+        format!("{:02} - {}", self.day, self.title)
+    }
+
+    // -- Relative to the workspace root, e.g. "2023/07 - Camel Cards".
+    fn path(&self) -> String {
+        format!("{}/{}", self.year, self.dir_name())
+    }
+
+    // -- Sorts entries by year, then day.
+    fn key(&self) -> u32 {
+        key(self.year, self.day)
+    }
+
+    // -- Binary names are shared by the whole workspace, so only the days of the year
+    // -- that predates the others go without a year prefix.
+    fn bin(&self, part: u8) -> String {
+        match self.year {
+            UNDATED_YEAR => format!("day{:02}_part{}", self.day, part),
+            year => format!("y{}_day{:02}_part{}", year, self.day, part),
+        }
+    }
+
+    fn render(&self, template: &str, part: u8) -> String {
+        template
+            .replace("{{BIN1}}", &self.bin(1))
+            .replace("{{BIN2}}", &self.bin(2))
+            .replace("{{YEAR}}", &self.year.to_string())
+            .replace("{{DAY2}}", &format!("{:02}", self.day))
+            .replace("{{DAY}}", &self.day.to_string())
+            .replace("{{PART}}", &part.to_string())
+            .replace("{{PACKAGE}}", &self.package)
+            .replace("{{KRATE}}", &self.krate)
+    }
+}
+
+// -----------------------------------------------------------------------------
+fn key(year: u16, day: u8) -> u32 {
+    u32::from(year) * 100 + u32::from(day)
+}
+
+// -- Insert a line among the entries keyed by year and day, keeping them in order.
+// -- Entries are the lines for which `key` returns a key; the new line goes before the
+// -- first later day, or after the last entry.
+fn insert_ordered<F>(text: &str, names: &Names, entry: &str, key: F) -> Result<String, String>
+where
+    F: Fn(&[&str], usize) -> Option<u32>,
+{
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u32)> = (0..lines.len())
+        .filter_map(|index| key(&lines, index).map(|number| (index, number)))
+        .collect();
+
+    if keyed.iter().any(|(_, number)| *number == names.key()) {
+        return Err(format!(
+            "day {} of {} is already registered",
+            names.day, names.year
+        ));
+    }
+
+    let position = match keyed.iter().find(|(_, number)| *number > names.key()) {
+        Some((index, _)) => *index,
+        None => {
+            let (last, _) = keyed.last().ok_or("no existing entries to insert after")?;
+            // -- Skip over the rest of a multi-line entry.
+            let mut index = last + 1;
+            while index < lines.len() && lines[index].starts_with("        ") {
+                index += 1;
+            }
+            if lines.get(index).is_some_and(|line| line.trim() == "),") {
+                index += 1;
+            }
+            index
+        }
+    };
+
+    let mut result: Vec<&str> = lines[..position].to_vec();
+    result.extend(entry.lines());
+    result.extend(&lines[position..]);
+
+    Ok(result.join("\n") + "\n")
+}
+
+fn leading_number<T: std::str::FromStr>(text: &str) -> Option<T> {
+    let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+// -- "2023/07 - Camel Cards..." -> the key of day 7 of 2023.
+fn path_key(text: &str) -> Option<u32> {
+    let (year, rest) = text.split_once('/')?;
+    Some(key(year.parse().ok()?, leading_number(rest)?))
+}
+
+fn register_member(manifest: &str, names: &Names) -> Result<String, String> {
+    let entry = format!("    \"{}\",", names.path());
+    insert_ordered(manifest, names, &entry, |lines, index| {
+        path_key(lines[index].trim().strip_prefix('"')?)
+    })
+}
+
+fn register_dependency(manifest: &str, names: &Names) -> Result<String, String> {
+    let entry = format!("{} = {{ path = \"../{}\" }}", names.package, names.path());
+    insert_ordered(manifest, names, &entry, |lines, index| {
+        path_key(lines[index].split_once("path = \"../")?.1)
+    })
+}
+
+fn register_day(days: &str, names: &Names) -> Result<String, String> {
+    let args = [
+        names.year.to_string(),
+        names.day.to_string(),
+        format!("\"{}\"", names.title),
+        names.krate.clone(),
+    ];
+
+    let entry = if args.join(", ").len() > MAX_ARGS_WIDTH {
+        format!("    day!(\n        {}\n    ),", args.join(",\n        "))
+    } else {
+        format!("    day!({}),", args.join(", "))
+    };
+
+    let days = insert_ordered(days, names, &entry, |lines, index| {
+        let rest = lines[index].trim().strip_prefix("day!(")?;
+        let (year, day) = if rest.is_empty() {
+            (lines.get(index + 1)?.trim(), lines.get(index + 2)?.trim())
+        } else {
+            rest.split_once(", ")?
+        };
+        Some(key(leading_number(year)?, leading_number(day)?))
+    })?;
+
+    // -- Grow the registry's array length to match.
+    let (before, after) = days
+        .split_once("pub static DAYS: [Day; ")
+        .ok_or("unable to find the DAYS registry")?;
+    let (count, after) = after
+        .split_once(']')
+        .ok_or("unable to find the DAYS registry")?;
+    let count: usize = count
+        .parse()
+        .map_err(|_| "unable to read the DAYS registry length")?;
+
+    Ok(format!(
+        "{}pub static DAYS: [Day; {}]{}",
+        before,
+        count + 1,
+        after
+    ))
+}
+
+fn register_golden(tests: &str, names: &Names) -> Result<String, String> {
+    let entry = format!(
+        "    y{}_day{:02} => ({}, {}),",
+        names.year, names.day, names.year, names.day
+    );
+    insert_ordered(tests, names, &entry, |lines, index| {
+        let (_, args) = lines[index].trim().split_once(" => (")?;
+        let (year, day) = args.split_once(", ")?;
+        Some(key(year.parse().ok()?, leading_number(day)?))
+    })
+}
+
+// -----------------------------------------------------------------------------
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("unable to create '{}': {}", dir.display(), err))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|err| format!("unable to write '{}': {}", path.display(), err))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("unable to read '{}': {}", path.display(), err))
+}
+
+// -- Create the crate for a new day in its year's directory under the workspace root, and
+// -- register it with the workspace, the runner and the regression suite. Nothing is
+// -- written unless every registration succeeds.
+pub fn create(root: &Path, year: u16, day: u8, title: &str) -> Result<PathBuf, String> {
+    validate(year, day, title)?;
+    let names = Names::new(year, day, title);
+    let year_dir = root.join(year.to_string());
+    let dir = year_dir.join(names.dir_name());
+
+    // -- A year without any days yet has no directory either.
+    let prefix = format!("{:02} - ", day);
+    let existing = fs::read_dir(&year_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix));
+
+    if let Some(existing) = existing {
+        return Err(format!(
+            "day {} of {} already exists at '{}'",
+            day,
+            year,
+            existing.path().display()
+        ));
+    }
+
+    let registrations: [(PathBuf, Register); 4] = [
+        (root.join("Cargo.toml"), register_member),
+        (root.join("aoc/Cargo.toml"), register_dependency),
+        (root.join("aoc/src/days.rs"), register_day),
+        (root.join("aoc/tests/golden.rs"), register_golden),
+    ];
+
+    let updates = registrations
+        .iter()
+        .map(|(path, register)| {
+            let updated = register(&read(path)?, &names)
+                .map_err(|err| format!("'{}': {}", path.display(), err))?;
+            Ok((path, updated))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    write(&dir.join("Cargo.toml"), &names.render(CARGO_TEMPLATE, 0))?;
+    write(&dir.join("src/lib.rs"), LIB_TEMPLATE)?;
+    write(&dir.join("src/generate.rs"), GENERATE_TEMPLATE)?;
+    write(&dir.join("src/reference.rs"), REFERENCE_TEMPLATE)?;
+    write(&dir.join("answers.txt"), ANSWERS_TEMPLATE)?;
+    write(&dir.join("fixtures/example.txt"), "")?;
+
+    for part in [1, 2] {
+        let bin = format!("src/bin/{}.rs", names.bin(part));
+        write(
+            &dir.join(format!("src/part{}.rs", part)),
+            &names.render(PART_TEMPLATE, part),
+        )?;
+        write(&dir.join(bin), &names.render(BIN_TEMPLATE, part))?;
+    }
+
+    for (path, updated) in updates {
+        write(path, &updated)?;
+    }
+
+    Ok(dir)
+}
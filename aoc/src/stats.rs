@@ -0,0 +1,100 @@
+use std::fmt::Write;
+
+use common::report::Report;
+use common::stats;
+
+use crate::bench::format_duration;
+use crate::table;
+
+// -----------------------------------------------------------------------------
+// -- Byte counts in binary units, e.g. "1.5 MiB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+// -- The --stats report for the parts of one day: a table of phases, then one of
+// -- the counters, timers and peak memory each part recorded.
+pub fn render(year: u16, day: u8, reports: &[Report]) -> String {
+    let allocations = stats::counting_allocations();
+    let mut phases = Vec::new();
+    let mut measures = Vec::new();
+
+    for report in reports {
+        let Some(stats) = &report.stats else {
+            continue;
+        };
+        let part = report.part.to_string();
+
+        for phase in &stats.phases {
+            let counted = |value: String| {
+                if allocations {
+                    value
+                } else {
+                    String::from("-")
+                }
+            };
+            phases.push([
+                part.clone(),
+                String::from(phase.name),
+                format_duration(phase.time),
+                counted(phase.allocations.to_string()),
+                counted(format_bytes(phase.allocated)),
+                counted(format_bytes(phase.peak_heap)),
+            ]);
+        }
+
+        if let Some(peak_rss) = stats.peak_rss {
+            measures.push([
+                part.clone(),
+                String::from("peak_rss"),
+                format_bytes(peak_rss),
+            ]);
+        }
+
+        for (name, value) in &stats.counters {
+            measures.push([part.clone(), String::from(*name), value.to_string()]);
+        }
+
+        for (name, calls, time) in &stats.timers {
+            measures.push([
+                part.clone(),
+                String::from(*name),
+                format!("{} in {} call(s)", format_duration(*time), calls),
+            ]);
+        }
+    }
+
+    let mut output = String::new();
+    writeln!(output, "{} day {:02} stats:", year, day).unwrap();
+    output.push_str(&table::render(
+        [
+            "part",
+            "phase",
+            "time",
+            "allocations",
+            "allocated",
+            "peak heap",
+        ],
+        &phases,
+    ));
+
+    if !measures.is_empty() {
+        output.push('\n');
+        output.push_str(&table::render(["part", "measure", "value"], &measures));
+    }
+
+    output
+}
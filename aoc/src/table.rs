@@ -0,0 +1,32 @@
+use std::fmt::Write;
+
+// -----------------------------------------------------------------------------
+pub fn render<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    // -- Size every column to its widest cell.
+    let mut widths = header.map(|cell| cell.chars().count());
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let header = header.map(String::from);
+
+    for (index, row) in std::iter::once(&header).chain(rows).enumerate() {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        writeln!(table, "{}", cells.join("  ").trim_end()).unwrap();
+
+        if index == 0 {
+            let rules: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            writeln!(table, "{}", rules.join("  ")).unwrap();
+        }
+    }
+
+    table
+}
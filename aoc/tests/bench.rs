@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use aoc::bench::{History, Run, Sample, Trend};
+
+fn sample(part: u8, parse_ms: u64, solve_ms: u64) -> Sample {
+    Sample {
+        year: 2023,
+        day: 21,
+        part,
+        answer: String::from("3677"),
+        parse: Duration::from_millis(parse_ms),
+        solve: Duration::from_millis(solve_ms),
+    }
+}
+
+#[test]
+fn history_round_trip() {
+    let history = History {
+        runs: vec![
+            Run::new(5, vec![sample(1, 1, 200), sample(2, 1, 220)]),
+            Run::new(3, vec![sample(1, 1, 150)]),
+        ],
+    };
+
+    let parsed = History::parse(&history.to_string()).unwrap();
+    assert_eq!(parsed, history);
+
+    // -- The latest run without a part falls back to an older one.
+    assert_eq!(parsed.previous(2023, 21, 1), Some(&sample(1, 1, 150)));
+    assert_eq!(parsed.previous(2023, 21, 2), Some(&sample(2, 1, 220)));
+    assert_eq!(parsed.previous(2023, 1, 1), None);
+    assert_eq!(parsed.previous(2024, 21, 1), None);
+}
+
+#[test]
+fn undated_samples_are_from_2023() {
+    let text = r#"{"runs":[{"timestamp":0,"iterations":1,"samples":[
+        {"day":21,"part":1,"answer":"3677","parse_ns":1000000,"solve_ns":200000000}
+    ]}]}"#;
+
+    let history = History::parse(text).unwrap();
+    assert_eq!(history.previous(2023, 21, 1), Some(&sample(1, 1, 200)));
+}
+
+#[test]
+fn trend_flags() {
+    let previous = sample(1, 1, 100);
+
+    assert_eq!(Trend::between(None, &previous, 0.1), Trend::New);
+    assert_eq!(
+        Trend::between(Some(&previous), &sample(1, 1, 105), 0.1),
+        Trend::Steady
+    );
+    assert_eq!(
+        Trend::between(Some(&previous), &sample(1, 1, 150), 0.1),
+        Trend::Slower
+    );
+    assert_eq!(
+        Trend::between(Some(&previous), &sample(1, 1, 50), 0.1),
+        Trend::Faster
+    );
+}
@@ -0,0 +1,65 @@
+use common::Line;
+use cube_conundrum::cubes::{parse_games, Bag, Cubes, Game};
+
+#[test]
+fn any_color_goes() {
+    let line = Line::new(1, "Game 7: 3 dark red, 2 teal; 1 teal, 4 dark red, 2 teal");
+    let game = Game::parse(&line).unwrap();
+
+    assert_eq!(game.id, 7);
+    assert_eq!(game.rounds[1].count("teal"), 3);
+    assert_eq!(game.rounds[1].count("red"), 0);
+
+    let fewest = game.fewest();
+    let colors: Vec<(&str, u32)> = fewest.colors().collect();
+    assert_eq!(colors, [("dark red", 4), ("teal", 3)]);
+    // -- Without red, green and blue there is no power.
+    assert_eq!(fewest.power(), 0);
+
+    let bag = Bag::parse("4 dark red, 3 teal, 1 gold").unwrap();
+    assert!(game.possible(&bag));
+    assert!(!game.possible(&Bag::standard()));
+
+    let mut gold = Cubes::new();
+    gold.add("gold", 2);
+    assert!(gold.invalid(&bag));
+}
+
+#[test]
+fn malformed_rounds_are_located() {
+    let err = parse_games("Game 1: 3 red\nGame 2: 3 red, blue\n").unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!((location.line(), location.text()), (2, "blue"));
+
+    let err = parse_games("Game x: 3 red\n").unwrap_err();
+    assert_eq!(err.location().unwrap().text(), "x");
+
+    assert!(Bag::parse("12 red, many blue").is_err());
+}
+
+#[test]
+fn a_missing_color_makes_no_power() {
+    // -- No game shows blue, so every power is 0, as with red, green and blue fixed.
+    let input = "Game 1: 3 red, 2 green\nGame 2: 1 green; 4 red\n";
+    assert_eq!(cube_conundrum::part2::solve(input).unwrap(), 0_u64.into());
+
+    let input = "Game 1: 3 red, 2 green, 1 blue\nGame 2: 1 green; 4 red\n";
+    assert_eq!(cube_conundrum::part2::solve(input).unwrap(), 6_u64.into());
+
+    // -- Only the game showing a color has it in its power.
+    let input = "Game 1: 1 red, 2 green, 3 blue\nGame 2: 4 red, 1 purple\n";
+    assert_eq!(cube_conundrum::part2::solve(input).unwrap(), 6_u64.into());
+    let input = "Game 1: 1 red, 2 green, 3 blue, 2 teal\nGame 2: 1 red, 1 green, 1 blue\n";
+    assert_eq!(cube_conundrum::part2::solve(input).unwrap(), 13_u64.into());
+
+    let line = Line::new(1, "Game 1: 0 red, 2 teal");
+    let game = Game::parse(&line).unwrap();
+    let teal = Cubes::parse(&line, "2 teal").unwrap();
+    assert_eq!(game.rounds[0], teal);
+    assert_ne!(game.rounds[0], Cubes::new());
+
+    // -- No cubes of a color leave the power alone, as they leave equality alone.
+    let line = Line::new(1, "Game 1: 2 red, 3 green, 4 blue, 0 teal");
+    let game = Game::parse(&line).unwrap();
+    assert_eq!(game.fewest().power(), 24);
+}
@@ -0,0 +1,70 @@
+use aoc::days::DAYS;
+use aoc::differential;
+use common::random::Rng;
+use common::reference::Reference;
+use common::{Answer, Error};
+
+#[test]
+fn references_agree() {
+    for day in DAYS.iter() {
+        for reference in day.references {
+            let summary = differential::check(day, reference, 0..2, &[1, 3]);
+
+            assert_eq!(summary.cases, 4);
+            assert!(
+                summary.mismatch.is_none(),
+                "day {:02} part {}: {:?}",
+                day.number,
+                reference.part,
+                summary.mismatch
+            );
+        }
+    }
+}
+
+#[test]
+fn shrinks_to_the_failing_line() {
+    let input = "1 2 3\n4 5 6\n7 8 9\n10 11 12\n";
+    let shrunk = differential::shrink(input, |input| input.contains('8'));
+
+    assert_eq!(shrunk, "8\n");
+}
+
+// -- Sums the numbers, but forgets the ones above 50.
+fn buggy_sum(input: &str) -> Result<Answer, Error> {
+    let sum: u64 = input
+        .split_whitespace()
+        .filter_map(|word| word.parse::<u64>().ok())
+        .filter(|value| *value <= 50)
+        .sum();
+    Ok(sum.into())
+}
+
+fn sum(input: &str) -> Result<Answer, Error> {
+    let sum: u64 = input
+        .split_whitespace()
+        .filter_map(|word| word.parse::<u64>().ok())
+        .sum();
+    Ok(sum.into())
+}
+
+fn numbers(rng: &mut Rng, size: usize) -> String {
+    (0..size * 4)
+        .map(|_| rng.range_u64(0..=100).to_string() + "\n")
+        .collect()
+}
+
+#[test]
+fn reports_a_shrunk_mismatch() {
+    let reference = Reference {
+        part: 1,
+        fast: buggy_sum,
+        slow: sum,
+        generate: numbers,
+    };
+    let summary = differential::check(&DAYS[0], &reference, 0..10, &[5]);
+    let mismatch = summary.mismatch.expect("the bug should be found");
+
+    assert_eq!(mismatch.input, "51\n");
+    assert_eq!(mismatch.actual, Ok("0".to_string()));
+}
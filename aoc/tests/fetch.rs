@@ -0,0 +1,161 @@
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::fetch::Fetcher;
+
+const SESSION: &str = "53616c7465645f5f";
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n";
+
+// -----------------------------------------------------------------------------
+// -- A stand-in for the puzzle site: serves day 9 of 2023 to the right session cookie,
+// -- using a chunked body like the real server does, fails day 10 with a server error,
+// -- and records every request line.
+struct MockServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+
+                let mut authorized = false;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    authorized |= header.trim() == format!("Cookie: session={}", SESSION);
+                }
+
+                let response = match (path, authorized) {
+                    (_, false) => String::from(
+                        "HTTP/1.1 400 Bad Request\r\nContent-Length: 31\r\n\r\n\
+                         Puzzle inputs differ by user.\r\n",
+                    ),
+                    ("/2023/day/9/input", true) => {
+                        let (head, tail) = INPUT.split_at(10);
+                        format!(
+                            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                             {:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+                            head.len(),
+                            head,
+                            tail.len(),
+                            tail
+                        )
+                    }
+                    ("/2023/day/10/input", true) => String::from(
+                        "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\n\r\n",
+                    ),
+                    _ => String::from("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"),
+                };
+
+                log.lock().unwrap().push(request_line.trim().to_string());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    fn num_requests(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn fetcher(server: &MockServer, session: &str, cache_dir: PathBuf) -> Fetcher {
+    Fetcher {
+        base_url: server.base_url.clone(),
+        session: String::from(session),
+        cache_dir,
+        interval: Duration::ZERO,
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[test]
+fn downloads_once_then_uses_cache() {
+    let server = MockServer::start();
+    let fetcher = fetcher(&server, SESSION, cache_dir("cache"));
+
+    assert_eq!(fetcher.fetch(2023, 9).unwrap(), INPUT);
+    assert_eq!(fetcher.fetch(2023, 9).unwrap(), INPUT);
+    assert_eq!(server.num_requests(), 1);
+    assert_eq!(
+        server.requests.lock().unwrap()[0],
+        "GET /2023/day/9/input HTTP/1.1"
+    );
+
+    let cached = fs::read_to_string(fetcher.cache_path(2023, 9)).unwrap();
+    assert_eq!(cached, INPUT);
+
+    fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+}
+
+#[test]
+fn failures_are_reported_and_not_cached() {
+    let server = MockServer::start();
+
+    let rejected = fetcher(&server, "not-a-session", cache_dir("rejected"));
+    let err = rejected.fetch(2023, 9).unwrap_err();
+    assert!(err.contains("refused the session token"), "{}", err);
+    assert!(!rejected.cache_path(2023, 9).exists());
+
+    let missing = fetcher(&server, SESSION, cache_dir("missing"));
+    let err = missing.fetch(2023, 26).unwrap_err();
+    assert!(err.contains("not available"), "{}", err);
+    assert!(!missing.cache_path(2023, 26).exists());
+
+    let broken = fetcher(&server, SESSION, cache_dir("broken"));
+    let err = broken.fetch(2023, 10).unwrap_err();
+    assert!(err.contains("server error (status 500)"), "{}", err);
+    assert!(!broken.cache_path(2023, 10).exists());
+
+    assert_eq!(server.num_requests(), 3);
+
+    fs::remove_dir_all(&rejected.cache_dir).unwrap();
+    fs::remove_dir_all(&missing.cache_dir).unwrap();
+    fs::remove_dir_all(&broken.cache_dir).unwrap();
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let server = MockServer::start();
+    let mut fetcher = fetcher(&server, SESSION, cache_dir("rate"));
+    fetcher.interval = Duration::from_millis(300);
+
+    let start = Instant::now();
+    fetcher.fetch(2023, 9).unwrap();
+    let _ = fetcher.fetch(2023, 10);
+    let _ = fetcher.fetch(2023, 11);
+
+    assert_eq!(server.num_requests(), 3);
+    assert!(start.elapsed() >= Duration::from_millis(600));
+
+    fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+}
@@ -0,0 +1,51 @@
+use aoc::days::DAYS;
+use aoc::{generate, golden};
+
+#[test]
+fn generated_inputs_solve() {
+    for day in DAYS.iter() {
+        // -- A freshly scaffolded part has no answers yet and refuses every input.
+        let cases = golden::load_cases(day).unwrap();
+        let solved: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|&part| cases.iter().any(|case| case.part == part))
+            .collect();
+
+        for seed in 1..=3 {
+            for size in [1, 6] {
+                let input = generate::input(day, size, seed);
+
+                for &part in &solved {
+                    let solve = day.solver(part).unwrap();
+
+                    if let Err(err) = solve(&input) {
+                        panic!(
+                            "day {:02} part {} failed on size {} seed {}: {}\n{}",
+                            day.number, part, size, seed, err, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in DAYS.iter() {
+        let input = generate::input(day, 8, 42);
+
+        assert_eq!(input, generate::input(day, 8, 42), "day {:02}", day.number);
+        assert_ne!(input, generate::input(day, 8, 43), "day {:02}", day.number);
+    }
+}
+
+#[test]
+fn scale_measures_every_size() {
+    let day = &DAYS[0];
+    let points = generate::scale(day, 1, &[2, 4], 7, 1).unwrap();
+
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].size, 2);
+    assert!(points[0].bytes < points[1].bytes);
+}
@@ -0,0 +1,54 @@
+use aoc::{days, golden};
+
+fn check(year: u16, number: u8) {
+    let day = days::find(year, number).unwrap();
+    let outcomes = golden::check_day(day).unwrap();
+    let failures: Vec<_> = outcomes
+        .iter()
+        .filter(|outcome| !outcome.passed())
+        .collect();
+
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} case(s) did not match\n{}",
+            failures.len(),
+            outcomes.len(),
+            golden::table(failures)
+        );
+    }
+}
+
+macro_rules! golden {
+    ($($name:ident => ($year:expr, $number:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check($year, $number);
+            }
+        )*
+    };
+}
+
+golden! {
+    y2023_day01 => (2023, 1),
+    y2023_day02 => (2023, 2),
+    y2023_day03 => (2023, 3),
+    y2023_day04 => (2023, 4),
+    y2023_day05 => (2023, 5),
+    y2023_day06 => (2023, 6),
+    y2023_day07 => (2023, 7),
+    y2023_day08 => (2023, 8),
+    y2023_day09 => (2023, 9),
+    y2023_day10 => (2023, 10),
+    y2023_day11 => (2023, 11),
+    y2023_day12 => (2023, 12),
+    y2023_day13 => (2023, 13),
+    y2023_day14 => (2023, 14),
+    y2023_day15 => (2023, 15),
+    y2023_day16 => (2023, 16),
+    y2023_day17 => (2023, 17),
+    y2023_day18 => (2023, 18),
+    y2023_day19 => (2023, 19),
+    y2023_day20 => (2023, 20),
+    y2023_day21 => (2023, 21),
+}
@@ -0,0 +1,84 @@
+use aoc::ledger::{Entry, Ledger, Status, Verdict};
+use common::Answer;
+
+const HASH: &str = "adb9796dddfbf269";
+
+fn entry(answer: i128, verdict: Verdict) -> Entry {
+    Entry {
+        year: 2023,
+        day: 10,
+        part: 1,
+        input_hash: String::from(HASH),
+        answer,
+        verdict,
+    }
+}
+
+fn status(ledger: &Ledger, answer: u64) -> Status {
+    ledger.status(2023, 10, 1, HASH, &Answer::from(answer))
+}
+
+#[test]
+fn ledger_round_trip() {
+    let mut ledger = Ledger::default();
+    ledger.record(entry(7000, Verdict::TooHigh)).unwrap();
+    ledger.record(entry(-3, Verdict::Rejected)).unwrap();
+    ledger.record(entry(6968, Verdict::Accepted)).unwrap();
+
+    assert_eq!(Ledger::parse(&ledger.to_string()).unwrap(), ledger);
+}
+
+#[test]
+fn hints_flag_known_wrong_answers() {
+    let mut ledger = Ledger::default();
+    ledger.record(entry(7000, Verdict::TooHigh)).unwrap();
+    ledger.record(entry(6000, Verdict::TooLow)).unwrap();
+    ledger.record(entry(6500, Verdict::Rejected)).unwrap();
+
+    assert_eq!(status(&ledger, 6968), Status::Unknown);
+    assert!(matches!(status(&ledger, 7001), Status::Wrong(_)));
+    assert!(matches!(status(&ledger, 6000), Status::Wrong(_)));
+    assert!(matches!(status(&ledger, 6500), Status::Wrong(_)));
+
+    // -- Other inputs, parts and years are unaffected.
+    assert_eq!(
+        ledger.status(2023, 10, 2, HASH, &Answer::from(7001_u64)),
+        Status::Unknown
+    );
+    assert_eq!(
+        ledger.status(2023, 10, 1, "0000000000000000", &Answer::from(7001_u64)),
+        Status::Unknown
+    );
+    assert_eq!(
+        ledger.status(2024, 10, 1, HASH, &Answer::from(7001_u64)),
+        Status::Unknown
+    );
+}
+
+#[test]
+fn undated_entries_are_from_2023() {
+    let text = r#"{"entries":[
+{"day":10,"part":1,"input_hash":"adb9796dddfbf269","answer":6968,"verdict":"accepted"}
+]}"#;
+
+    let ledger = Ledger::parse(text).unwrap();
+    assert_eq!(ledger.entries, [entry(6968, Verdict::Accepted)]);
+}
+
+#[test]
+fn accepted_answer_is_exclusive() {
+    let mut ledger = Ledger::default();
+    ledger.record(entry(7000, Verdict::TooHigh)).unwrap();
+
+    assert!(ledger.record(entry(7200, Verdict::Accepted)).is_err());
+    ledger.record(entry(6968, Verdict::Accepted)).unwrap();
+    assert!(ledger.record(entry(6969, Verdict::Accepted)).is_err());
+
+    assert_eq!(status(&ledger, 6968), Status::Accepted);
+    assert!(matches!(status(&ledger, 6969), Status::Wrong(_)));
+
+    // -- Recording the same answer again replaces its verdict.
+    ledger.record(entry(6968, Verdict::Rejected)).unwrap();
+    assert_eq!(ledger.entries.len(), 2);
+    assert!(matches!(status(&ledger, 6968), Status::Wrong(_)));
+}
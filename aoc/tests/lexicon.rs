@@ -0,0 +1,183 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+use common::pool::Pool;
+use common::random::Rng;
+use trebuchet::lexicon::DigitLexicon;
+use trebuchet::scanner::Scanner;
+
+const NUMS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// -- Part 2's original matcher, trying every word at every index of the line.
+fn search_digit<I>(line: &str, indices: I) -> u32
+where
+    I: Iterator<Item = usize>,
+{
+    for index in indices {
+        let subline = &line[index..];
+        let first_char = subline.chars().next().unwrap();
+
+        if let Some(digit) = first_char.to_digit(10) {
+            return digit;
+        }
+        if let Some(num) = NUMS.iter().position(|word| subline.starts_with(word)) {
+            return num as u32;
+        }
+    }
+
+    panic!("unable to find digit");
+}
+
+// -- Hands out a few bytes at a time, as pipes do.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.0.len()).min(7);
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn overlapping_words_read_from_both_ends() {
+    let english = DigitLexicon::english();
+
+    assert_eq!(english.first("eightwo"), Some(8));
+    assert_eq!(english.last("eightwo"), Some(2));
+    assert_eq!(english.first("xtwone3four"), Some(2));
+    assert_eq!(english.last("xtwone3four"), Some(4));
+    assert_eq!(english.last("7pqrstsixteen"), Some(6));
+    assert_eq!(english.first("abcdef"), None);
+
+    let german = DigitLexicon::german();
+    assert_eq!(german.first("xfünfeins"), Some(5));
+    assert_eq!(german.last("achtneunull"), Some(0));
+    assert_eq!(german.last("sieben two"), Some(7));
+}
+
+#[test]
+fn words_are_picked_by_where_they_start() {
+    // -- "bc" is complete before "abcde" is, but starts later.
+    let lexicon = DigitLexicon::new([("abcde", 1), ("bc", 2)]).unwrap();
+
+    assert_eq!(lexicon.first("xabcdex"), Some(1));
+    assert_eq!(lexicon.last("xabcdex"), Some(2));
+    assert_eq!(lexicon.first("xabcd"), Some(2));
+}
+
+#[test]
+fn word_lists_merge_with_builtins() {
+    let path = env::temp_dir().join(format!("aoc-lexicon-{}.txt", std::process::id()));
+    fs::write(&path, "# Dutch\nnul 0\neen 1\ntwee 2\n\ndrie 3\n").unwrap();
+
+    let spec = format!("english, {}", path.display());
+    let lexicon = DigitLexicon::from_spec(&spec).unwrap();
+    assert_eq!(lexicon.first("xtweeone"), Some(2));
+    assert_eq!(lexicon.last("xtweeone"), Some(1));
+
+    fs::remove_file(&path).unwrap();
+
+    let err = DigitLexicon::parse("een 1\ntwee two\n").unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 2);
+    assert!(err.message().contains("expected a digit"));
+
+    let err = DigitLexicon::from_spec("english,german,fr").unwrap_err();
+    assert!(err.message().contains("unknown lexicon 'fr'"), "{}", err);
+
+    let err = DigitLexicon::new([("six", 6), ("six", 5)]).unwrap_err();
+    assert!(err.message().contains("'six' stands for both 6 and 5"));
+
+    let err = DigitLexicon::parse("een 1\n7 3\n").unwrap_err();
+    assert!(
+        err.message().contains("'7' is a number, not a word"),
+        "{}",
+        err
+    );
+    assert!(DigitLexicon::new([("42", 4)]).is_err());
+}
+
+#[test]
+fn explanations_show_tokens_and_spans() {
+    let input = "eightwothree\nabc\n4nineeightseven2\n";
+    let report = trebuchet::explain::explain(input, 2).unwrap();
+    let rows: Vec<&str> = report.lines().collect();
+
+    assert_eq!(
+        rows[1],
+        "   1  'eight' word 0..5  'three' word 7..12     83"
+    );
+    assert_eq!(rows[2], "   2  error: no digit on the line");
+    assert_eq!(
+        rows[3],
+        "   3  '4' digit 0..1     '2' digit 15..16       42"
+    );
+    assert_eq!(rows[4], "total 125 from 2 line(s), no digit on line(s) 2");
+
+    // -- The solver fails on the same line instead of panicking.
+    let err = trebuchet::part2::solve(input).unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 2);
+    let err = trebuchet::part1::solve("1\nx\n").unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 2);
+}
+
+#[test]
+fn scanner_matches_search_digit() {
+    let english = DigitLexicon::english();
+    let mut rng = Rng::new(7);
+
+    for size in [1, 5, 40, 300] {
+        let mut input = trebuchet::generate::generate(&mut rng, size);
+        input.push_str("zeroneight0\nfivezero\n");
+
+        let expected: u64 = input
+            .lines()
+            .map(|line| {
+                let first = search_digit(line, 0..line.len());
+                let last = search_digit(line, (0..line.len()).rev());
+                u64::from(first * 10 + last)
+            })
+            .sum();
+
+        for (workers, chunk_size) in [(1, 1 << 20), (3, 1), (2, 16)] {
+            let scanner = Scanner {
+                lexicon: &english,
+                pool: Pool::new(workers),
+                chunk_size,
+            };
+
+            assert_eq!(scanner.sum(input.as_bytes()).unwrap(), expected);
+            let trickle = Trickle(input.as_bytes());
+            assert_eq!(scanner.sum_reader(trickle).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn scanner_reports_the_line_without_a_digit() {
+    let english = DigitLexicon::english();
+    let input = "1abc\ntwo\n\nthree\nnope\r\nsix\n";
+    let scanner = Scanner {
+        lexicon: &english,
+        pool: Pool::new(2),
+        chunk_size: 4,
+    };
+
+    let err = scanner.sum(input.as_bytes()).unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 3);
+
+    let err = scanner
+        .sum_reader(Trickle(&input.as_bytes()[5..]))
+        .unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 2);
+
+    let err = scanner.sum(&input.as_bytes()[10..]).unwrap_err();
+    assert_eq!(err.location().unwrap().line(), 2);
+    assert_eq!(err.location().unwrap().source(), "nope");
+
+    assert_eq!(scanner.sum(b"").unwrap(), 0);
+}
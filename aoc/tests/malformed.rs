@@ -0,0 +1,51 @@
+use common::Error;
+
+fn located(result: Result<common::Answer, Error>) -> (usize, String, String) {
+    let err = result.unwrap_err();
+    let location = err.location().expect("error should be located");
+    (
+        location.line(),
+        String::from(location.text()),
+        String::from(err.message()),
+    )
+}
+
+#[test]
+fn camel_cards_reject_bad_hands() {
+    let input = "32T3K 765\nT55J5\n";
+    let (line, text, _) = located(camel_cards::part1::solve(input));
+    assert_eq!((line, text.as_str()), (2, ""));
+
+    let input = "32T3K 765\nKK6X7 28\n";
+    let (line, text, _) = located(camel_cards::part2::solve(input));
+    assert_eq!((line, text.as_str()), (2, "X"));
+}
+
+#[test]
+fn mirage_histories_need_numbers() {
+    let input = "0 3 6 9 12 15\n\n10 13 16 21 30 45\n";
+
+    for result in [
+        mirage_maintenance::part1::solve(input),
+        mirage_maintenance::part2::solve(input),
+    ] {
+        let (line, _, message) = located(result);
+        assert_eq!(line, 2);
+        assert!(message.contains("expected a history of numbers"));
+    }
+}
+
+#[test]
+fn pipe_maze_reports_a_tangled_loop() {
+    let err = pipe_maze::part2::solve("||F\nFF7\nL-S\n").unwrap_err();
+    assert!(err.message().contains("tangled at line 2, column 2"));
+}
+
+#[test]
+fn lens_steps_are_located() {
+    let (line, text, _) = located(lens_library::part2::solve("rn=1,cm,qp=3\n"));
+    assert_eq!((line, text.as_str()), (1, "cm"));
+
+    let (_, text, _) = located(lens_library::part2::solve("rn=1,qp=x\n"));
+    assert_eq!(text, "x");
+}
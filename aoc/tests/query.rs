@@ -0,0 +1,93 @@
+use std::fs;
+
+use aoc::days;
+use cube_conundrum::cubes::{parse_games, Game};
+use cube_conundrum::query::{Output, Query};
+
+fn example() -> Vec<Game> {
+    let day = days::find(2023, 2).unwrap();
+    let input = fs::read_to_string(day.dir().join("fixtures/example.txt")).unwrap();
+    parse_games(&input).unwrap()
+}
+
+fn run(games: &[Game], query: &str) -> Output {
+    Query::parse_for(query, games).unwrap().run(games)
+}
+
+#[test]
+fn queries_reproduce_both_parts() {
+    let games = example();
+
+    let part1 = "games where within {12 red, 13 green, 14 blue} | sum id";
+    assert_eq!(run(&games, part1), Output::Number(8));
+    assert_eq!(run(&games, "games | sum power"), Output::Number(2286));
+
+    // -- A color only one game shows is left out of the others' powers.
+    let games = parse_games("Game 1: 1 red, 2 green, 3 blue\nGame 2: 4 red, 1 purple\n").unwrap();
+    assert_eq!(run(&games, "games | sum power"), Output::Number(6));
+    assert_eq!(
+        run(&games, "games where power > 0 | count"),
+        Output::Number(1)
+    );
+}
+
+#[test]
+fn filters_and_aggregates() {
+    let games = example();
+
+    let output = run(&games, "games where any round blue > 5");
+    let ids: Vec<u32> = match output {
+        Output::Games(games) => games.iter().map(|game| game.id).collect(),
+        output => panic!("expected games, got {:?}", output),
+    };
+    assert_eq!(ids, [1, 3, 4]);
+
+    let output = run(&games, "games where id in 2..4 | fewest");
+    assert_eq!(output.to_string(), "15 blue, 13 green, 20 red\n");
+
+    let output = run(
+        &games,
+        "rounds where id = 3 and exceeds {12 red, 13 green, 14 blue}",
+    );
+    assert_eq!(
+        output.to_string(),
+        "Game 3 round 1: 6 blue, 8 green, 20 red\n"
+    );
+
+    // -- 'and' binds tighter than 'or'.
+    let loose = "rounds where round = 1 and red > 5 or blue = 15 | count";
+    let tight = "rounds where round = 1 and (red > 5 or blue = 15) | count";
+    assert_eq!(run(&games, loose), Output::Number(3));
+    assert_eq!(run(&games, tight), Output::Number(2));
+
+    let games = parse_games("Game 1: 2 dark red; 1 blue\nGame 2: 3 blue\n").unwrap();
+    let output = run(
+        &games,
+        "games where all rounds not \"dark red\" > 0 | count",
+    );
+    assert_eq!(output, Output::Number(1));
+}
+
+#[test]
+fn mistakes_point_at_the_query() {
+    let err = Query::parse("games where blue >> 3").unwrap_err();
+    assert_eq!(err.location().unwrap().column(), 19);
+    assert!(err.message().contains("expected a number"));
+
+    let err = Query::parse("rounds where any round red > 1").unwrap_err();
+    assert_eq!(err.location().unwrap().text(), "any");
+
+    let err = Query::parse("games | sum").unwrap_err();
+    assert!(err.message().contains("expected 'id' or 'power'"));
+
+    let err = Query::parse("games where within {12 red").unwrap_err();
+    assert!(err.message().contains("missing closing '}'"));
+
+    // -- A misspelled keyword is not quietly taken for a color.
+    let err = Query::parse_for("games where pwer > 3", &example()).unwrap_err();
+    assert_eq!(err.location().unwrap().text(), "pwer");
+    assert!(err.message().contains("neither a keyword nor a color"));
+
+    let err = Query::parse("rounds where \"dark red\" > 1").unwrap_err();
+    assert_eq!(err.location().unwrap().text(), "\"dark red\"");
+}
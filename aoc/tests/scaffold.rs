@@ -0,0 +1,179 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::{days, scaffold};
+
+// -- A throwaway workspace root holding copies of the files a new day is registered in.
+fn fake_root(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    let files = [
+        ("Cargo.toml", include_str!("../../Cargo.toml")),
+        ("aoc/Cargo.toml", include_str!("../Cargo.toml")),
+        ("aoc/src/days.rs", include_str!("../src/days.rs")),
+        ("aoc/tests/golden.rs", include_str!("golden.rs")),
+    ];
+
+    for (path, contents) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    root
+}
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn package_names_follow_the_titles() {
+    assert_eq!(scaffold::package_name("Trebuchet?!"), "trebuchet");
+    assert_eq!(scaffold::package_name("Sand Slabs"), "sand-slabs");
+    assert_eq!(
+        scaffold::package_name("If You Give A Seed A Fertilizer"),
+        "if-you-give-a-seed-a-fertilizer"
+    );
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let root = fake_root("create");
+    let dir = scaffold::create(&root, 2023, 22, "Sand Slabs").unwrap();
+
+    assert_eq!(dir, root.join("2023/22 - Sand Slabs"));
+    for file in [
+        "Cargo.toml",
+        "answers.txt",
+        "fixtures/example.txt",
+        "src/lib.rs",
+        "src/generate.rs",
+        "src/reference.rs",
+        "src/part1.rs",
+        "src/part2.rs",
+        "src/bin/day22_part1.rs",
+        "src/bin/day22_part2.rs",
+    ] {
+        assert!(dir.join(file).is_file(), "missing {}", file);
+    }
+
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"sand-slabs\""));
+    assert!(manifest.contains("path = \"src/bin/day22_part2.rs\""));
+
+    let bin = fs::read_to_string(dir.join("src/bin/day22_part2.rs")).unwrap();
+    assert!(bin.contains("common::timed!(sand_slabs::part2)"));
+
+    assert!(read(&root, "Cargo.toml")
+        .contains("    \"2023/21 - Step Counter\",\n    \"2023/22 - Sand Slabs\",\n]"));
+    assert!(read(&root, "aoc/Cargo.toml")
+        .ends_with("sand-slabs = { path = \"../2023/22 - Sand Slabs\" }\n"));
+    assert!(read(&root, "aoc/tests/golden.rs")
+        .contains("    y2023_day21 => (2023, 21),\n    y2023_day22 => (2023, 22),\n}"));
+
+    let days = read(&root, "aoc/src/days.rs");
+    assert!(days.contains(&format!("pub static DAYS: [Day; {}]", days::DAYS.len() + 1)));
+    assert!(days.contains("    day!(2023, 22, \"Sand Slabs\", sand_slabs),\n];"));
+
+    // -- The same day cannot be created twice.
+    assert!(scaffold::create(&root, 2023, 22, "Sand Slabs").is_err());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn starts_a_new_year() {
+    let root = fake_root("year");
+    let dir = scaffold::create(&root, 2024, 1, "Historian Hysteria").unwrap();
+
+    assert_eq!(dir, root.join("2024/01 - Historian Hysteria"));
+
+    // -- Binaries are named after the year, so they can't clash with 2023's.
+    assert!(dir.join("src/bin/y2024_day01_part1.rs").is_file());
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"y2024_day01_part2\""));
+    assert!(manifest.contains("common = { path = \"../../common\" }"));
+
+    assert!(read(&root, "Cargo.toml")
+        .contains("    \"2023/21 - Step Counter\",\n    \"2024/01 - Historian Hysteria\",\n]"));
+    assert!(read(&root, "aoc/tests/golden.rs").contains("    y2024_day01 => (2024, 1),\n}"));
+    assert!(read(&root, "aoc/src/days.rs")
+        .contains("    day!(2024, 1, \"Historian Hysteria\", historian_hysteria),\n];"));
+
+    // -- Day 1 of 2023 is a different day altogether.
+    assert!(scaffold::create(&root, 2023, 1, "Historian Hysteria").is_err());
+    assert!(scaffold::create(&root, 2024, 1, "Historian Hysteria").is_err());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn inserts_missing_days_in_order() {
+    let root = fake_root("order");
+
+    // -- Pretend day 09 never existed, then scaffold it again.
+    let manifest = read(&root, "Cargo.toml").replace("    \"2023/09 - Mirage Maintenance\",\n", "");
+    fs::write(root.join("Cargo.toml"), manifest).unwrap();
+    let count = days::DAYS.len();
+    let days = read(&root, "aoc/src/days.rs")
+        .replace(
+            "    day!(2023, 9, \"Mirage Maintenance\", mirage_maintenance),\n",
+            "",
+        )
+        .replace(
+            &format!("[Day; {}]", count),
+            &format!("[Day; {}]", count - 1),
+        );
+    fs::write(root.join("aoc/src/days.rs"), days).unwrap();
+    let deps = read(&root, "aoc/Cargo.toml").replace(
+        "mirage-maintenance = { path = \"../2023/09 - Mirage Maintenance\" }\n",
+        "",
+    );
+    fs::write(root.join("aoc/Cargo.toml"), deps).unwrap();
+    let tests = read(&root, "aoc/tests/golden.rs").replace("    y2023_day09 => (2023, 9),\n", "");
+    fs::write(root.join("aoc/tests/golden.rs"), tests).unwrap();
+
+    scaffold::create(&root, 2023, 9, "Mirage Maintenance").unwrap();
+
+    for path in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/days.rs",
+        "aoc/tests/golden.rs",
+    ] {
+        let original = fs::read_to_string(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(path),
+        )
+        .unwrap();
+        let restored = read(&root, path);
+        assert_eq!(restored, original, "{}", path);
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn rejects_bad_days_and_titles() {
+    let root = fake_root("reject");
+
+    assert!(scaffold::create(&root, 2014, 1, "Not Yet").is_err());
+    assert!(scaffold::create(&root, 2023, 0, "Nothing").is_err());
+    assert!(scaffold::create(&root, 2023, 26, "Boxing Day").is_err());
+    assert!(scaffold::create(&root, 2023, 22, "Slabs/Bricks").is_err());
+    assert!(scaffold::create(&root, 2023, 22, "?!").is_err());
+    assert!(scaffold::create(&root, 2023, 21, "Step Counter Again").is_err());
+    assert!(scaffold::create(&root, 2019, 2, "1202 Program Alarm").is_err());
+    assert!(scaffold::create(&root, 2023, 22, "Loop").is_err());
+    assert!(scaffold::create(&root, 2023, 22, "Self").is_err());
+
+    // -- Nothing was touched by the failed attempts.
+    assert_eq!(read(&root, "Cargo.toml"), include_str!("../../Cargo.toml"));
+    assert!(!root.join("2023/22 - Slabs").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+use aoc::days::{self, DAYS};
+use common::stepper::Stepper;
+
+fn example(year: u16, number: u8) -> Stepper {
+    let day = days::find(year, number).unwrap();
+    // -- Day 21 has no example, its own input will do.
+    let example = day.dir().join("fixtures/example.txt");
+    let path = if example.exists() {
+        example
+    } else {
+        day.input_path()
+    };
+    let input = fs::read_to_string(path).unwrap();
+    Stepper::new((day.stepper.unwrap())(&input).unwrap())
+}
+
+#[test]
+fn simulations_step_back_and_forth() {
+    for day in DAYS.iter().filter(|day| day.stepper.is_some()) {
+        let mut stepper = example(day.year, day.number);
+        let first = stepper.current().render();
+
+        stepper.goto(10);
+        let tenth = stepper.current().render();
+        assert_ne!(tenth, first, "day {:02}", day.number);
+
+        stepper.goto(0);
+        assert_eq!(stepper.current().render(), first, "day {:02}", day.number);
+        stepper.goto(10);
+        assert_eq!(stepper.current().render(), tenth, "day {:02}", day.number);
+    }
+}
+
+#[test]
+fn simulations_match_the_answers() {
+    // -- The first tilt is all of day 14 part 1.
+    let mut stepper = example(2023, 14);
+    stepper.goto(1);
+    assert!(stepper.current().status().ends_with("load 136"));
+
+    // -- Day 16's beam dies out after energizing part 1's tiles.
+    let mut stepper = example(2023, 16);
+    stepper.goto(usize::MAX);
+    assert_eq!(stepper.end(), Some(stepper.position()));
+    assert!(stepper.current().status().ends_with("46 tile(s) energized"));
+}
+
+#[test]
+fn looping_simulations_skip_ahead() {
+    // -- A billion spin cycles land on part 2's answer without running them all.
+    let mut stepper = example(2023, 14);
+    stepper.goto(4_000_000_000);
+    assert_eq!(
+        stepper.current().status(),
+        "cycle 1000000000, tilted East, load 64"
+    );
+    // -- ... and the example repeats every 7 cycles, going back too.
+    stepper.goto(4_000_000_000 - 7 * 4);
+    assert_eq!(
+        stepper.current().status(),
+        "cycle 999999993, tilted East, load 64"
+    );
+
+    // -- Once the garden is filled the walk alternates between two sets of plots.
+    let mut stepper = example(2023, 21);
+    stepper.goto(1_000_000_000);
+    let even = stepper.current().render();
+    stepper.goto(1_000_000_001);
+    let odd = stepper.current().render();
+    assert_ne!(even, odd);
+    stepper.goto(999_999_998);
+    assert_eq!(stepper.current().render(), even);
+    assert_eq!(stepper.position(), 999_999_998);
+}
@@ -0,0 +1,34 @@
+use std::fmt::{self, Display};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+}
+
+use Answer::*;
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unsigned(value) => write!(f, "{}", value),
+            Signed(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    $variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_from!(Signed, i64, i8, i16, i32, i64, isize);
@@ -0,0 +1,256 @@
+use std::env;
+use std::fs;
+use std::io::{read_to_string, stdin, stdout, BufRead, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::image;
+use crate::report::{Format, Part, Report};
+use crate::visualize::{self, Playback};
+use crate::Error;
+
+const INPUT_NAME: &str = "input.txt";
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+use Source::*;
+
+impl Source {
+    // -- A path of '-' stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Stdin,
+            path => File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn bundled(manifest_dir: &str) -> Self {
+        File(Path::new(manifest_dir).join(INPUT_NAME))
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            File(path) => fs::read_to_string(path)
+                .map_err(|err| format!("unable to read '{}': {}", path.display(), err)),
+            Stdin => read_input(stdin().lock()).map_err(|err| err.to_string()),
+        }
+    }
+
+    // -- For inputs read a batch at a time rather than as a whole.
+    pub fn open(&self) -> Result<Box<dyn Read>, String> {
+        match self {
+            File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(file)),
+                Err(err) => Err(format!("unable to read '{}': {}", path.display(), err)),
+            },
+            Stdin => Ok(Box::new(stdin().lock())),
+        }
+    }
+}
+
+pub fn read_input<R: BufRead>(reader: R) -> Result<String, Error> {
+    read_to_string(reader).map_err(|err| Error::new(format!("unable to read input: {}", err)))
+}
+
+// -- Walks the arguments of a binary reading one input: '--input' is taken care of,
+// -- every other flag goes to `flag` along with a way to take its value. `flag` returns
+// -- false for the flags it doesn't know.
+pub fn parse_input_args<I, F>(mut args: I, mut flag: F) -> Result<Option<Source>, String>
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, &mut dyn FnMut() -> Result<String, String>) -> Result<bool, String>,
+{
+    let mut source = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--input" => source = Some(Source::from_arg(&value()?)),
+            _ if flag(&arg, &mut value)? => {}
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(source)
+}
+
+// -----------------------------------------------------------------------------
+struct Options {
+    source: Option<Source>,
+    format: Format,
+    visualize: bool,
+    fps: Option<u32>,
+    export: Option<PathBuf>,
+}
+
+fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut options = Options {
+        source: None,
+        format: Format::Text,
+        visualize: false,
+        fps: None,
+        export: None,
+    };
+
+    options.source = parse_input_args(args, |arg, value| {
+        match arg {
+            "--format" => options.format = Format::from_arg(&value()?)?,
+            "--visualize" => options.visualize = true,
+            // -- A frame rate implies '--visualize'.
+            "--fps" => {
+                let fps = value()?;
+                match fps.parse() {
+                    Ok(fps) if fps > 0 => options.fps = Some(fps),
+                    _ => return Err(format!("invalid frame rate '{}'", fps)),
+                }
+                options.visualize = true;
+            }
+            "--export" => {
+                let path = PathBuf::from(value()?);
+                match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("svg" | "png") => options.export = Some(path),
+                    _ => {
+                        return Err(format!(
+                            "'{}' is neither an .svg nor a .png",
+                            path.display()
+                        ))
+                    }
+                }
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    })?;
+
+    // -- Frames would end up in the middle of the JSON on stdout.
+    if options.visualize && options.format == Format::Json {
+        return Err(String::from(
+            "'--visualize' cannot be combined with '--format json'",
+        ));
+    }
+
+    Ok(options)
+}
+
+// -- Frames go to stdout ahead of the answer, colored only when stdout is a terminal and
+// -- NO_COLOR is unset.
+fn play(part: &Part, fps: Option<u32>) {
+    let frames = visualize::take();
+
+    if frames.is_empty() {
+        eprintln!(
+            "note: {} day {:02} part {} has nothing to visualize",
+            part.year, part.day, part.part
+        );
+        return;
+    }
+
+    let playback = Playback {
+        fps,
+        color: stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+    };
+
+    if let Err(err) = playback.play(&frames, &mut stdout().lock()) {
+        eprintln!("error: unable to play frames: {}", err);
+    }
+}
+
+// -- Only failing to write the file is an error, a part may well have nothing to draw.
+fn export(part: &Part, path: &Path) -> bool {
+    let Some(drawing) = image::take() else {
+        eprintln!(
+            "note: {} day {:02} part {} has nothing to export",
+            part.year, part.day, part.part
+        );
+        return true;
+    };
+
+    match drawing.save(path) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            false
+        }
+    }
+}
+
+// -- A bad command line, reported the same way by every binary.
+pub fn usage_error(err: &str, usage: &str) -> ExitCode {
+    let program = env::args().next().unwrap_or_default();
+    let program = Path::new(&program).file_name().unwrap_or_default();
+
+    eprintln!(
+        "error: {}\n\nusage: {} {}",
+        err,
+        program.to_string_lossy(),
+        usage
+    );
+    ExitCode::from(2)
+}
+
+pub fn main(manifest_dir: &str, part: &Part) -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            return usage_error(
+                &err,
+                "[--input PATH] [--format text|json] [--visualize] [--fps N] [--export PATH]",
+            )
+        }
+    };
+
+    // -- Without '--input', the input.txt bundled next to the crate's Cargo.toml. Stdin is
+    // -- only read when asked for with '--input -', so an idle pipe never blocks a run.
+    let source = options
+        .source
+        .unwrap_or_else(|| Source::bundled(manifest_dir));
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.visualize {
+        visualize::start();
+    }
+    if options.export.is_some() {
+        image::start();
+    }
+
+    let report = Report::run(part, &input);
+
+    if options.visualize {
+        play(part, options.fps);
+    }
+    let exported = match &options.export {
+        Some(path) => export(part, path),
+        None => true,
+    };
+
+    match (options.format, &report.outcome) {
+        (Format::Json, _) => println!("{}", report.to_json()),
+        (Format::Text, Ok(timing)) => println!("{}", timing.answer),
+        (Format::Text, Err(err)) => eprintln!("error: {}", err),
+    }
+
+    match report.outcome {
+        Ok(_) if exported => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,29 @@
+use std::cell::RefCell;
+
+use crate::json::Value;
+
+// -----------------------------------------------------------------------------
+// -- Side channel for facts a solver finds along the way (cycle lengths and the like)
+// -- that are not part of the answer but are worth surfacing in machine-readable output.
+thread_local! {
+    static RECORDED: RefCell<Vec<(&'static str, Value)>> = const { RefCell::new(Vec::new()) };
+}
+
+// -- Recording the same key again replaces the earlier value, so repeated runs of a
+// -- solver do not pile up entries.
+pub fn record<V: Into<Value>>(key: &'static str, value: V) {
+    let value = value.into();
+
+    RECORDED.with(|recorded| {
+        let mut recorded = recorded.borrow_mut();
+
+        match recorded.iter_mut().find(|(name, _)| *name == key) {
+            Some(entry) => entry.1 = value,
+            None => recorded.push((key, value)),
+        }
+    });
+}
+
+pub fn take() -> Vec<(&'static str, Value)> {
+    RECORDED.with(|recorded| recorded.take())
+}
@@ -0,0 +1,107 @@
+use std::fmt::{self, Display};
+
+use crate::input::Line;
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    line: usize,
+    column: usize,
+    width: usize,
+    text: String,
+    source: String,
+}
+
+impl Location {
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    location: Option<Box<Location>>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let location = None;
+        Self { message, location }
+    }
+
+    // -- Locate the offending text by its byte range within the line.
+    pub fn at(mut self, line: &Line, offset: usize, len: usize) -> Self {
+        let source = line.as_str();
+        let offset = offset.min(source.len());
+        let end = (offset + len).min(source.len());
+
+        let column = source[..offset].chars().count() + 1;
+        let text = String::from(&source[offset..end]);
+        let width = text.chars().count().max(1);
+
+        self.location = Some(Box::new(Location {
+            line: line.number(),
+            column,
+            width,
+            text,
+            source: String::from(source),
+        }));
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_deref()
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        let Some(location) = &self.location else {
+            return Ok(());
+        };
+
+        // -- Render a caret snippet underneath the message.
+        let number = location.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent: String = location
+            .source
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(location.width);
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{}--> line {}, column {}",
+            gutter, location.line, location.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, location.source)?;
+        write!(f, "{} | {}{}", gutter, indent, carets)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,258 @@
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::png;
+
+// -- Cells are drawn this many pixels wide, unless that would make the image larger
+// -- than `MAX_SIDE` pixels.
+const CELL_SIZE: f64 = 8.0;
+const MAX_SIDE: f64 = 1024.0;
+
+pub const BACKGROUND: Rgb = Rgb(0x00, 0x2b, 0x36);
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Shape {
+    Cell { col: usize, row: usize, color: Rgb },
+    Polygon { points: Vec<(f64, f64)>, color: Rgb },
+}
+
+use Shape::*;
+
+// -----------------------------------------------------------------------------
+// -- A picture of a puzzle's state in grid units: unit cells at integer coordinates and
+// -- polygons with arbitrary corners, painted in order over the background. It can be
+// -- written as an SVG, which keeps the shapes, or as a PNG.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drawing {
+    width: f64,
+    height: f64,
+    background: Rgb,
+    shapes: Vec<Shape>,
+}
+
+impl Drawing {
+    pub fn new(width: f64, height: f64, background: Rgb) -> Self {
+        Self {
+            width: width.max(1.0),
+            height: height.max(1.0),
+            background,
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn cell(&mut self, col: usize, row: usize, color: Rgb) {
+        self.shapes.push(Cell { col, row, color });
+    }
+
+    pub fn polygon(&mut self, points: Vec<(f64, f64)>, color: Rgb) {
+        self.shapes.push(Polygon { points, color });
+    }
+
+    // -- Pixels per grid unit: whole pixels whenever there is room for them.
+    fn scale(&self) -> f64 {
+        let scale = (MAX_SIDE / self.width.max(self.height)).min(CELL_SIZE);
+
+        if scale >= 1.0 {
+            scale.floor()
+        } else {
+            scale
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        let scale = self.scale();
+        let mut svg = String::new();
+
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">",
+            (self.width * scale).ceil(),
+            (self.height * scale).ceil(),
+            self.width,
+            self.height
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            self.width,
+            self.height,
+            self.background.hex()
+        )
+        .unwrap();
+
+        // -- Neighbouring cells of the same color on a row are merged into one rectangle.
+        let mut shapes = self.shapes.iter().peekable();
+
+        while let Some(shape) = shapes.next() {
+            match shape {
+                Cell { col, row, color } => {
+                    let mut len = 1;
+
+                    while let Some(Cell {
+                        col: next_col,
+                        row: next_row,
+                        color: next_color,
+                    }) = shapes.peek()
+                    {
+                        if *next_col != col + len || next_row != row || next_color != color {
+                            break;
+                        }
+                        len += 1;
+                        shapes.next();
+                    }
+
+                    writeln!(
+                        svg,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                        col,
+                        row,
+                        len,
+                        color.hex()
+                    )
+                    .unwrap();
+                }
+                Polygon { points, color } => {
+                    let points: Vec<String> =
+                        points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+
+                    writeln!(
+                        svg,
+                        "<polygon points=\"{}\" fill=\"{}\" fill-opacity=\"0.6\" stroke=\"{}\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+                        points.join(" "),
+                        color.hex(),
+                        color.hex()
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    // -- Width, height and the pixels of the PNG image, row by row.
+    pub fn raster(&self) -> (usize, usize, Vec<Rgb>) {
+        let scale = self.scale();
+        let width = ((self.width * scale).ceil() as usize).max(1);
+        let height = ((self.height * scale).ceil() as usize).max(1);
+        let mut pixels = vec![self.background; width * height];
+
+        // -- The pixels a span of grid units covers, at least one however small it is.
+        let span = |from: f64, to: f64, limit: usize| {
+            let first = ((from * scale) as usize).min(limit - 1);
+            let last = ((to * scale) as usize).clamp(first + 1, limit);
+            first..last
+        };
+
+        for shape in self.shapes.iter() {
+            match shape {
+                Cell { col, row, color } => {
+                    let (col, row) = (*col as f64, *row as f64);
+
+                    for y in span(row, row + 1.0, height) {
+                        for x in span(col, col + 1.0, width) {
+                            pixels[y * width + x] = *color;
+                        }
+                    }
+                }
+                Polygon { points, color } => {
+                    for y in 0..height {
+                        for (from, to) in crossings(points, (y as f64 + 0.5) / scale) {
+                            for x in span(from, to, width) {
+                                pixels[y * width + x] = *color;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (width, height, pixels)
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.raster();
+        let bytes: Vec<u8> = pixels
+            .iter()
+            .flat_map(|rgb| [rgb.0, rgb.1, rgb.2])
+            .collect();
+        png::encode(width, height, &bytes)
+    }
+
+    // -- The format follows the extension, '.svg' or '.png'.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => self.to_svg().into_bytes(),
+            Some("png") => self.to_png(),
+            _ => {
+                return Err(format!(
+                    "'{}' is neither an .svg nor a .png",
+                    path.display()
+                ))
+            }
+        };
+
+        fs::write(path, bytes)
+            .map_err(|err| format!("unable to write '{}': {}", path.display(), err))
+    }
+}
+
+// -- The spans of a scanline at height `y` inside the polygon, by the even-odd rule.
+fn crossings(points: &[(f64, f64)], y: f64) -> Vec<(f64, f64)> {
+    let mut xs: Vec<f64> = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .filter(|((_, y0), (_, y1))| (*y0 <= y) != (*y1 <= y))
+        .map(|((x0, y0), (x1, y1))| x0 + (y - y0) * (x1 - x0) / (y1 - y0))
+        .collect();
+
+    xs.sort_by(f64::total_cmp);
+    xs.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+// -----------------------------------------------------------------------------
+// -- The drawing a solver would like exported, kept only while a caller asked for it with
+// -- `start`. Like `visualize::frame`, it is only built when someone is going to use it.
+thread_local! {
+    static RECORDED: RefCell<Option<Option<Drawing>>> = const { RefCell::new(None) };
+}
+
+pub fn start() {
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(None));
+}
+
+pub fn enabled() -> bool {
+    RECORDED.with(|recorded| recorded.borrow().is_some())
+}
+
+// -- Recording again replaces the earlier drawing.
+pub fn record<F>(draw: F)
+where
+    F: FnOnce() -> Drawing,
+{
+    if !enabled() {
+        return;
+    }
+
+    let drawing = draw();
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(Some(drawing)));
+}
+
+// -- Stops recording and returns the last drawing recorded since `start`.
+pub fn take() -> Option<Drawing> {
+    RECORDED.with(|recorded| recorded.take()).flatten()
+}
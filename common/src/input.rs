@@ -0,0 +1,119 @@
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::Error;
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    fn offset_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let address = token.as_ptr() as usize;
+
+        // -- Tokens borrowed from this line are located by address, anything else by search.
+        if address >= start && address + token.len() <= start + self.text.len() {
+            address - start
+        } else {
+            self.text.find(token).unwrap_or(0)
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(message).at(self, 0, self.text.len())
+    }
+
+    pub fn error_at(&self, offset: usize, len: usize, message: impl Into<String>) -> Error {
+        Error::new(message).at(self, offset, len)
+    }
+
+    pub fn error_in(&self, token: &str, message: impl Into<String>) -> Error {
+        Error::new(message).at(self, self.offset_of(token), token.len())
+    }
+
+    pub fn error_at_end(&self, message: impl Into<String>) -> Error {
+        Error::new(message).at(self, self.text.len(), 0)
+    }
+
+    pub fn locate(&self, token: &str, err: Error) -> Error {
+        err.at(self, self.offset_of(token), token.len())
+    }
+
+    pub fn expect(&self, token: Option<&'a str>, what: &str) -> Result<&'a str, Error> {
+        token.ok_or_else(|| self.error_at_end(format!("expected {}", what)))
+    }
+
+    pub fn parse<T>(&self, token: &str) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|err| self.error_in(token, format!("invalid number '{}': {}", token, err)))
+    }
+
+    pub fn parse_next<I, T>(&self, tokens: &mut I, what: &str) -> Result<T, Error>
+    where
+        I: Iterator<Item = &'a str>,
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.expect(tokens.next(), what)?;
+        self.parse(token)
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+// -----------------------------------------------------------------------------
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line::new(index + 1, text))
+}
+
+pub fn split_blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for line in lines(input) {
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
@@ -0,0 +1,30 @@
+mod answer;
+pub mod cli;
+pub mod diagnostics;
+mod error;
+pub mod image;
+mod input;
+pub mod json;
+pub mod math;
+pub mod png;
+pub mod pool;
+pub mod random;
+pub mod reference;
+pub mod report;
+pub mod stats;
+pub mod stepper;
+mod timing;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use error::{Error, Location};
+pub use input::{lines, split_blocks, Line};
+pub use timing::{time, Timed, Timing};
+
+pub type Solver = fn(&str) -> Result<Answer, Error>;
+
+// -- A readable account of how a part arrives at its answer, line by line.
+pub type Explainer = fn(&str, u8) -> Result<String, Error>;
+
+// -- Answers an ad hoc question, given as text, about an input.
+pub type Querier = fn(&str, &str) -> Result<String, Error>;
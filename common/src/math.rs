@@ -0,0 +1,150 @@
+// -----------------------------------------------------------------------------
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let mut m = a;
+    let mut n = b;
+
+    if m == 0 || n == 0 {
+        return m | n;
+    }
+
+    let shift = (m | n).trailing_zeros();
+    m >>= m.trailing_zeros();
+    n >>= n.trailing_zeros();
+
+    while m != n {
+        if m > n {
+            m -= n;
+            m >>= m.trailing_zeros();
+        } else {
+            n -= m;
+            n >>= n.trailing_zeros();
+        }
+    }
+
+    m << shift
+}
+
+// -- None if the result does not fit.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// -- The lcm of two u64 values always fits in a u128.
+pub fn lcm_u128(a: u64, b: u64) -> u128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    u128::from(a / gcd(a, b)) * u128::from(b)
+}
+
+pub fn lcm_all<I>(values: I) -> Option<u64>
+where
+    I: IntoIterator<Item = u64>,
+{
+    values.into_iter().try_fold(1, lcm)
+}
+
+// -----------------------------------------------------------------------------
+// -- Returns (g, x, y) such that a * x + b * y = g = gcd(a, b).
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(modulus))
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u64,
+    pub modulus: u64,
+}
+
+impl Congruence {
+    pub fn new(residue: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "congruence modulus must be positive");
+        let residue = residue % modulus;
+        Self { residue, modulus }
+    }
+
+    // -- Combine with another congruence; the moduli need not be coprime. None if the two
+    // -- are incompatible or the combined modulus does not fit.
+    pub fn merge(&self, other: &Congruence) -> Option<Congruence> {
+        let (a1, m1) = (i128::from(self.residue), i128::from(self.modulus));
+        let (a2, m2) = (i128::from(other.residue), i128::from(other.modulus));
+
+        let (g, p, _) = extended_gcd(m1, m2);
+
+        if (a2 - a1) % g != 0 {
+            return None;
+        }
+
+        let modulus = u64::try_from(lcm_u128(self.modulus, other.modulus)).ok()?;
+
+        // -- Both factors are reduced below m2 / g before multiplying, so the product
+        // -- fits in a u128 even for moduli near u64::MAX.
+        let reduced = m2 / g;
+        let diff = ((a2 - a1) / g).rem_euclid(reduced) as u128;
+        let p = p.rem_euclid(reduced) as u128;
+        let step = (diff * p % reduced as u128) as i128;
+
+        // -- m1 * step is below the combined modulus, which fits in a u64.
+        let residue = (a1 + m1 * step).rem_euclid(i128::from(modulus));
+
+        Some(Congruence::new(residue as u64, modulus))
+    }
+
+    // -- Smallest solution no less than the given bound.
+    pub fn first_at_least(&self, bound: u64) -> Option<u64> {
+        if self.residue >= bound {
+            return Some(self.residue);
+        }
+
+        let num_steps = (bound - self.residue).div_ceil(self.modulus);
+        num_steps
+            .checked_mul(self.modulus)?
+            .checked_add(self.residue)
+    }
+}
+
+pub fn crt<I>(congruences: I) -> Option<Congruence>
+where
+    I: IntoIterator<Item = Congruence>,
+{
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), |acc, congruence| {
+            acc.merge(&congruence)
+        })
+}
@@ -0,0 +1,190 @@
+// -----------------------------------------------------------------------------
+// -- A minimal PNG encoder for 8-bit RGB images. The pixel data is deflated with the
+// -- fixed Huffman codes and only ever matches against the previous pixel, which is all
+// -- it takes to shrink the flat, blocky images the puzzles draw.
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+const BYTES_PER_PIXEL: usize = 3;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+// -- Base lengths of the length codes 257 to 285, and their number of extra bits.
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+// -- Distance code 2 stands for a distance of exactly 3, one pixel back.
+const PIXEL_DISTANCE_CODE: u32 = 2;
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+
+    for chunk in bytes.chunks(5552) {
+        for byte in chunk {
+            a += u32::from(*byte);
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+
+    (b << 16) | a
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    nbits: u32,
+}
+
+impl BitWriter {
+    // -- Deflate packs values starting at the least significant bit.
+    fn write(&mut self, value: u32, nbits: u32) {
+        for bit in 0..nbits {
+            self.bits |= ((value >> bit) & 1) << self.nbits;
+            self.nbits += 1;
+
+            if self.nbits == 8 {
+                self.bytes.push(self.bits as u8);
+                self.bits = 0;
+                self.nbits = 0;
+            }
+        }
+    }
+
+    // -- Huffman codes are the exception, and go most significant bit first.
+    fn write_code(&mut self, code: u32, nbits: u32) {
+        for bit in (0..nbits).rev() {
+            self.write((code >> bit) & 1, 1);
+        }
+    }
+
+    fn write_symbol(&mut self, symbol: u16) {
+        let symbol = u32::from(symbol);
+
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xC0 + symbol - 280, 8),
+        }
+    }
+
+    fn write_match(&mut self, length: usize) {
+        let index = LENGTH_BASES
+            .iter()
+            .rposition(|base| usize::from(*base) <= length)
+            .unwrap();
+
+        self.write_symbol(257 + index as u16);
+        self.write(
+            (length - usize::from(LENGTH_BASES[index])) as u32,
+            u32::from(LENGTH_EXTRA_BITS[index]),
+        );
+        self.write_code(PIXEL_DISTANCE_CODE, 5);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.nbits > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter::default();
+
+    // -- A single, final block with fixed Huffman codes.
+    writer.write(1, 1);
+    writer.write(1, 2);
+
+    let mut position = 0;
+
+    while position < data.len() {
+        let length = (position..data.len().min(position + MAX_MATCH))
+            .take_while(|at| *at >= BYTES_PER_PIXEL && data[*at] == data[*at - BYTES_PER_PIXEL])
+            .count();
+
+        if length >= MIN_MATCH {
+            writer.write_match(length);
+            position += length;
+        } else {
+            writer.write_symbol(u16::from(data[position]));
+            position += 1;
+        }
+    }
+
+    writer.write_symbol(256);
+    writer.finish()
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(deflate(data));
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// -----------------------------------------------------------------------------
+// -- `pixels` holds the red, green and blue bytes of each pixel, row by row.
+pub fn encode(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    assert_eq!(
+        pixels.len(),
+        width * height * BYTES_PER_PIXEL,
+        "pixel data size"
+    );
+
+    let mut header = Vec::with_capacity(13);
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // -- 8 bits per channel, truecolor, deflate, no filtering, no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+
+    // -- Every scanline starts with its filter type, always none here.
+    let mut scanlines = Vec::with_capacity(height * (width * BYTES_PER_PIXEL + 1));
+    for row in pixels.chunks(width * BYTES_PER_PIXEL).take(height) {
+        scanlines.push(0);
+        scanlines.extend(row);
+    }
+
+    let mut png = SIGNATURE.to_vec();
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib(&scanlines));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
@@ -0,0 +1,117 @@
+use std::ops::RangeInclusive;
+
+// -----------------------------------------------------------------------------
+// -- A small seeded generator (splitmix64). Not cryptographic; the point is that a seed
+// -- reproduces exactly the same sequence on every platform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // -- Uniform in 0..bound, without modulo bias.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        let zone = u64::MAX - u64::MAX % bound;
+
+        loop {
+            let value = self.next_u64();
+
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range");
+        let span = high.abs_diff(low);
+
+        match span.checked_add(1) {
+            Some(bound) => low.wrapping_add(self.below(bound) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    pub fn range_u64(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range");
+
+        match (high - low).checked_add(1) {
+            Some(bound) => low + self.below(bound),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn range_usize(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        self.range_u64(low as u64..=high as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.unit() < probability
+    }
+
+    // -- Uniform in [0, 1).
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.index(index + 1));
+        }
+    }
+
+    // -- `count` distinct values from the range, in random order.
+    pub fn distinct(&mut self, count: usize, range: RangeInclusive<u64>) -> Vec<u64> {
+        let (low, high) = range.into_inner();
+        assert!(
+            high.saturating_sub(low).saturating_add(1) >= count as u64,
+            "range too small"
+        );
+
+        let mut values = Vec::with_capacity(count);
+
+        while values.len() < count {
+            let value = self.range_u64(low..=high);
+
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        values
+    }
+
+    // -- A lowercase word of the given length.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| char::from(b'a' + self.below(26) as u8))
+            .collect()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::random::Generator;
+use crate::Solver;
+
+// -----------------------------------------------------------------------------
+// -- A slow but straightforward solver for one part of a day, to check the fast one
+// -- against. `generate` makes inputs small enough for the slow one to finish quickly.
+#[derive(Clone, Copy)]
+pub struct Reference {
+    pub part: u8,
+    pub fast: Solver,
+    pub slow: Solver,
+    pub generate: Generator,
+}
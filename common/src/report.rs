@@ -0,0 +1,134 @@
+use crate::json::Value;
+use crate::stats::{self, Stats};
+use crate::{diagnostics, Error, Timed, Timing};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+use Format::*;
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match arg {
+            "text" => Ok(Text),
+            "json" => Ok(Json),
+            _ => Err(format!(
+                "unknown format '{}', expected 'text' or 'json'",
+                arg
+            )),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug)]
+pub struct Part {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub timed: Timed,
+}
+
+// -- Build the timed entry point of a part module, e.g. `common::timed!(trebuchet::part1)`.
+#[macro_export]
+macro_rules! timed {
+    ($($module:ident)::+) => {
+        |input| {
+            $crate::time(input, $($module)::+::parse, |parsed| {
+                $($module)::+::answer(parsed)
+            })
+        }
+    };
+}
+
+// -- 64-bit FNV-1a; stable across platforms and toolchains, unlike the std hasher.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub outcome: Result<Timing, Error>,
+    pub diagnostics: Vec<(&'static str, Value)>,
+    // -- Only collected while `stats` is enabled.
+    pub stats: Option<Stats>,
+}
+
+impl Report {
+    pub fn run(part: &Part, input: &str) -> Self {
+        // -- Drop anything left behind by an earlier run on this thread.
+        diagnostics::take();
+
+        let stats_enabled = stats::enabled();
+        if stats_enabled {
+            stats::take();
+        }
+
+        let (outcome, peak_rss) = stats::sample_rss(|| (part.timed)(input));
+
+        let stats = stats_enabled.then(|| Stats {
+            peak_rss,
+            ..stats::take()
+        });
+
+        Self {
+            year: part.year,
+            day: part.day,
+            part: part.part,
+            input_hash: input_hash(input),
+            outcome,
+            diagnostics: diagnostics::take(),
+            stats,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut fields = vec![
+            (String::from("year"), self.year.into()),
+            (String::from("day"), self.day.into()),
+            (String::from("part"), self.part.into()),
+            (String::from("input_hash"), self.input_hash.as_str().into()),
+        ];
+
+        match &self.outcome {
+            Ok(timing) => fields.extend([
+                (String::from("answer"), timing.answer.into()),
+                (
+                    String::from("parse_ns"),
+                    (timing.parse.as_nanos() as u64).into(),
+                ),
+                (
+                    String::from("solve_ns"),
+                    (timing.solve.as_nanos() as u64).into(),
+                ),
+            ]),
+            Err(err) => fields.push((String::from("error"), err.to_string().into())),
+        }
+
+        let diagnostics = self
+            .diagnostics
+            .iter()
+            .map(|(key, value)| (String::from(*key), value.clone()))
+            .collect();
+        fields.push((String::from("diagnostics"), Value::Object(diagnostics)));
+
+        if let Some(stats) = &self.stats {
+            fields.push((String::from("stats"), stats.to_json()));
+        }
+
+        Value::Object(fields)
+    }
+}
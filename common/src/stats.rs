@@ -0,0 +1,397 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::json::Value;
+
+// -- How often the resident set size is sampled while a part runs.
+const RSS_INTERVAL: Duration = Duration::from_millis(1);
+
+// -----------------------------------------------------------------------------
+// -- Opt-in instrumentation of the solvers: heap allocations per phase, peak resident
+// -- memory, and named counters and timers. Nothing is counted until `start`, and as
+// -- everything is process-wide, parts must run one at a time while it is on.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn start() {
+    take();
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn stop() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// -----------------------------------------------------------------------------
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+// -- Live bytes are counted from whenever counting started, so memory allocated before
+// -- and freed after can take them below zero.
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+fn allocated(size: usize) {
+    // -- A load keeps the flag's cache line shared, a store on every allocation would not.
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+
+    if enabled() {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+fn freed(size: usize) {
+    if enabled() {
+        LIVE.fetch_sub(size as i64, Ordering::Relaxed);
+    }
+}
+
+// -- Install it in a binary with
+// --     #[global_allocator]
+// --     static ALLOCATOR: CountingAllocator = CountingAllocator;
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    // -- A reallocation counts as one allocation of the new size, freeing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// -- Whether allocations are seen at all, which takes a `CountingAllocator`.
+pub fn counting_allocations() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Phase {
+    pub name: &'static str,
+    pub time: Duration,
+    pub allocations: u64,
+    pub allocated: u64,
+    // -- The most heap the phase held on top of what was live when it started.
+    pub peak_heap: u64,
+}
+
+#[derive(Clone, Copy)]
+pub struct Mark {
+    started: Instant,
+    allocations: u64,
+    allocated: u64,
+    live: i64,
+}
+
+static PHASES: Mutex<Vec<Phase>> = Mutex::new(Vec::new());
+
+// -- Marks the start of a phase; `None` while not enabled, which makes `phase` a no-op.
+pub fn mark() -> Option<Mark> {
+    if !enabled() {
+        return None;
+    }
+
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    Some(Mark {
+        started: Instant::now(),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+        live,
+    })
+}
+
+pub fn phase(name: &'static str, mark: Option<Mark>) {
+    let Some(mark) = mark else {
+        return;
+    };
+
+    let phase = Phase {
+        name,
+        time: mark.started.elapsed(),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - mark.allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - mark.allocated,
+        peak_heap: (PEAK.load(Ordering::Relaxed) - mark.live).max(0) as u64,
+    };
+    PHASES.lock().unwrap().push(phase);
+}
+
+// -----------------------------------------------------------------------------
+// -- Counters and timers are statics that sign up here the first time they are used
+// -- while enabled, so only the ones a part actually touched are reported.
+static COUNTERS: Mutex<Vec<&'static Counter>> = Mutex::new(Vec::new());
+static TIMERS: Mutex<Vec<&'static Timer>> = Mutex::new(Vec::new());
+
+pub struct Counter {
+    name: &'static str,
+    value: AtomicU64,
+    registered: AtomicBool,
+}
+
+impl Counter {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            value: AtomicU64::new(0),
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn add(&'static self, amount: u64) {
+        if !enabled() {
+            return;
+        }
+
+        if !self.registered.swap(true, Ordering::Relaxed) {
+            COUNTERS.lock().unwrap().push(self);
+        }
+        self.value.fetch_add(amount, Ordering::Relaxed);
+    }
+}
+
+pub struct Timer {
+    name: &'static str,
+    calls: AtomicU64,
+    nanos: AtomicU64,
+    registered: AtomicBool,
+}
+
+impl Timer {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            calls: AtomicU64::new(0),
+            nanos: AtomicU64::new(0),
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn start(&'static self) -> TimerGuard {
+        TimerGuard {
+            timer: self,
+            started: enabled().then(Instant::now),
+        }
+    }
+}
+
+// -- Adds the time since `Timer::start` to its timer when dropped.
+pub struct TimerGuard {
+    timer: &'static Timer,
+    started: Option<Instant>,
+}
+
+impl Drop for TimerGuard {
+    fn drop(&mut self) {
+        let Some(started) = self.started else {
+            return;
+        };
+
+        if !self.timer.registered.swap(true, Ordering::Relaxed) {
+            TIMERS.lock().unwrap().push(self.timer);
+        }
+        self.timer.calls.fetch_add(1, Ordering::Relaxed);
+        self.timer
+            .nanos
+            .fetch_add(started.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+// -- Bump a named counter, by one or by the given amount: `common::count!("cache_hits")`.
+#[macro_export]
+macro_rules! count {
+    ($name:literal) => {
+        $crate::count!($name, 1)
+    };
+    ($name:literal, $amount:expr) => {{
+        static COUNTER: $crate::stats::Counter = $crate::stats::Counter::new($name);
+        COUNTER.add($amount as u64);
+    }};
+}
+
+// -- Time the rest of the enclosing block: `let _timer = common::timer!("find_cycles");`.
+#[macro_export]
+macro_rules! timer {
+    ($name:literal) => {{
+        static TIMER: $crate::stats::Timer = $crate::stats::Timer::new($name);
+        TIMER.start()
+    }};
+}
+
+// -----------------------------------------------------------------------------
+// -- The kilobytes on the VmRSS line of /proc/self/status, on Linux only.
+fn resident_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+// -- Runs `f` while another thread samples the resident set size, and returns the
+// -- highest sample along with the result.
+pub fn sample_rss<T, F>(f: F) -> (T, Option<u64>)
+where
+    F: FnOnce() -> T,
+{
+    if !enabled() || resident_bytes().is_none() {
+        return (f(), None);
+    }
+
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        let sampler = scope.spawn(|| {
+            let mut peak = 0;
+            loop {
+                peak = peak.max(resident_bytes().unwrap_or(0));
+                if done.load(Ordering::Relaxed) {
+                    break peak;
+                }
+                thread::sleep(RSS_INTERVAL);
+            }
+        });
+
+        let result = f();
+        done.store(true, Ordering::Relaxed);
+        (result, sampler.join().ok())
+    })
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub phases: Vec<Phase>,
+    pub peak_rss: Option<u64>,
+    pub counters: Vec<(&'static str, u64)>,
+    // -- Name, number of calls and total time.
+    pub timers: Vec<(&'static str, u64, Duration)>,
+}
+
+// -- Everything recorded since the last call, resetting counters and timers. Counters
+// -- and timers bumped from several places under the same name are added up.
+pub fn take() -> Stats {
+    let phases = PHASES.lock().unwrap().drain(..).collect();
+
+    let mut counters: Vec<(&'static str, u64)> = Vec::new();
+    for counter in COUNTERS.lock().unwrap().drain(..) {
+        counter.registered.store(false, Ordering::Relaxed);
+        let value = counter.value.swap(0, Ordering::Relaxed);
+
+        match counters.iter_mut().find(|(name, _)| *name == counter.name) {
+            Some((_, total)) => *total += value,
+            None => counters.push((counter.name, value)),
+        }
+    }
+
+    let mut timers: Vec<(&'static str, u64, Duration)> = Vec::new();
+    for timer in TIMERS.lock().unwrap().drain(..) {
+        timer.registered.store(false, Ordering::Relaxed);
+        let calls = timer.calls.swap(0, Ordering::Relaxed);
+        let time = Duration::from_nanos(timer.nanos.swap(0, Ordering::Relaxed));
+
+        match timers.iter_mut().find(|(name, _, _)| *name == timer.name) {
+            Some((_, total_calls, total_time)) => {
+                *total_calls += calls;
+                *total_time += time;
+            }
+            None => timers.push((timer.name, calls, time)),
+        }
+    }
+
+    Stats {
+        phases,
+        peak_rss: None,
+        counters,
+        timers,
+    }
+}
+
+impl Stats {
+    pub fn to_json(&self) -> Value {
+        let phases = self
+            .phases
+            .iter()
+            .map(|phase| {
+                let mut fields = vec![
+                    (String::from("name"), phase.name.into()),
+                    (String::from("ns"), (phase.time.as_nanos() as u64).into()),
+                ];
+
+                if counting_allocations() {
+                    fields.extend([
+                        (String::from("allocations"), phase.allocations.into()),
+                        (String::from("allocated_bytes"), phase.allocated.into()),
+                        (String::from("peak_heap_bytes"), phase.peak_heap.into()),
+                    ]);
+                }
+
+                Value::Object(fields)
+            })
+            .collect();
+
+        let counters = self
+            .counters
+            .iter()
+            .map(|(name, value)| (String::from(*name), (*value).into()))
+            .collect();
+
+        let timers = self
+            .timers
+            .iter()
+            .map(|(name, calls, time)| {
+                let fields = vec![
+                    (String::from("calls"), (*calls).into()),
+                    (String::from("ns"), (time.as_nanos() as u64).into()),
+                ];
+                (String::from(*name), Value::Object(fields))
+            })
+            .collect();
+
+        Value::Object(vec![
+            (String::from("phases"), Value::Array(phases)),
+            (
+                String::from("peak_rss_bytes"),
+                self.peak_rss.map_or(Value::Null, Value::from),
+            ),
+            (String::from("counters"), Value::Object(counters)),
+            (String::from("timers"), Value::Object(timers)),
+        ])
+    }
+}
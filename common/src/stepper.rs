@@ -0,0 +1,215 @@
+use std::io::{self, BufRead, Write};
+
+use crate::visualize::strip_ansi;
+use crate::Error;
+
+const HELP: &str = "[enter] next  n [K] forward  p [K] back  g N jump to step N  q quit";
+
+// -----------------------------------------------------------------------------
+// -- A simulation that can be advanced one tick at a time and shown at any point.
+pub trait Simulation {
+    // -- Advance one tick; false if there was nothing left to do.
+    fn step(&mut self) -> bool;
+
+    // -- Advance up to `steps` ticks, returning how many were taken; fewer means the
+    // -- simulation ended. Simulations that settle into a loop skip whole laps of it.
+    fn advance(&mut self, steps: usize) -> usize {
+        (0..steps).take_while(|_| self.step()).count()
+    }
+
+    // -- The state as it stands, e.g. a grid through `Canvas::render`.
+    fn render(&self) -> String;
+
+    // -- A one-line summary shown above the state.
+    fn status(&self) -> String;
+}
+
+// -- Builds the simulation afresh at step 0.
+pub type Start = Box<dyn Fn() -> Box<dyn Simulation>>;
+
+// -- Parses a day's input into its simulation.
+pub type Loader = fn(&str) -> Result<Start, Error>;
+
+// -----------------------------------------------------------------------------
+// -- Simulations only ever move forward, so going back replays them from the start. The
+// -- days stepped through are quick enough that this beats keeping every state around,
+// -- and the ones that never end skip ahead once they loop.
+pub struct Stepper {
+    start: Start,
+    current: Box<dyn Simulation>,
+    position: usize,
+    end: Option<usize>,
+}
+
+impl Stepper {
+    pub fn new(start: Start) -> Self {
+        let current = start();
+
+        Self {
+            start,
+            current,
+            position: 0,
+            end: None,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    // -- The last step, once the simulation has been run that far.
+    pub fn end(&self) -> Option<usize> {
+        self.end
+    }
+
+    pub fn current(&self) -> &dyn Simulation {
+        self.current.as_ref()
+    }
+
+    // -- Stops early at the end of the simulation.
+    pub fn goto(&mut self, target: usize) {
+        let target = self.end.map_or(target, |end| target.min(end));
+
+        if target < self.position {
+            self.current = (self.start)();
+            self.position = 0;
+        }
+
+        let wanted = target - self.position;
+        let taken = self.current.advance(wanted);
+        self.position += taken;
+
+        if taken < wanted {
+            self.end = Some(self.position);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(usize),
+    Back(usize),
+    Jump(usize),
+    Quit,
+}
+
+use Command::*;
+
+impl Command {
+    // -- An empty line steps forward, a bare number jumps to that step.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("n");
+        let count = words.next();
+
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected '{}'", extra));
+        }
+
+        let number = |count: Option<&str>, default: Option<usize>| match count {
+            Some(count) => count
+                .parse()
+                .map_err(|_| format!("expected a number, found '{}'", count)),
+            None => default.ok_or_else(|| format!("'{}' needs a step number", command)),
+        };
+
+        match command {
+            "n" | "next" => Ok(Forward(number(count, Some(1))?)),
+            "p" | "prev" | "b" | "back" => Ok(Back(number(count, Some(1))?)),
+            "g" | "goto" => Ok(Jump(number(count, None)?)),
+            "q" | "quit" if count.is_none() => Ok(Quit),
+            _ if count.is_none() => match command.parse() {
+                Ok(step) => Ok(Jump(step)),
+                Err(_) => Err(format!("unknown command '{}'", command)),
+            },
+            _ => Err(format!("unknown command '{}'", command)),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// -- With `interactive` set every step replaces the previous one on screen, otherwise
+// -- steps are printed one after the other so a session can be scripted through stdin.
+pub struct Session {
+    pub interactive: bool,
+    pub color: bool,
+}
+
+impl Session {
+    fn draw<W: Write>(&self, stepper: &Stepper, note: &str, out: &mut W) -> io::Result<()> {
+        let current = stepper.current();
+        let state = current.render();
+
+        if self.interactive {
+            // -- Cursor home, then clear the screen.
+            write!(out, "\x1B[H\x1B[2J")?;
+        }
+
+        let end = match stepper.end() {
+            Some(end) => format!("/{}", end),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "step {}{}: {}",
+            stepper.position(),
+            end,
+            current.status()
+        )?;
+
+        if self.color {
+            write!(out, "{}", state)?;
+        } else {
+            write!(out, "{}", strip_ansi(&state))?;
+        }
+
+        if !note.is_empty() {
+            writeln!(out, "{}", note)?;
+        }
+
+        write!(out, "{}\n> ", HELP)?;
+        out.flush()
+    }
+
+    // -- Runs until 'q' or the end of the commands.
+    pub fn run<R, W>(&self, start: Start, commands: R, out: &mut W) -> io::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let mut stepper = Stepper::new(start);
+        let mut lines = commands.lines();
+        let mut note = String::new();
+
+        loop {
+            self.draw(&stepper, &note, out)?;
+            note.clear();
+
+            let Some(line) = lines.next() else {
+                writeln!(out)?;
+                return Ok(());
+            };
+
+            if !self.interactive {
+                writeln!(out)?;
+            }
+
+            let target = match Command::parse(&line?) {
+                Ok(Forward(steps)) => stepper.position().saturating_add(steps),
+                Ok(Back(steps)) => stepper.position().saturating_sub(steps),
+                Ok(Jump(step)) => step,
+                Ok(Quit) => return Ok(()),
+                Err(err) => {
+                    note = format!("error: {}", err);
+                    continue;
+                }
+            };
+
+            stepper.goto(target);
+            if stepper.position() < target {
+                note = format!("the simulation ends at step {}", stepper.position());
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+use crate::{stats, Answer, Error};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+pub type Timed = fn(&str) -> Result<Timing, Error>;
+
+// -- Run the parse and answer steps of a part, timing each one separately. The parsed
+// -- value may borrow from the input.
+pub fn time<'a, T, P, A>(input: &'a str, parse: P, answer: A) -> Result<Timing, Error>
+where
+    P: FnOnce(&'a str) -> Result<T, Error>,
+    A: FnOnce(&T) -> Result<Answer, Error>,
+{
+    let mark = stats::mark();
+    let start = Instant::now();
+    let parsed = parse(input)?;
+    let parse = start.elapsed();
+    stats::phase("parse", mark);
+
+    let mark = stats::mark();
+    let start = Instant::now();
+    let answer = answer(&parsed)?;
+    let solve = start.elapsed();
+    stats::phase("solve", mark);
+
+    Ok(Timing {
+        answer,
+        parse,
+        solve,
+    })
+}
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+// -- Frames a solver renders along the way, kept only while a caller asked for them with
+// -- `start`, so solvers pay nothing but a flag check when nobody is watching.
+thread_local! {
+    static FRAMES: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+pub fn start() {
+    FRAMES.with(|frames| *frames.borrow_mut() = Some(Vec::new()));
+}
+
+pub fn enabled() -> bool {
+    FRAMES.with(|frames| frames.borrow().is_some())
+}
+
+// -- The frame is only rendered when recording, so building it may be expensive.
+pub fn frame<F>(render: F)
+where
+    F: FnOnce() -> String,
+{
+    if !enabled() {
+        return;
+    }
+
+    let frame = render();
+    FRAMES.with(|frames| {
+        if let Some(frames) = frames.borrow_mut().as_mut() {
+            frames.push(frame);
+        }
+    });
+}
+
+// -- Stops recording and returns everything recorded since `start`.
+pub fn take() -> Vec<String> {
+    FRAMES.with(|frames| frames.take()).unwrap_or_default()
+}
+
+pub fn strip_ansi(frame: &str) -> String {
+    let mut stripped = String::with_capacity(frame.len());
+    let mut chars = frame.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\x1B' {
+            // -- Skip a CSI sequence: ESC '[' parameters, up to the final letter.
+            for ch in chars.by_ref() {
+                if ch.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        stripped.push(ch);
+    }
+
+    stripped
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Playback {
+    // -- Without a frame rate frames are printed one after the other, otherwise each
+    // -- frame replaces the previous one on screen.
+    pub fps: Option<u32>,
+    pub color: bool,
+}
+
+impl Playback {
+    pub fn play<W: Write>(&self, frames: &[String], out: &mut W) -> io::Result<()> {
+        for (index, frame) in frames.iter().enumerate() {
+            if self.fps.is_some() {
+                // -- Cursor home, then clear the screen.
+                write!(out, "\x1B[H\x1B[2J")?;
+            }
+
+            writeln!(out, "frame {}/{}", index + 1, frames.len())?;
+
+            if self.color {
+                write!(out, "{}", frame)?;
+            } else {
+                write!(out, "{}", strip_ansi(frame))?;
+            }
+
+            match self.fps {
+                Some(fps) => {
+                    out.flush()?;
+                    thread::sleep(Duration::from_secs_f64(1.0 / f64::from(fps.max(1))));
+                }
+                None => writeln!(out)?,
+            }
+        }
+
+        out.flush()
+    }
+}
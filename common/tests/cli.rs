@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use common::cli::{self, Source};
+
+fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split_whitespace().map(String::from)
+}
+
+#[test]
+fn input_args_leave_other_flags_to_the_caller() {
+    let mut part = None;
+    let source = cli::parse_input_args(args("--part 1 --input day.txt"), |arg, value| {
+        match arg {
+            "--part" => part = Some(value()?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    });
+
+    assert_eq!(source, Ok(Some(Source::File(PathBuf::from("day.txt")))));
+    assert_eq!(part.as_deref(), Some("1"));
+
+    let ignore = |_: &str, _: &mut dyn FnMut() -> Result<String, String>| Ok(false);
+    assert_eq!(
+        cli::parse_input_args(args("--input -"), ignore),
+        Ok(Some(Source::Stdin))
+    );
+    assert_eq!(cli::parse_input_args(args(""), ignore), Ok(None));
+    assert_eq!(
+        cli::parse_input_args(args("--input"), ignore),
+        Err(String::from("missing value for '--input'"))
+    );
+    assert_eq!(
+        cli::parse_input_args(args("--part 2"), ignore),
+        Err(String::from("unknown argument '--part'"))
+    );
+}
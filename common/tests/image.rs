@@ -0,0 +1,82 @@
+use common::image::{Drawing, Rgb};
+use common::png;
+
+const BLACK: Rgb = Rgb(0, 0, 0);
+const RED: Rgb = Rgb(255, 0, 0);
+
+#[test]
+fn checksums() {
+    assert_eq!(png::crc32(b"IEND"), 0xAE42_6082);
+    assert_eq!(png::crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(png::adler32(b"Wikipedia"), 0x11E6_0398);
+}
+
+#[test]
+fn png_chunks_are_well_formed() {
+    let pixels = [255_u8, 0, 0].repeat(64 * 64);
+    let png = png::encode(64, 64, &pixels);
+
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mut at = 8;
+    let mut kinds = Vec::new();
+
+    while at < png.len() {
+        let len = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+        let body = &png[at + 4..at + 8 + len];
+        let crc = u32::from_be_bytes(png[at + 8 + len..at + 12 + len].try_into().unwrap());
+
+        assert_eq!(png::crc32(body), crc);
+        kinds.push(String::from_utf8(body[..4].to_vec()).unwrap());
+
+        if kinds.len() == 1 {
+            assert_eq!(&body[4..12], &[0, 0, 0, 64, 0, 0, 0, 64]);
+        }
+        at += 12 + len;
+    }
+
+    assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+    // -- A single color compresses to a fraction of its 12 KiB.
+    assert!(png.len() < 1000, "{} bytes", png.len());
+}
+
+#[test]
+fn svg_merges_runs_of_cells() {
+    let mut drawing = Drawing::new(3.0, 1.0, BLACK);
+    drawing.cell(0, 0, RED);
+    drawing.cell(1, 0, RED);
+    drawing.cell(2, 0, Rgb(0, 0, 255));
+
+    let svg = drawing.to_svg();
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"8\""));
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#ff0000\"/>"));
+    assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#0000ff\"/>"));
+}
+
+#[test]
+fn polygons_fill_their_inside() {
+    // -- An L shape in a 4x4 drawing: the left column and the bottom row.
+    let mut drawing = Drawing::new(4.0, 4.0, BLACK);
+    drawing.polygon(
+        vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 3.0),
+            (4.0, 3.0),
+            (4.0, 4.0),
+            (0.0, 4.0),
+        ],
+        RED,
+    );
+
+    let (width, height, pixels) = drawing.raster();
+    assert_eq!((width, height), (32, 32));
+
+    let at = |col: usize, row: usize| pixels[(row * 8 + 4) * width + col * 8 + 4];
+    assert_eq!(at(0, 0), RED);
+    assert_eq!(at(1, 0), BLACK);
+    assert_eq!(at(2, 2), BLACK);
+    assert_eq!(at(3, 3), RED);
+    assert_eq!(pixels.iter().filter(|pixel| **pixel == RED).count(), 7 * 64);
+}
@@ -0,0 +1,79 @@
+use common::math::{self, Congruence};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(math::gcd(0, 7), 7);
+    assert_eq!(math::gcd(48, 18), 6);
+    assert_eq!(math::gcd(17, 5), 1);
+
+    assert_eq!(math::lcm(4, 6), Some(12));
+    assert_eq!(math::lcm(0, 6), Some(0));
+    assert_eq!(math::lcm(u64::MAX, u64::MAX - 1), None);
+    assert_eq!(
+        math::lcm_u128(u64::MAX, u64::MAX - 1),
+        u128::from(u64::MAX) * u128::from(u64::MAX - 1)
+    );
+    assert_eq!(math::lcm_all([2, 3, 4, 5]), Some(60));
+}
+
+#[test]
+fn extended_gcd_and_inverse() {
+    let (g, x, y) = math::extended_gcd(240, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+
+    assert_eq!(math::mod_inverse(3, 11), Some(4));
+    assert_eq!(math::mod_inverse(-3, 11), Some(7));
+    assert_eq!(math::mod_inverse(6, 9), None);
+}
+
+#[test]
+fn crt_coprime_and_non_coprime() {
+    let solution = math::crt([
+        Congruence::new(2, 3),
+        Congruence::new(3, 5),
+        Congruence::new(2, 7),
+    ]);
+    assert_eq!(solution, Some(Congruence::new(23, 105)));
+
+    // -- Non-coprime moduli that agree on their common factor.
+    let solution = math::crt([Congruence::new(3, 4), Congruence::new(5, 6)]);
+    assert_eq!(solution, Some(Congruence::new(11, 12)));
+
+    // -- ... and ones that do not.
+    assert_eq!(
+        math::crt([Congruence::new(1, 4), Congruence::new(2, 6)]),
+        None
+    );
+}
+
+#[test]
+fn first_at_least() {
+    let congruence = Congruence::new(3, 10);
+
+    assert_eq!(congruence.first_at_least(0), Some(3));
+    assert_eq!(congruence.first_at_least(3), Some(3));
+    assert_eq!(congruence.first_at_least(4), Some(13));
+    assert_eq!(congruence.first_at_least(u64::MAX), None);
+}
+
+#[test]
+fn crt_near_u64_max() {
+    // -- u64::MAX = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417.
+    let big = u64::MAX / 3;
+    let solution = math::crt([Congruence::new(2, 3), Congruence::new(big - 1, big)]).unwrap();
+    assert_eq!(solution.modulus, u64::MAX);
+    assert_eq!(solution.residue % 3, 2);
+    assert_eq!(solution.residue % big, big - 1);
+
+    let k = u64::MAX / 15;
+    let solution = math::crt([Congruence::new(k + 7, 3 * k), Congruence::new(7, 5 * k)]);
+    assert_eq!(solution, Some(Congruence::new(10 * k + 7, 15 * k)));
+
+    // -- Coprime moduli whose product does not fit.
+    let solution = math::crt([
+        Congruence::new(1, u64::MAX),
+        Congruence::new(2, u64::MAX - 1),
+    ]);
+    assert_eq!(solution, None);
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use common::pool::Pool;
+
+#[test]
+fn results_keep_the_order_of_the_items() {
+    let items: Vec<u64> = (0..100).collect();
+
+    // -- Early items take longest, so they finish last.
+    let squares = Pool::new(4).map(&items, |item| {
+        thread::sleep(Duration::from_micros(100 - item));
+        item * item
+    });
+
+    assert_eq!(
+        squares,
+        items.iter().map(|item| item * item).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn work_is_spread_over_the_workers() {
+    let threads = Mutex::new(HashSet::new());
+    let items = [(); 16];
+
+    Pool::new(4).map(&items, |_| {
+        threads.lock().unwrap().insert(thread::current().id());
+        thread::sleep(Duration::from_millis(5));
+    });
+
+    let threads = threads.into_inner().unwrap();
+    assert!(threads.len() > 1);
+    assert!(!threads.contains(&thread::current().id()));
+}
+
+#[test]
+fn small_jobs_stay_on_the_calling_thread() {
+    let caller = thread::current().id();
+
+    assert_eq!(Pool::new(0).workers(), 1);
+    assert!(Pool::available().workers() >= 1);
+    assert_eq!(Pool::new(8).map(&[1], |_| thread::current().id()), [caller]);
+    assert_eq!(
+        Pool::new(1).map(&[1, 2], |_| thread::current().id()),
+        [caller, caller]
+    );
+    assert!(Pool::new(8).map(&[] as &[u8], |item| *item).is_empty());
+}
@@ -0,0 +1,37 @@
+use common::random::Rng;
+
+#[test]
+fn seeds_reproduce_sequences() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let mut c = Rng::new(8);
+
+    let first: Vec<_> = (0..8).map(|_| a.next_u64()).collect();
+    assert_eq!(first, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(first, (0..8).map(|_| c.next_u64()).collect::<Vec<_>>());
+}
+
+#[test]
+fn ranges_are_inclusive_and_bounded() {
+    let mut rng = Rng::new(1);
+    let mut seen = [false; 5];
+
+    for _ in 0..1000 {
+        let value = rng.range(-2..=2);
+        assert!((-2..=2).contains(&value));
+        seen[(value + 2) as usize] = true;
+    }
+
+    assert!(seen.iter().all(|seen| *seen));
+    assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+    rng.range(i64::MIN..=i64::MAX);
+}
+
+#[test]
+fn distinct_values_do_not_repeat() {
+    let mut rng = Rng::new(3);
+    let mut values = rng.distinct(10, 1..=10);
+
+    values.sort();
+    assert_eq!(values, (1..=10).collect::<Vec<_>>());
+}
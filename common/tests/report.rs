@@ -0,0 +1,77 @@
+use common::json::Value;
+use common::report::{self, Part, Report};
+use common::{diagnostics, Answer, Error};
+
+fn parse(input: &str) -> Result<u64, Error> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| Error::new(format!("not a number: '{}'", input.trim())))
+}
+
+fn answer(value: &u64) -> Result<Answer, Error> {
+    diagnostics::record("doubled", value * 2);
+    Ok((*value).into())
+}
+
+const PART: Part = Part {
+    year: 2023,
+    day: 7,
+    part: 2,
+    timed: |input| common::time(input, parse, answer),
+};
+
+#[test]
+fn json_keeps_large_integers_exact() {
+    let value = Value::parse(r#"{"answer":207652583562007123,"ratio":0.5}"#).unwrap();
+
+    assert_eq!(
+        value.get("answer").and_then(Value::as_u64),
+        Some(207652583562007123)
+    );
+    assert_eq!(value.get("ratio").and_then(Value::as_f64), Some(0.5));
+    assert_eq!(
+        value.to_string(),
+        r#"{"answer":207652583562007123,"ratio":0.5}"#
+    );
+}
+
+#[test]
+fn input_hash_is_stable() {
+    assert_eq!(report::input_hash(""), "cbf29ce484222325");
+    assert_eq!(report::input_hash("a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn report_includes_answer_and_diagnostics() {
+    let json = Report::run(&PART, "21\n").to_json();
+
+    assert_eq!(json.get("year").and_then(Value::as_u64), Some(2023));
+    assert_eq!(json.get("day").and_then(Value::as_u64), Some(7));
+    assert_eq!(json.get("part").and_then(Value::as_u64), Some(2));
+    assert_eq!(json.get("answer").and_then(Value::as_u64), Some(21));
+    assert!(json.get("parse_ns").and_then(Value::as_u64).is_some());
+    assert!(json.get("solve_ns").and_then(Value::as_u64).is_some());
+    assert_eq!(
+        json.get("input_hash").and_then(Value::as_str),
+        Some(report::input_hash("21\n").as_str())
+    );
+
+    let doubled = json
+        .get("diagnostics")
+        .and_then(|value| value.get("doubled"));
+    assert_eq!(doubled.and_then(Value::as_u64), Some(42));
+}
+
+#[test]
+fn report_carries_errors_without_stale_diagnostics() {
+    diagnostics::record("leftover", 1);
+    let json = Report::run(&PART, "x\n").to_json();
+
+    assert!(json.get("answer").is_none());
+    assert_eq!(
+        json.get("error").and_then(Value::as_str),
+        Some("not a number: 'x'")
+    );
+    assert_eq!(json.get("diagnostics"), Some(&Value::Object(Vec::new())));
+}
@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+
+use common::stats::{self, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// -- The stats are process-wide, so the tests take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn counters_only_count_while_enabled() {
+    let _lock = LOCK.lock().unwrap();
+
+    stats::stop();
+    common::count!("ignored");
+    {
+        let _timer = common::timer!("ignored");
+    }
+    assert_eq!(stats::take(), stats::Stats::default());
+
+    stats::start();
+    for _ in 0..3 {
+        common::count!("steps");
+        let _timer = common::timer!("loop");
+    }
+    common::count!("steps", 4);
+    stats::stop();
+
+    let taken = stats::take();
+    assert_eq!(taken.counters, [("steps", 7)]);
+    assert_eq!(taken.timers.len(), 1);
+    assert_eq!((taken.timers[0].0, taken.timers[0].1), ("loop", 3));
+
+    // -- Taking resets them.
+    assert_eq!(stats::take(), stats::Stats::default());
+}
+
+#[test]
+fn phases_count_their_allocations() {
+    let _lock = LOCK.lock().unwrap();
+
+    stats::start();
+    let timing = common::time(
+        "1 2 3",
+        |input| Ok(input.split(' ').map(String::from).collect::<Vec<_>>()),
+        |words| Ok(common::Answer::from(words.len() as u64)),
+    )
+    .unwrap();
+    stats::stop();
+
+    assert_eq!(timing.answer, common::Answer::from(3_u64));
+    assert!(stats::counting_allocations());
+
+    let taken = stats::take();
+    let names: Vec<_> = taken.phases.iter().map(|phase| phase.name).collect();
+    assert_eq!(names, ["parse", "solve"]);
+
+    // -- The vector and its three strings, all still alive once parsing is done.
+    let parse = &taken.phases[0];
+    assert_eq!(parse.allocations, 4);
+    assert_eq!(parse.peak_heap, parse.allocated);
+    assert_eq!(taken.phases[1].allocations, 0);
+}
@@ -0,0 +1,110 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use common::stepper::{Command, Session, Simulation, Start, Stepper};
+
+// -- Counts up to a limit, keeping track of how often it was started.
+struct Counter {
+    value: u32,
+    limit: u32,
+}
+
+impl Simulation for Counter {
+    fn step(&mut self) -> bool {
+        if self.value == self.limit {
+            return false;
+        }
+        self.value += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        format!("\x1B[0;33m{}\x1B[0m\n", "#".repeat(self.value as usize))
+    }
+
+    fn status(&self) -> String {
+        format!("value {}", self.value)
+    }
+}
+
+fn counter(limit: u32, starts: Rc<Cell<u32>>) -> Start {
+    Box::new(move || {
+        starts.set(starts.get() + 1);
+        Box::new(Counter { value: 0, limit })
+    })
+}
+
+#[test]
+fn going_back_replays_from_the_start() {
+    let starts = Rc::new(Cell::new(0));
+    let mut stepper = Stepper::new(counter(5, starts.clone()));
+
+    stepper.goto(3);
+    assert_eq!(stepper.current().status(), "value 3");
+    assert_eq!(
+        (stepper.position(), stepper.end(), starts.get()),
+        (3, None, 1)
+    );
+
+    stepper.goto(2);
+    assert_eq!(stepper.current().status(), "value 2");
+    assert_eq!(starts.get(), 2);
+
+    // -- Running off the end stops there, and remembers it.
+    stepper.goto(9);
+    assert_eq!((stepper.position(), stepper.end()), (5, Some(5)));
+    stepper.goto(7);
+    assert_eq!((stepper.position(), starts.get()), (5, 2));
+}
+
+#[test]
+fn commands() {
+    assert_eq!(Command::parse(""), Ok(Command::Forward(1)));
+    assert_eq!(Command::parse("n 10"), Ok(Command::Forward(10)));
+    assert_eq!(Command::parse("p"), Ok(Command::Back(1)));
+    assert_eq!(Command::parse("back 3"), Ok(Command::Back(3)));
+    assert_eq!(Command::parse("g 42"), Ok(Command::Jump(42)));
+    assert_eq!(Command::parse(" 17 "), Ok(Command::Jump(17)));
+    assert_eq!(Command::parse("q"), Ok(Command::Quit));
+
+    assert!(Command::parse("g").is_err());
+    assert!(Command::parse("n x").is_err());
+    assert!(Command::parse("n 1 2").is_err());
+    assert!(Command::parse("jump").is_err());
+}
+
+#[test]
+fn scripted_session_prints_a_transcript() {
+    let session = Session {
+        interactive: false,
+        color: false,
+    };
+    let mut out = Vec::new();
+
+    session
+        .run(
+            counter(2, Rc::new(Cell::new(0))),
+            "\n9\nwat\nq\nn\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    let steps: Vec<_> = out
+        .lines()
+        .filter(|line| line.starts_with("step"))
+        .collect();
+    assert_eq!(
+        steps,
+        [
+            "step 0: value 0",
+            "step 1: value 1",
+            "step 2/2: value 2",
+            "step 2/2: value 2"
+        ]
+    );
+
+    assert!(out.contains("##\nthe simulation ends at step 2\n"));
+    assert!(out.contains("error: unknown command 'wat'"));
+    assert!(!out.contains('\x1B'));
+}
@@ -0,0 +1,51 @@
+use common::visualize::{self, Playback};
+
+#[test]
+fn frames_are_only_rendered_while_recording() {
+    visualize::frame(|| panic!("rendered without anyone watching"));
+    assert!(!visualize::enabled());
+
+    visualize::start();
+    visualize::frame(|| String::from("a\n"));
+    visualize::frame(|| String::from("b\n"));
+
+    assert_eq!(visualize::take(), ["a\n", "b\n"]);
+    assert!(!visualize::enabled());
+    assert!(visualize::take().is_empty());
+}
+
+#[test]
+fn playback_without_color_strips_escapes() {
+    let frames = [
+        String::from("\x1B[0;1;7;31m#\x1B[0m.\n"),
+        String::from("..\n"),
+    ];
+    let mut out = Vec::new();
+    let playback = Playback {
+        fps: None,
+        color: false,
+    };
+
+    playback.play(&frames, &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "frame 1/2\n#.\n\nframe 2/2\n..\n\n"
+    );
+}
+
+#[test]
+fn playback_at_a_frame_rate_clears_between_frames() {
+    let frames = [String::from("a\n"), String::from("b\n")];
+    let mut out = Vec::new();
+    let playback = Playback {
+        fps: Some(1000),
+        color: true,
+    };
+
+    playback.play(&frames, &mut out).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.matches("\x1B[H\x1B[2J").count(), 2);
+    assert!(out.ends_with("frame 2/2\nb\n"));
+}
@@ -0,0 +1,68 @@
+use std::fmt::{self, Display};
+
+use crate::Direction;
+
+// -- Offsets of the eight surrounding cells, row by row from the north-west.
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord2D {
+    pub col: isize,
+    pub row: isize,
+}
+
+impl Coord2D {
+    pub const fn new(col: isize, row: isize) -> Self {
+        Self { col, row }
+    }
+
+    // -- Grid dimensions are bounded by allocation size, so indices always fit.
+    pub const fn from_indices(col: usize, row: usize) -> Self {
+        Self::new(col as isize, row as isize)
+    }
+
+    pub const fn offset(&self, dcol: isize, drow: isize) -> Self {
+        Self::new(self.col + dcol, self.row + drow)
+    }
+
+    pub fn step(&self, direction: Direction) -> Self {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(&self, direction: Direction, distance: isize) -> Self {
+        let (dcol, drow) = direction.delta();
+        self.offset(dcol * distance, drow * distance)
+    }
+
+    pub fn manhattan_distance(&self, other: &Coord2D) -> usize {
+        self.col.abs_diff(other.col) + self.row.abs_diff(other.row)
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Coord2D> {
+        let coord = *self;
+        Direction::ALL.into_iter().map(move |dir| coord.step(dir))
+    }
+
+    pub fn neighbors8(&self) -> impl Iterator<Item = Coord2D> {
+        let coord = *self;
+        NEIGHBORS8
+            .into_iter()
+            .map(move |(dcol, drow)| coord.offset(dcol, drow))
+    }
+}
+
+impl Display for Coord2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.col, self.row)
+    }
+}
@@ -0,0 +1,65 @@
+use std::ops::Neg;
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+use Direction::*;
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    // -- Column and row offsets of a single step, rows growing southward.
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, East | West)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, North | South)
+    }
+}
+
+impl Neg for Direction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+}
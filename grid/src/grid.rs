@@ -0,0 +1,275 @@
+use std::ops::{Index, IndexMut};
+
+use common::{Error, Line};
+
+use crate::{Coord2D, Direction};
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    ncols: usize,
+    nrows: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(ncols: usize, nrows: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let cells = vec![value; ncols * nrows];
+        Self {
+            cells,
+            ncols,
+            nrows,
+        }
+    }
+
+    pub fn from_fn<F>(ncols: usize, nrows: usize, mut f: F) -> Self
+    where
+        F: FnMut(Coord2D) -> T,
+    {
+        let cells = (0..nrows)
+            .flat_map(|row| (0..ncols).map(move |col| Coord2D::from_indices(col, row)))
+            .map(&mut f)
+            .collect();
+        Self {
+            cells,
+            ncols,
+            nrows,
+        }
+    }
+
+    // -- Every row must be as long as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let nrows = rows.len();
+        let ncols = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != ncols) {
+            return None;
+        }
+
+        let cells = rows.into_iter().flatten().collect();
+        Some(Self {
+            cells,
+            ncols,
+            nrows,
+        })
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of(&self, coord: &Coord2D) -> Option<usize> {
+        let col = usize::try_from(coord.col).ok()?;
+        let row = usize::try_from(coord.row).ok()?;
+
+        if col < self.ncols && row < self.nrows {
+            Some(row * self.ncols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, coord: &Coord2D) -> bool {
+        self.index_of(coord).is_some()
+    }
+
+    pub fn get(&self, coord: &Coord2D) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord2D) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
+    }
+
+    // -- Treat the grid as tiling the plane infinitely in every direction.
+    pub fn get_wrap(&self, coord: &Coord2D) -> &T {
+        let col = coord.col.rem_euclid(self.ncols as isize);
+        let row = coord.row.rem_euclid(self.nrows as isize);
+        &self[Coord2D::new(col, row)]
+    }
+
+    pub fn step(&self, coord: &Coord2D, direction: Direction) -> Option<Coord2D> {
+        Some(coord.step(direction)).filter(|next| self.contains(next))
+    }
+
+    pub fn neighbors4<'a>(&'a self, coord: &Coord2D) -> impl Iterator<Item = Coord2D> + 'a {
+        coord.neighbors4().filter(|next| self.contains(next))
+    }
+
+    pub fn neighbors8<'a>(&'a self, coord: &Coord2D) -> impl Iterator<Item = Coord2D> + 'a {
+        coord.neighbors8().filter(|next| self.contains(next))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.ncols..(row + 1) * self.ncols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.nrows).map(|row| self.row(row))
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord2D> {
+        let ncols = self.ncols;
+        (0..self.len()).map(move |index| Coord2D::from_indices(index % ncols, index / ncols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord2D, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn position<P>(&self, mut predicate: P) -> Option<Coord2D>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            ncols: self.ncols,
+            nrows: self.nrows,
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.nrows, self.ncols, |coord| {
+            self[Coord2D::new(coord.row, coord.col)].clone()
+        })
+    }
+
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.nrows as isize - 1;
+        Grid::from_fn(self.nrows, self.ncols, |coord| {
+            self[Coord2D::new(coord.row, last_row - coord.col)].clone()
+        })
+    }
+
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_col = self.ncols as isize - 1;
+        Grid::from_fn(self.nrows, self.ncols, |coord| {
+            self[Coord2D::new(last_col - coord.row, coord.col)].clone()
+        })
+    }
+}
+
+impl<T> Index<Coord2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord2D) -> &Self::Output {
+        match self.index_of(&coord) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "coordinate {} outside {}x{} grid",
+                coord, self.ncols, self.nrows
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord2D> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord2D) -> &mut Self::Output {
+        match self.index_of(&coord) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "coordinate {} outside {}x{} grid",
+                coord, self.ncols, self.nrows
+            ),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+impl<T> Grid<T> {
+    // -- Errors returned by the cell parser are located at the offending character.
+    pub fn parse_lines<'a, I, F>(lines: I, mut parse_cell: F) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: FnMut(Coord2D, char) -> Result<T, Error>,
+    {
+        let mut cells = Vec::new();
+        let mut ncols = None;
+        let mut nrows = 0;
+
+        for line in lines {
+            let mut num_cols = 0;
+
+            for (col, (offset, ch)) in line.char_indices().enumerate() {
+                let coord = Coord2D::from_indices(col, nrows);
+                let cell =
+                    parse_cell(coord, ch).map_err(|err| err.at(&line, offset, ch.len_utf8()))?;
+                cells.push(cell);
+                num_cols += 1;
+            }
+
+            match ncols {
+                Some(ncols) if ncols != num_cols => {
+                    return Err(line.error(format!(
+                        "expected {} tiles in row, found {}",
+                        ncols, num_cols
+                    )))
+                }
+                _ => ncols = Some(num_cols),
+            }
+
+            nrows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            ncols: ncols.unwrap_or(0),
+            nrows,
+        })
+    }
+
+    pub fn parse<F>(input: &str, parse_cell: F) -> Result<Self, Error>
+    where
+        F: FnMut(Coord2D, char) -> Result<T, Error>,
+    {
+        Self::parse_lines(common::lines(input), parse_cell)
+    }
+}
+
+impl<T> TryFrom<&str> for Grid<T>
+where
+    T: TryFrom<char, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::parse(input, |_, ch| T::try_from(ch))
+    }
+}
@@ -0,0 +1,9 @@
+mod coord;
+mod direction;
+mod grid;
+mod render;
+
+pub use coord::Coord2D;
+pub use direction::Direction;
+pub use grid::Grid;
+pub use render::{Canvas, Color};
@@ -0,0 +1,193 @@
+use common::image::{Drawing, Rgb, BACKGROUND};
+
+use crate::{Coord2D, Grid};
+
+// -- In exported images uncolored glyphs are a dim gray, and highlighted cells without a
+// -- color white.
+const GLYPH: Rgb = Rgb(0x58, 0x6e, 0x75);
+const HIGHLIGHT: Rgb = Rgb(0xfd, 0xf6, 0xe3);
+
+// -- Glyphs that stand for nothing at all, left as background.
+const BLANK: [char; 3] = ['.', '·', ' '];
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+use Color::*;
+
+impl Color {
+    fn code(&self) -> &'static str {
+        match self {
+            Red => "31",
+            Green => "32",
+            Yellow => "33",
+            Blue => "34",
+            Magenta => "35",
+            Cyan => "36",
+            Gray => "90",
+        }
+    }
+
+    pub fn rgb(&self) -> Rgb {
+        match self {
+            Red => Rgb(0xdc, 0x32, 0x2f),
+            Green => Rgb(0x85, 0x99, 0x00),
+            Yellow => Rgb(0xb5, 0x89, 0x00),
+            Blue => Rgb(0x26, 0x8b, 0xd2),
+            Magenta => Rgb(0xd3, 0x36, 0x82),
+            Cyan => Rgb(0x2a, 0xa1, 0x98),
+            Gray => Rgb(0x93, 0xa1, 0xa1),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    color: Option<Color>,
+    highlight: bool,
+}
+
+impl Style {
+    fn escape(&self) -> String {
+        let mut codes = vec!["0"];
+
+        if self.highlight {
+            codes.push("1;7");
+        }
+        if let Some(color) = self.color {
+            codes.push(color.code());
+        }
+
+        format!("\x1B[{}m", codes.join(";"))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Cell {
+    glyph: char,
+    style: Style,
+}
+
+// -----------------------------------------------------------------------------
+// -- A grid of glyphs with colored overlay layers on top, rendered to a single ANSI
+// -- frame. Layers are applied in order, so a later layer wins where they overlap, and
+// -- coordinates outside the canvas are ignored.
+#[derive(Clone)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    pub fn from_fn<F>(ncols: usize, nrows: usize, mut glyph: F) -> Self
+    where
+        F: FnMut(Coord2D) -> char,
+    {
+        let cells = Grid::from_fn(ncols, nrows, |coord| Cell {
+            glyph: glyph(coord),
+            style: Style::default(),
+        });
+        Self { cells }
+    }
+
+    pub fn from_grid<T, F>(grid: &Grid<T>, glyph: F) -> Self
+    where
+        F: Fn(&T) -> char,
+    {
+        Self::from_fn(grid.ncols(), grid.nrows(), |coord| glyph(&grid[coord]))
+    }
+
+    pub fn layer<I>(mut self, color: Color, coords: I) -> Self
+    where
+        I: IntoIterator<Item = Coord2D>,
+    {
+        for coord in coords {
+            if let Some(cell) = self.cells.get_mut(&coord) {
+                cell.style.color = Some(color);
+            }
+        }
+        self
+    }
+
+    // -- Highlighted cells are drawn bold in reverse video, keeping any layer color.
+    pub fn highlight<I>(mut self, coords: I) -> Self
+    where
+        I: IntoIterator<Item = Coord2D>,
+    {
+        for coord in coords {
+            if let Some(cell) = self.cells.get_mut(&coord) {
+                cell.style.highlight = true;
+            }
+        }
+        self
+    }
+
+    // -- Replaces the glyph of single cells, e.g. to draw a path with arrows.
+    pub fn draw<I>(mut self, glyphs: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord2D, char)>,
+    {
+        for (coord, glyph) in glyphs {
+            if let Some(cell) = self.cells.get_mut(&coord) {
+                cell.glyph = glyph;
+            }
+        }
+        self
+    }
+
+    // -- One unit cell per cell, in its layer's color. Cells with neither a color nor a
+    // -- highlight show their glyph only as a dim square, or nothing for blank glyphs.
+    pub fn to_drawing(&self) -> Drawing {
+        let mut drawing = Drawing::new(
+            self.cells.ncols() as f64,
+            self.cells.nrows() as f64,
+            BACKGROUND,
+        );
+
+        for (coord, cell) in self.cells.iter() {
+            let color = match (cell.style.color, cell.style.highlight) {
+                (Some(color), _) => color.rgb(),
+                (None, true) => HIGHLIGHT,
+                (None, false) if BLANK.contains(&cell.glyph) => continue,
+                (None, false) => GLYPH,
+            };
+
+            drawing.cell(coord.col as usize, coord.row as usize, color);
+        }
+
+        drawing
+    }
+
+    // -- Escapes are only written where the style changes, and every line ends reset.
+    pub fn render(&self) -> String {
+        let mut frame = String::with_capacity(self.cells.len() * 2);
+
+        for row in self.cells.rows() {
+            let mut current = Style::default();
+
+            for cell in row {
+                if cell.style != current {
+                    frame.push_str(&cell.style.escape());
+                    current = cell.style;
+                }
+                frame.push(cell.glyph);
+            }
+
+            if current != Style::default() {
+                frame.push_str("\x1B[0m");
+            }
+            frame.push('\n');
+        }
+
+        frame
+    }
+}
@@ -0,0 +1,107 @@
+use common::Error;
+use grid::{Coord2D, Direction, Grid};
+
+fn sample() -> Grid<char> {
+    // 0 1 2
+    // 3 4 5
+    Grid::parse("012\n345\n", |_, ch| Ok(ch)).unwrap()
+}
+
+fn text(grid: &Grid<char>) -> Vec<String> {
+    grid.rows().map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn parse_dimensions_and_access() {
+    let grid = sample();
+
+    assert_eq!((grid.ncols(), grid.nrows(), grid.len()), (3, 2, 6));
+    assert_eq!(grid.get(&Coord2D::new(2, 1)), Some(&'5'));
+    assert_eq!(grid.get(&Coord2D::new(3, 0)), None);
+    assert_eq!(grid.get(&Coord2D::new(0, -1)), None);
+    assert_eq!(grid[Coord2D::new(1, 0)], '1');
+    assert_eq!(grid.position(|ch| *ch == '4'), Some(Coord2D::new(1, 1)));
+}
+
+#[test]
+fn parse_errors_are_located() {
+    let err = Grid::parse("..\n.x\n", |_, ch| match ch {
+        '.' => Ok(()),
+        _ => Err(Error::new("bad tile")),
+    })
+    .unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!((location.line(), location.column()), (2, 2));
+
+    let err = Grid::parse("...\n..\n", |_, _| Ok(())).unwrap_err();
+    assert_eq!(err.message(), "expected 3 tiles in row, found 2");
+    assert_eq!(err.location().unwrap().line(), 2);
+}
+
+#[test]
+fn from_rows_rejects_ragged() {
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    assert_eq!(
+        Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()[Coord2D::new(1, 1)],
+        4
+    );
+}
+
+#[test]
+fn wrapping_access() {
+    let grid = sample();
+
+    assert_eq!(*grid.get_wrap(&Coord2D::new(-1, 0)), '2');
+    assert_eq!(*grid.get_wrap(&Coord2D::new(3, 2)), '0');
+    assert_eq!(*grid.get_wrap(&Coord2D::new(-4, -3)), '5');
+}
+
+#[test]
+fn steps_and_neighbors() {
+    let grid = sample();
+    let corner = Coord2D::new(0, 0);
+
+    assert_eq!(
+        grid.step(&corner, Direction::East),
+        Some(Coord2D::new(1, 0))
+    );
+    assert_eq!(grid.step(&corner, Direction::North), None);
+    assert_eq!(grid.neighbors4(&corner).count(), 2);
+    assert_eq!(grid.neighbors8(&corner).count(), 3);
+    assert_eq!(grid.neighbors8(&Coord2D::new(1, 0)).count(), 5);
+    assert_eq!(corner.neighbors8().count(), 8);
+}
+
+#[test]
+fn directions() {
+    for direction in Direction::ALL {
+        assert_eq!(-(-direction), direction);
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), -direction);
+        assert_ne!(direction.is_horizontal(), direction.is_vertical());
+        assert_eq!(
+            Coord2D::default().step(direction).step(-direction),
+            Coord2D::default()
+        );
+    }
+
+    assert_eq!(
+        Coord2D::new(1, 2).manhattan_distance(&Coord2D::new(-2, 0)),
+        5
+    );
+    assert_eq!(
+        Coord2D::new(1, 2).step_by(Direction::North, 6),
+        Coord2D::new(1, -4)
+    );
+}
+
+#[test]
+fn transpose_and_rotate() {
+    let grid = sample();
+
+    assert_eq!(text(&grid.transpose()), ["03", "14", "25"]);
+    assert_eq!(text(&grid.rotate_cw()), ["30", "41", "52"]);
+    assert_eq!(text(&grid.rotate_ccw()), ["25", "14", "03"]);
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+}
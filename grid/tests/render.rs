@@ -0,0 +1,44 @@
+use grid::{Canvas, Color, Coord2D, Grid};
+
+fn sample() -> Canvas {
+    let grid = Grid::parse("#..\n.#.\n", |_, ch| Ok(ch)).unwrap();
+    Canvas::from_grid(&grid, |ch| *ch)
+}
+
+#[test]
+fn plain_canvas_has_no_escapes() {
+    assert_eq!(sample().render(), "#..\n.#.\n");
+}
+
+#[test]
+fn escapes_only_where_the_style_changes() {
+    let frame = sample()
+        .layer(Color::Red, [Coord2D::new(1, 0), Coord2D::new(2, 0)])
+        .render();
+
+    assert_eq!(frame, "#\x1B[0;31m..\x1B[0m\n.#.\n");
+}
+
+#[test]
+fn later_layers_win_and_outside_coords_are_ignored() {
+    let frame = sample()
+        .layer(Color::Red, [Coord2D::new(0, 1), Coord2D::new(5, 5)])
+        .layer(Color::Green, [Coord2D::new(0, 1), Coord2D::new(-1, 0)])
+        .highlight([Coord2D::new(2, 1)])
+        .draw([(Coord2D::new(1, 1), '>')])
+        .render();
+
+    assert_eq!(frame, "#..\n\x1B[0;32m.\x1B[0m>\x1B[0;1;7m.\x1B[0m\n");
+}
+
+#[test]
+fn drawings_skip_blank_uncolored_cells() {
+    let drawing = sample()
+        .layer(Color::Red, [Coord2D::new(1, 0)])
+        .to_drawing();
+    let svg = drawing.to_svg();
+
+    // -- Two rocks in dim gray, one red cell, and nothing for the other dots.
+    assert_eq!(svg.matches("<rect x=").count(), 3);
+    assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#dc322f\"/>"));
+}